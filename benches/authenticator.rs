@@ -0,0 +1,38 @@
+//! Reading the authenticator for a request: owned, through the ArcSwap of `shared_authentication` and behind a Mutex
+use std::sync::Arc;
+
+use arc_swap::ArcSwap;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use reqwest::header::HeaderMap;
+use rraw::auth::{AnonymousAuthenticator, Authenticator};
+use tokio::sync::Mutex;
+
+/// What the Client does with the authenticator for every request
+fn request_headers(auth: &impl Authenticator) -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    if !auth.needs_token_refresh() {
+        auth.headers(&mut headers);
+    }
+    headers
+}
+
+fn authenticator(c: &mut Criterion) {
+    let mut group = c.benchmark_group("anonymous_authenticator");
+    let owned = AnonymousAuthenticator::new();
+    group.bench_function("owned", |b| b.iter(|| request_headers(black_box(&owned))));
+    let shared = ArcSwap::from_pointee(AnonymousAuthenticator::new());
+    group.bench_function("arc_swap", |b| {
+        b.iter(|| request_headers(&**black_box(&shared).load()))
+    });
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap();
+    let locked = Arc::new(Mutex::new(AnonymousAuthenticator::new()));
+    group.bench_function("mutex", |b| {
+        b.iter(|| runtime.block_on(async { request_headers(&*black_box(&locked).lock().await) }))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, authenticator);
+criterion_main!(benches);
@@ -0,0 +1,121 @@
+//! A GET request through the Client against a local server, so the headers are built the same way as for Reddit
+use async_trait::async_trait;
+use criterion::{criterion_group, criterion_main, Criterion};
+use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION};
+use reqwest::Client as ReqwestClient;
+use rraw::access::Access;
+use rraw::auth::{AnonymousAuthenticator, Authenticator};
+use rraw::error::Error;
+use rraw::Client;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use tokio::runtime::Runtime;
+
+const AGENT: &str = "RRAW Test (by u/KingTuxWH)";
+const RESPONSE: &[u8] =
+    b"HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: 2\r\n\r\n{}";
+
+/// An OAuth authenticator with a fixed token. Logging in does not make a request
+#[derive(Clone, Debug)]
+struct BearerAuthenticator(HeaderValue);
+
+#[async_trait(?Send)]
+impl Authenticator for BearerAuthenticator {
+    async fn login(&mut self, _client: &ReqwestClient, _user_agent: &str) -> Result<bool, Error> {
+        Ok(true)
+    }
+    async fn logout(&mut self, _client: &ReqwestClient, _user_agent: &str) -> Result<(), Error> {
+        Ok(())
+    }
+    async fn token_refresh(
+        &mut self,
+        _client: &ReqwestClient,
+        _user_agent: &str,
+    ) -> Result<bool, Error> {
+        Ok(true)
+    }
+    fn headers(&self, headers: &mut HeaderMap) {
+        headers.insert(AUTHORIZATION, self.0.clone());
+    }
+    fn oauth(&self) -> bool {
+        true
+    }
+    fn needs_token_refresh(&self) -> bool {
+        false
+    }
+    fn get_refresh_token(&self) -> Option<String> {
+        None
+    }
+}
+
+/// Answers every request on a connection with `{}`. Keeps the connection open so only the request is measured
+async fn local_server() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    tokio::spawn(async move {
+        while let Ok((mut stream, _)) = listener.accept().await {
+            tokio::spawn(async move {
+                let mut buffer = Vec::new();
+                let mut chunk = [0u8; 4096];
+                loop {
+                    let read = match stream.read(&mut chunk).await {
+                        Ok(0) | Err(_) => return,
+                        Ok(read) => read,
+                    };
+                    buffer.extend_from_slice(&chunk[..read]);
+                    while let Some(end) = buffer.windows(4).position(|window| window == b"\r\n\r\n")
+                    {
+                        buffer.drain(..end + 4);
+                        if stream.write_all(RESPONSE).await.is_err() {
+                            return;
+                        }
+                    }
+                }
+            });
+        }
+    });
+    url
+}
+
+fn bench_get<A: Authenticator>(
+    c: &mut Criterion,
+    runtime: &Runtime,
+    url: &str,
+    name: &str,
+    auth: A,
+) {
+    let client = runtime.block_on(async {
+        Client::builder(auth, AGENT)
+            .base_url(url)
+            .login()
+            .await
+            .unwrap()
+    });
+    c.bench_function(name, |b| {
+        b.iter(|| {
+            runtime
+                .block_on(client.get_raw("/api/v1/me", Access::Any))
+                .unwrap()
+        })
+    });
+}
+
+fn headers(c: &mut Criterion) {
+    let runtime = tokio::runtime::Builder::new_multi_thread()
+        .enable_all()
+        .build()
+        .unwrap();
+    let url = runtime.block_on(local_server());
+    bench_get(
+        c,
+        &runtime,
+        &url,
+        "get_anonymous",
+        AnonymousAuthenticator::new(),
+    );
+    let token = HeaderValue::from_static("Bearer 1234567890-abcdefghijklmnopqrstuvwxyzABCD");
+    bench_get(c, &runtime, &url, "get_bearer", BearerAuthenticator(token));
+}
+
+criterion_group!(benches, headers);
+criterion_main!(benches);
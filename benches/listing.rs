@@ -0,0 +1,37 @@
+//! Parsing a listing of about 1MB with serde_json versus simd-json
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use rraw::submission::response::SubmissionsResponse;
+use serde_json::Value;
+
+/// Repeats the submissions of the fixture until the listing is at least 1MB
+fn large_listing() -> Vec<u8> {
+    let mut listing: Value =
+        serde_json::from_str(include_str!("../tests/fixtures/submissions.json")).unwrap();
+    let children = listing["data"]["children"].as_array().unwrap().clone();
+    let size = serde_json::to_vec(&children).unwrap().len();
+    let repeat = 1024 * 1024 / size + 1;
+    let page = listing["data"]["children"].as_array_mut().unwrap();
+    for _ in 0..repeat {
+        page.extend(children.iter().cloned());
+    }
+    serde_json::to_vec(&listing).unwrap()
+}
+
+fn listing(c: &mut Criterion) {
+    let body = large_listing();
+    let mut group = c.benchmark_group("listing");
+    group.throughput(criterion::Throughput::Bytes(body.len() as u64));
+    group.bench_function("serde_json", |b| {
+        b.iter(|| serde_json::from_slice::<SubmissionsResponse>(black_box(&body)).unwrap())
+    });
+    group.bench_function("simd_json", |b| {
+        b.iter(|| {
+            let mut buffer = black_box(&body).to_vec();
+            simd_json::serde::from_slice::<SubmissionsResponse>(&mut buffer).unwrap()
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, listing);
+criterion_main!(benches);
@@ -0,0 +1,64 @@
+//! Where a request is sent. Each endpoint declares its [Access] and the host is picked from the authenticator
+use crate::error::Error;
+
+/// Serves the OAuth API. Used by authenticators that support OAuth
+pub const OAUTH_HOST: &str = "https://oauth.reddit.com";
+/// Serves the website and its `.json` pages. Used by anonymous and cookie logins
+pub const WEB_HOST: &str = "https://www.reddit.com";
+/// Serves the endpoints that are not part of the OAuth API
+pub const PRIVATE_HOST: &str = "https://reddit.com";
+
+/// Who can call an endpoint
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Access {
+    /// Works with or without OAuth. OAuth authenticators use [OAUTH_HOST]. Others use [WEB_HOST]
+    Any,
+    /// Requires an OAuth token. Returns [Error::NotLoggedIn] for other authenticators
+    OAuthOnly,
+    /// Not part of the OAuth API. Always sent to [PRIVATE_HOST]
+    WebOnly,
+}
+
+impl Access {
+    /// The host for an authenticator. Returns [Error::NotLoggedIn] if `oauth` is required and not supported
+    pub fn host(&self, oauth: bool) -> Result<&'static str, Error> {
+        match self {
+            Access::WebOnly => Ok(PRIVATE_HOST),
+            Access::OAuthOnly if !oauth => Err(Error::NotLoggedIn),
+            Access::Any | Access::OAuthOnly if oauth => Ok(OAUTH_HOST),
+            Access::Any | Access::OAuthOnly => Ok(WEB_HOST),
+        }
+    }
+    /// Is an `Authorization` header required
+    pub fn requires_oauth(&self) -> bool {
+        matches!(self, Access::OAuthOnly)
+    }
+    /// The path of a GET for the host. The website only answers with JSON if the path ends in `.json`.
+    /// The OAuth host answers with JSON either way, so paths sent to it are not changed.
+    ///
+    /// API paths such as `/api/info` or `/r/{name}/api/widgets` answer with JSON on every host and are never changed.
+    /// Neither are `.rss` feeds
+    /// ```
+    /// use rraw::access::Access;
+    /// assert_eq!(Access::Any.path("/r/rust/about?raw_json=1", false), "/r/rust/about.json?raw_json=1");
+    /// assert_eq!(Access::Any.path("/r/rust/comments/15bfi0/rraw/", false), "/r/rust/comments/15bfi0/rraw/.json");
+    /// assert_eq!(Access::Any.path("/r/rust/about", true), "/r/rust/about");
+    /// assert_eq!(Access::Any.path("/api/info?id=t3_15bfi0", false), "/api/info?id=t3_15bfi0");
+    /// assert_eq!(Access::Any.path("/r/rust/api/widgets", false), "/r/rust/api/widgets");
+    /// ```
+    pub fn path(&self, path: &str, oauth: bool) -> String {
+        let (route, query) = match path.find('?') {
+            Some(index) => path.split_at(index),
+            None => (path, ""),
+        };
+        let uses_web = *self == Access::WebOnly || !oauth;
+        if !uses_web
+            || route.contains("/api/")
+            || route.ends_with(".json")
+            || route.ends_with(".rss")
+        {
+            return path.to_string();
+        }
+        format!("{route}.json{query}")
+    }
+}
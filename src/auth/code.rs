@@ -1,14 +1,14 @@
 use std::fmt::{Debug, Formatter};
 use std::time::{SystemTime, UNIX_EPOCH};
 
-use crate::auth::{TokenResponseData, AUTH_CONTENT_TYPE};
-use crate::{Authenticator, Authorized, utils};
+use crate::auth::{BearerCache, TokenResponseData, AUTH_CONTENT_TYPE};
+use crate::{utils, Authenticator, Authorized};
 use async_trait::async_trait;
 use log::warn;
 use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION, CONTENT_TYPE, USER_AGENT};
 use reqwest::{Body, Client};
 
-use crate::error::http_error::IntoResult;
+use crate::error::check_response;
 use crate::error::internal_error::InternalError;
 use crate::error::Error;
 
@@ -16,6 +16,7 @@ use crate::error::Error;
 pub struct CodeAuthenticator {
     /// Token
     pub token: Option<String>,
+    pub(crate) bearer: BearerCache,
     /// When does it expire
     pub expiration_time: Option<u128>,
     /// Refresh token
@@ -55,6 +56,7 @@ impl CodeAuthenticator {
     ) -> CodeAuthenticator {
         CodeAuthenticator {
             token: None,
+            bearer: BearerCache::default(),
             expiration_time: None,
             refresh_token: None,
             client_id: client_id.into(),
@@ -97,7 +99,7 @@ impl Authenticator for CodeAuthenticator {
         let mut header = HeaderMap::new();
         header.insert(
             AUTHORIZATION,
-            HeaderValue::from_str(&*format!(
+            HeaderValue::from_str(&format!(
                 "Basic {}",
                 utils::basic_header(&self.client_id, &self.client_secret)
             ))
@@ -115,11 +117,12 @@ impl Authenticator for CodeAuthenticator {
             .send()
             .await
             .map_err(InternalError::from)?;
-        response.status().into_result()?;
+        let response = check_response(response).await?;
 
         let token: TokenResponseData = response.json().await?;
 
         self.token = Some(token.access_token);
+        self.bearer.update(self.token.as_deref());
         let x = token.expires_in * 1000;
         let x1 = (x as u128)
             + SystemTime::now()
@@ -153,8 +156,9 @@ impl Authenticator for CodeAuthenticator {
             .headers(header)
             .send()
             .await?;
-        response.status().into_result()?;
+        check_response(response).await?;
         self.token = None;
+        self.bearer.update(None);
         self.expiration_time = None;
         self.refresh_token = None;
         Ok(())
@@ -162,14 +166,13 @@ impl Authenticator for CodeAuthenticator {
     /// Returns true if successful
     async fn token_refresh(&mut self, client: &Client, user_agent: &str) -> Result<bool, Error> {
         let url = "https://www.reddit.com/api/v1/access_token";
-        let body = format!(
-            "grant_type=refresh_token&refresh_token={}",
-            &self.refresh_token.to_owned().unwrap()
-        );
+        // Without a refresh token the user has to authorize the app again
+        let refresh_token = self.refresh_token.as_deref().ok_or(Error::TokenExpired)?;
+        let body = format!("grant_type=refresh_token&refresh_token={refresh_token}");
         let mut header = HeaderMap::new();
         header.insert(
             AUTHORIZATION,
-            HeaderValue::from_str(&*format!(
+            HeaderValue::from_str(&format!(
                 "Basic {}",
                 utils::basic_header(&self.client_id, &self.client_secret)
             ))
@@ -187,11 +190,12 @@ impl Authenticator for CodeAuthenticator {
             .send()
             .await
             .map_err(InternalError::from)?;
-        response.status().into_result()?;
+        let response = check_response(response).await?;
 
         let token: TokenResponseData = response.json().await?;
 
         self.token = Some(token.access_token);
+        self.bearer.update(self.token.as_deref());
         let x = token.expires_in * 1000;
         let x1 = (x as u128)
             + SystemTime::now()
@@ -204,10 +208,9 @@ impl Authenticator for CodeAuthenticator {
     // headers
     fn headers(&self, headers: &mut HeaderMap) {
         if let Some(token) = self.token.as_ref() {
-            headers.insert(
-                AUTHORIZATION,
-                HeaderValue::from_str(&format!("Bearer {}", token)).unwrap(),
-            );
+            if let Some(value) = self.bearer.header(token) {
+                headers.insert(AUTHORIZATION, value);
+            }
         } else {
             warn!("No token found");
         }
@@ -223,10 +226,9 @@ impl Authenticator for CodeAuthenticator {
             .unwrap()
             .as_millis();
         if self.refresh_token.is_some() {
-            if self.expiration_time.is_none() {
-                true
-            } else {
-                i >= self.expiration_time.unwrap()
+            match self.expiration_time {
+                None => true,
+                Some(expiration_time) => i >= expiration_time,
             }
         } else {
             false
@@ -235,6 +237,12 @@ impl Authenticator for CodeAuthenticator {
     fn get_refresh_token(&self) -> Option<String> {
         self.refresh_token.to_owned()
     }
+    /// Clears the token but keeps the refresh token. The next request refreshes it
+    fn invalidate_token(&mut self) {
+        self.token = None;
+        self.bearer.update(None);
+        self.expiration_time = None;
+    }
 }
 
 impl Authorized for CodeAuthenticator {}
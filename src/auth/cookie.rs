@@ -0,0 +1,162 @@
+use std::fmt::{Debug, Formatter};
+
+use crate::access::WEB_HOST;
+use crate::auth::AUTH_CONTENT_TYPE;
+use crate::responses::api::JsonResponse;
+use crate::Authenticator;
+use async_trait::async_trait;
+use log::warn;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue, CONTENT_TYPE, COOKIE, USER_AGENT};
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+
+use crate::error::check_response;
+use crate::error::internal_error::InternalError;
+use crate::error::Error;
+use crate::utils::form_body;
+
+/// The header Reddit accepts the modhash in
+pub static MODHASH_HEADER: HeaderName = HeaderName::from_static("x-modhash");
+
+/// The `data` of the `/api/login` response
+#[derive(Deserialize, Serialize, Clone, PartialEq)]
+pub struct CookieLoginData {
+    pub modhash: String,
+    /// The value of the `reddit_session` cookie
+    pub cookie: String,
+    #[serde(default)]
+    pub need_https: bool,
+}
+
+impl Debug for CookieLoginData {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[CookieLoginData]")
+    }
+}
+
+/// Legacy cookie based login using `/api/login`. This does not support OAuth.
+///
+/// Endpoints that require OAuth will return [Error::NotLoggedIn]. The modhash is sent as the `uh` value of form posts
+#[derive(Clone)]
+pub struct CookieAuthenticator {
+    /// The `reddit_session` cookie
+    pub cookie: Option<String>,
+    /// The modhash used to prevent CSRF
+    pub modhash: Option<String>,
+    /// Username
+    username: String,
+    /// Password
+    password: String,
+    /// Where `/api/login` is sent. [WEB_HOST] unless the Client has a base url
+    host: String,
+}
+
+impl Debug for CookieAuthenticator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "[CookieAuthenticator] Cookie Defined: {}",
+            self.cookie.is_some()
+        )
+    }
+}
+
+impl CookieAuthenticator {
+    /// Creates a new Authenticator
+    pub fn new<S: Into<String>>(username: S, password: S) -> CookieAuthenticator {
+        CookieAuthenticator {
+            cookie: None,
+            modhash: None,
+            username: username.into(),
+            password: password.into(),
+            host: WEB_HOST.to_string(),
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl Authenticator for CookieAuthenticator {
+    /// Logs in
+    async fn login(&mut self, client: &Client, user_agent: &str) -> Result<bool, Error> {
+        let url = format!("{}/api/login/{}", self.host, &self.username);
+        let body = form_body(&[
+            ("api_type", "json"),
+            ("user", &self.username),
+            ("passwd", &self.password),
+            ("rem", "true"),
+        ]);
+        let mut header = HeaderMap::new();
+        let user_agent = HeaderValue::from_str(user_agent).map_err(|error| {
+            Error::InvalidOption(format!("{user_agent:?} is not a valid user agent: {error}"))
+        })?;
+        header.insert(USER_AGENT, user_agent);
+        header.insert(CONTENT_TYPE, AUTH_CONTENT_TYPE.clone());
+        let response = client
+            .post(url)
+            .body(body)
+            .headers(header)
+            .send()
+            .await
+            .map_err(InternalError::from)?;
+        let response = check_response(response).await?;
+        let response: JsonResponse<CookieLoginData> = response.json().await?;
+        let data = response
+            .into_result()?
+            .ok_or_else(|| Error::from("Reddit did not return a session"))?;
+        self.cookie = Some(data.cookie);
+        self.modhash = Some(data.modhash);
+        Ok(true)
+    }
+    /// Forgets the session
+    async fn logout(&mut self, _client: &Client, _user_agent: &str) -> Result<(), Error> {
+        self.cookie = None;
+        self.modhash = None;
+        Ok(())
+    }
+    /// Logs in again
+    async fn token_refresh(&mut self, client: &Client, user_agent: &str) -> Result<bool, Error> {
+        self.login(client, user_agent).await
+    }
+    /// The session cookie and modhash
+    fn headers(&self, headers: &mut HeaderMap) {
+        if let Some(cookie) = self.cookie.as_ref() {
+            if let Ok(value) = HeaderValue::from_str(&format!("reddit_session={cookie}")) {
+                headers.insert(COOKIE, value);
+            }
+        } else {
+            warn!("No cookie found");
+        }
+        if let Some(value) = self
+            .modhash
+            .as_ref()
+            .and_then(|modhash| HeaderValue::from_str(modhash).ok())
+        {
+            headers.insert(MODHASH_HEADER.clone(), value);
+        }
+    }
+    /// False
+    fn oauth(&self) -> bool {
+        false
+    }
+    /// True if not logged in
+    fn needs_token_refresh(&self) -> bool {
+        self.cookie.is_none()
+    }
+    /// Always None
+    fn get_refresh_token(&self) -> Option<String> {
+        None
+    }
+    /// Logs in through the base url
+    fn set_base_url(&mut self, base_url: &str) {
+        self.host = base_url.to_string();
+    }
+    /// The modhash from the login
+    fn modhash(&self) -> Option<String> {
+        self.modhash.clone()
+    }
+    /// Clears the session. The next request logs in again
+    fn invalidate_token(&mut self) {
+        self.cookie = None;
+        self.modhash = None;
+    }
+}
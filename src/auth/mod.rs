@@ -3,22 +3,55 @@ use std::fmt::{Debug, Formatter};
 use async_trait::async_trait;
 use reqwest::header::{HeaderMap, HeaderValue};
 use reqwest::Client;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use crate::error::Error;
 
 mod code;
+mod cookie;
 mod password;
+mod scope;
 mod token;
 
 pub use code::CodeAuthenticator;
+pub use cookie::{CookieAuthenticator, CookieLoginData};
 pub use password::PasswordAuthenticator;
+pub use scope::{Scope, ScopeDescription, ScopeDescriptions};
 pub use token::TokenAuthenticator;
 
 pub static AUTH_CONTENT_TYPE: HeaderValue =
     HeaderValue::from_static("application/x-www-form-urlencoded");
 
-#[derive(Deserialize, Debug)]
+/// The `Authorization` header for the current token. Built once per token instead of on every request.
+///
+/// The token fields are public so the cached value is only used while it matches the token
+#[derive(Clone, Default)]
+pub(crate) struct BearerCache(Option<HeaderValue>);
+
+impl BearerCache {
+    /// Rebuilds the header. Call after the token changes
+    pub(crate) fn update(&mut self, token: Option<&str>) {
+        self.0 = token.and_then(bearer_header);
+    }
+    /// The header for `token`. Built from scratch if the cache is stale
+    pub(crate) fn header(&self, token: &str) -> Option<HeaderValue> {
+        match &self.0 {
+            Some(value) if value.as_bytes().strip_prefix(b"Bearer ") == Some(token.as_bytes()) => {
+                Some(value.clone())
+            }
+            _ => bearer_header(token),
+        }
+    }
+}
+
+/// `Bearer {token}` marked as sensitive
+pub(crate) fn bearer_header(token: &str) -> Option<HeaderValue> {
+    let mut value = HeaderValue::from_str(&format!("Bearer {token}")).ok()?;
+    value.set_sensitive(true);
+    Some(value)
+}
+
+#[derive(Deserialize, Serialize, Clone, PartialEq)]
 pub struct TokenResponseData {
     pub access_token: String,
     pub expires_in: u64,
@@ -28,6 +61,18 @@ pub struct TokenResponseData {
     pub refresh_token: String,
 }
 
+impl Debug for TokenResponseData {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "[TokenResponseData] Scope: {} Expires In: {} Refresh Token Defined: {}",
+            self.scope,
+            self.expires_in,
+            !self.refresh_token.is_empty()
+        )
+    }
+}
+
 fn default_response() -> String {
     "".to_string()
 }
@@ -49,11 +94,24 @@ pub trait Authenticator: Clone + Send + Sync + Debug {
     fn needs_token_refresh(&self) -> bool;
     /// Returns refresh token
     fn get_refresh_token(&self) -> Option<String>;
+    /// The modhash sent as `uh` in form posts. Only used by cookie based logins
+    fn modhash(&self) -> Option<String> {
+        None
+    }
+    /// Sends the login requests to [ClientBuilder::base_url](crate::builder::ClientBuilder::base_url) instead of Reddit.
+    /// Called before logging in. Does nothing by default
+    fn set_base_url(&mut self, _base_url: &str) {}
+    /// Forgets the token without telling Reddit so the next request logs in again.
+    /// Unlike [Authenticator::logout] the token is not revoked. Does nothing by default
+    fn invalidate_token(&mut self) {}
 }
 
 pub trait Authorized: Authenticator {}
 
-/// AnonymousAuthenticator
+/// Reads without logging in. Requests go to [WEB_HOST](crate::access::WEB_HOST) without an `Authorization` header.
+///
+/// Passed to the Client by value. No lock is taken for it on a request. With `shared_authentication` the Client
+/// reads it through an `ArcSwap` otherwise it owns it directly. `cargo bench --bench authenticator` compares the two with a Mutex
 #[derive(Clone, Default)]
 pub struct AnonymousAuthenticator;
 
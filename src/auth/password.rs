@@ -1,14 +1,14 @@
 use std::fmt::{Debug, Formatter};
 use std::time::{SystemTime, UNIX_EPOCH};
 
-use crate::auth::{TokenResponseData, AUTH_CONTENT_TYPE};
-use crate::{Authenticator, Authorized, utils};
+use crate::auth::{BearerCache, TokenResponseData, AUTH_CONTENT_TYPE};
+use crate::{utils, Authenticator, Authorized};
 use async_trait::async_trait;
 use log::warn;
 use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION, CONTENT_TYPE, USER_AGENT};
 use reqwest::{Body, Client};
 
-use crate::error::http_error::IntoResult;
+use crate::error::check_response;
 use crate::error::internal_error::InternalError;
 use crate::error::Error;
 
@@ -16,6 +16,7 @@ use crate::error::Error;
 pub struct PasswordAuthenticator {
     /// Token
     pub token: Option<String>,
+    bearer: BearerCache,
     /// When does it expire
     pub expiration_time: Option<u128>,
     /// Client ID
@@ -50,6 +51,7 @@ impl PasswordAuthenticator {
     ) -> PasswordAuthenticator {
         PasswordAuthenticator {
             token: None,
+            bearer: BearerCache::default(),
             expiration_time: None,
             client_id: client_id.into(),
             client_secret: client_secret.into(),
@@ -71,7 +73,7 @@ impl Authenticator for PasswordAuthenticator {
         let mut header = HeaderMap::new();
         header.insert(
             AUTHORIZATION,
-            HeaderValue::from_str(&*format!(
+            HeaderValue::from_str(&format!(
                 "Basic {}",
                 utils::basic_header(&self.client_id, &self.client_secret)
             ))
@@ -86,10 +88,11 @@ impl Authenticator for PasswordAuthenticator {
             .send()
             .await
             .map_err(InternalError::from)?;
-        response.status().into_result()?;
+        let response = check_response(response).await?;
 
         let token: TokenResponseData = response.json().await?;
         self.token = Some(token.access_token);
+        self.bearer.update(self.token.as_deref());
         let x = token.expires_in * 1000;
         let x1 = (x as u128)
             + SystemTime::now()
@@ -116,8 +119,9 @@ impl Authenticator for PasswordAuthenticator {
             .headers(header)
             .send()
             .await?;
-        response.status().into_result()?;
+        check_response(response).await?;
         self.token = None;
+        self.bearer.update(None);
         self.expiration_time = None;
         Ok(())
     }
@@ -128,10 +132,9 @@ impl Authenticator for PasswordAuthenticator {
     /// headers
     fn headers(&self, headers: &mut HeaderMap) {
         if let Some(token) = self.token.as_ref() {
-            headers.insert(
-                AUTHORIZATION,
-                HeaderValue::from_str(&format!("Bearer {}", token)).unwrap(),
-            );
+            if let Some(value) = self.bearer.header(token) {
+                headers.insert(AUTHORIZATION, value);
+            }
         } else {
             warn!("No token found");
         }
@@ -146,16 +149,21 @@ impl Authenticator for PasswordAuthenticator {
             .duration_since(UNIX_EPOCH)
             .unwrap()
             .as_millis();
-        if self.expiration_time.is_none() {
-            true
-        } else {
-            i >= self.expiration_time.unwrap()
+        match self.expiration_time {
+            None => true,
+            Some(expiration_time) => i >= expiration_time,
         }
     }
     /// Always None
     fn get_refresh_token(&self) -> Option<String> {
         Option::None
     }
+    /// Clears the token. The next request logs in with the password
+    fn invalidate_token(&mut self) {
+        self.token = None;
+        self.bearer.update(None);
+        self.expiration_time = None;
+    }
 }
 
 impl Authorized for PasswordAuthenticator {}
@@ -0,0 +1,146 @@
+//! OAuth scopes. Reddit describes each of them at `/api/v1/scopes`
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+/// An OAuth scope. Requested when authorizing an app and required by most endpoints
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Scope {
+    Account,
+    Creddits,
+    Edit,
+    Flair,
+    History,
+    Identity,
+    LiveManage,
+    ModConfig,
+    ModContributors,
+    ModFlair,
+    ModLog,
+    ModMail,
+    ModNote,
+    ModOthers,
+    ModPosts,
+    ModSelf,
+    ModTraffic,
+    ModWiki,
+    MySubreddits,
+    PrivateMessages,
+    Read,
+    Report,
+    Save,
+    StructuredStyles,
+    Submit,
+    Subscribe,
+    Vote,
+    WikiEdit,
+    WikiRead,
+}
+
+impl Scope {
+    /// Every scope in the order of their ids
+    pub const ALL: [Scope; 29] = [
+        Scope::Account,
+        Scope::Creddits,
+        Scope::Edit,
+        Scope::Flair,
+        Scope::History,
+        Scope::Identity,
+        Scope::LiveManage,
+        Scope::ModConfig,
+        Scope::ModContributors,
+        Scope::ModFlair,
+        Scope::ModLog,
+        Scope::ModMail,
+        Scope::ModNote,
+        Scope::ModOthers,
+        Scope::ModPosts,
+        Scope::ModSelf,
+        Scope::ModTraffic,
+        Scope::ModWiki,
+        Scope::MySubreddits,
+        Scope::PrivateMessages,
+        Scope::Read,
+        Scope::Report,
+        Scope::Save,
+        Scope::StructuredStyles,
+        Scope::Submit,
+        Scope::Subscribe,
+        Scope::Vote,
+        Scope::WikiEdit,
+        Scope::WikiRead,
+    ];
+    /// The id Reddit uses. Such as `mysubreddits`
+    pub fn id(&self) -> &'static str {
+        match self {
+            Scope::Account => "account",
+            Scope::Creddits => "creddits",
+            Scope::Edit => "edit",
+            Scope::Flair => "flair",
+            Scope::History => "history",
+            Scope::Identity => "identity",
+            Scope::LiveManage => "livemanage",
+            Scope::ModConfig => "modconfig",
+            Scope::ModContributors => "modcontributors",
+            Scope::ModFlair => "modflair",
+            Scope::ModLog => "modlog",
+            Scope::ModMail => "modmail",
+            Scope::ModNote => "modnote",
+            Scope::ModOthers => "modothers",
+            Scope::ModPosts => "modposts",
+            Scope::ModSelf => "modself",
+            Scope::ModTraffic => "modtraffic",
+            Scope::ModWiki => "modwiki",
+            Scope::MySubreddits => "mysubreddits",
+            Scope::PrivateMessages => "privatemessages",
+            Scope::Read => "read",
+            Scope::Report => "report",
+            Scope::Save => "save",
+            Scope::StructuredStyles => "structuredstyles",
+            Scope::Submit => "submit",
+            Scope::Subscribe => "subscribe",
+            Scope::Vote => "vote",
+            Scope::WikiEdit => "wikiedit",
+            Scope::WikiRead => "wikiread",
+        }
+    }
+    /// None if Reddit added a scope this version does not know
+    pub fn from_id(id: &str) -> Option<Scope> {
+        Scope::ALL.into_iter().find(|scope| scope.id() == id)
+    }
+    /// The ids joined with `,`. As the `scope` of an authorization URL
+    /// ```
+    /// use rraw::auth::Scope;
+    /// assert_eq!(Scope::join(&[Scope::Identity, Scope::Read]), "identity,read");
+    /// ```
+    pub fn join(scopes: &[Scope]) -> String {
+        scopes.iter().map(Scope::id).collect::<Vec<_>>().join(",")
+    }
+}
+
+impl Display for Scope {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.id())
+    }
+}
+
+/// What a scope lets an app do. Meant to be shown on a consent screen
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+pub struct ScopeDescription {
+    pub id: String,
+    /// Such as `My Subreddits`
+    pub name: String,
+    pub description: String,
+}
+
+impl ScopeDescription {
+    /// None if Reddit added a scope this version does not know
+    pub fn scope(&self) -> Option<Scope> {
+        Scope::from_id(&self.id)
+    }
+}
+
+/// The scopes by id. From `/api/v1/scopes`
+pub type ScopeDescriptions = BTreeMap<String, ScopeDescription>;
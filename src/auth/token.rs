@@ -1,14 +1,14 @@
 use std::fmt::{Debug, Formatter};
 use std::time::{SystemTime, UNIX_EPOCH};
 
-use crate::auth::{CodeAuthenticator, TokenResponseData, AUTH_CONTENT_TYPE};
-use crate::{Authenticator, Authorized, utils};
+use crate::auth::{BearerCache, CodeAuthenticator, TokenResponseData, AUTH_CONTENT_TYPE};
+use crate::{utils, Authenticator, Authorized};
 use async_trait::async_trait;
 use log::warn;
 use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION, CONTENT_TYPE, USER_AGENT};
 use reqwest::{Body, Client};
 
-use crate::error::http_error::IntoResult;
+use crate::error::check_response;
 use crate::error::internal_error::InternalError;
 use crate::error::Error;
 
@@ -16,6 +16,7 @@ use crate::error::Error;
 pub struct TokenAuthenticator {
     /// Token
     pub token: Option<String>,
+    bearer: BearerCache,
     /// When does it expire
     pub expiration_time: Option<u128>,
     /// Refresh token
@@ -49,6 +50,7 @@ impl TokenAuthenticator {
     ) -> TokenAuthenticator {
         TokenAuthenticator {
             token: None,
+            bearer: BearerCache::default(),
             expiration_time: None,
             refresh_token: refresh_token.into(),
             client_id: client_id.into(),
@@ -70,7 +72,7 @@ impl Authenticator for TokenAuthenticator {
 
         header.insert(
             AUTHORIZATION,
-            HeaderValue::from_str(&*format!(
+            HeaderValue::from_str(&format!(
                 "Basic {}",
                 utils::basic_header(&self.client_id, &self.client_secret)
             ))
@@ -88,10 +90,11 @@ impl Authenticator for TokenAuthenticator {
             .send()
             .await
             .map_err(InternalError::from)?;
-        response.status().into_result()?;
+        let response = check_response(response).await?;
 
         let token: TokenResponseData = response.json().await?;
         self.token = Some(token.access_token);
+        self.bearer.update(self.token.as_deref());
         let x = token.expires_in * 1000;
         let x1 = (x as u128)
             + SystemTime::now()
@@ -118,8 +121,9 @@ impl Authenticator for TokenAuthenticator {
             .headers(header)
             .send()
             .await?;
-        response.status().into_result()?;
+        check_response(response).await?;
         self.token = None;
+        self.bearer.update(None);
         self.expiration_time = None;
         self.refresh_token = "".to_string();
         Ok(())
@@ -131,10 +135,9 @@ impl Authenticator for TokenAuthenticator {
     /// headers
     fn headers(&self, headers: &mut HeaderMap) {
         if let Some(token) = self.token.as_ref() {
-            headers.insert(
-                AUTHORIZATION,
-                HeaderValue::from_str(&format!("Bearer {}", token)).unwrap(),
-            );
+            if let Some(value) = self.bearer.header(token) {
+                headers.insert(AUTHORIZATION, value);
+            }
         } else {
             warn!("No token found");
         }
@@ -150,10 +153,9 @@ impl Authenticator for TokenAuthenticator {
             .unwrap()
             .as_millis();
         if !self.refresh_token.is_empty() {
-            if self.expiration_time.is_none() {
-                true
-            } else {
-                i >= self.expiration_time.unwrap()
+            match self.expiration_time {
+                None => true,
+                Some(expiration_time) => i >= expiration_time,
             }
         } else {
             false
@@ -162,6 +164,12 @@ impl Authenticator for TokenAuthenticator {
     fn get_refresh_token(&self) -> Option<String> {
         Some(self.refresh_token.to_owned())
     }
+    /// Clears the token but keeps the refresh token. The next request refreshes it
+    fn invalidate_token(&mut self) {
+        self.token = None;
+        self.bearer.update(None);
+        self.expiration_time = None;
+    }
 }
 
 impl Authorized for TokenAuthenticator {}
@@ -175,6 +183,7 @@ impl TryInto<TokenAuthenticator> for CodeAuthenticator {
         }
         Ok(TokenAuthenticator {
             token: self.token,
+            bearer: self.bearer,
             expiration_time: self.expiration_time,
             refresh_token: self.refresh_token.unwrap(),
             client_id: self.client_id,
@@ -0,0 +1,214 @@
+//! Batches `/api/info` lookups made at the same time into one request
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::Duration;
+
+use futures_util::future::{select, Either};
+use futures_util::pin_mut;
+use log::trace;
+use tokio::sync::{oneshot, Notify};
+
+use crate::access::Access;
+use crate::auth::Authenticator;
+use crate::error::Error;
+use crate::responses::listing::RedditListing;
+use crate::responses::RedditTypeResponse;
+use crate::Client;
+
+/// The most fullnames Reddit accepts in one `/api/info` request
+pub const MAX_BATCH: usize = 100;
+
+type Reply = Result<Option<RedditTypeResponse>, Error>;
+
+struct Waiter {
+    /// Tells the leader which batch holds its own waiter
+    id: u64,
+    fullname: String,
+    sender: oneshot::Sender<Reply>,
+}
+
+#[derive(Default)]
+struct Pending {
+    waiters: Vec<Waiter>,
+    /// A caller is waiting out the window to send the batch
+    leader: bool,
+    next_id: u64,
+}
+
+/// Collects [InfoBatcher::get] calls for up to `window` or [MAX_BATCH] fullnames and looks them up with one `/api/info` request.
+///
+/// There is no background task. The first caller of a batch waits out the window and sends the request for everyone.
+/// Once the batch with its own fullname is sent, or its future is dropped, another waiting caller takes over.
+/// If the request fails every caller of the batch gets [Error::Batch] wrapping the same error
+/// ```no_run
+/// #[tokio::main]
+/// async fn main() ->anyhow::Result<()>{
+///    use std::time::Duration;
+///    use futures_util::future::join_all;
+///    use rraw::auth::AnonymousAuthenticator;
+///    use rraw::Client;
+///    let client = Client::login(AnonymousAuthenticator::new(), "RRAW Test (by u/KingTuxWH)").await?;
+///    let batcher = client.info_batcher(Duration::from_millis(50));
+///    let things = join_all(["t3_15bfi0", "t1_jtq1a2b"].iter().map(|fullname| batcher.get(fullname))).await;
+///    Ok(())
+/// }
+/// ```
+pub struct InfoBatcher<'a, A: Authenticator> {
+    client: &'a Client<A>,
+    window: Duration,
+    pending: Mutex<Pending>,
+    /// The batch reached [MAX_BATCH]
+    full: Notify,
+    /// The leader was dropped before sending the batch
+    vacant: Notify,
+}
+
+impl<'a, A: Authenticator> InfoBatcher<'a, A> {
+    pub(crate) fn new(client: &'a Client<A>, window: Duration) -> InfoBatcher<'a, A> {
+        InfoBatcher {
+            client,
+            window,
+            pending: Mutex::default(),
+            full: Notify::new(),
+            vacant: Notify::new(),
+        }
+    }
+    /// Looks up the thing with the next batch. None if Reddit did not return it
+    pub async fn get(&self, fullname: &str) -> Result<Option<RedditTypeResponse>, Error> {
+        loop {
+            let (sender, receiver) = oneshot::channel();
+            let (id, leader) = self.push(fullname, sender);
+            if leader {
+                self.lead(id).await;
+            }
+            if let Some(reply) = self.wait(id, receiver).await {
+                return reply;
+            }
+            trace!("The batch with {fullname} was dropped before it was sent. Adding it again");
+        }
+    }
+    /// Adds the waiter. Returns its id and true if the caller is now the leader
+    fn push(&self, fullname: &str, sender: oneshot::Sender<Reply>) -> (u64, bool) {
+        let mut pending = self.lock();
+        let id = pending.next_id;
+        pending.next_id += 1;
+        pending.waiters.push(Waiter {
+            id,
+            fullname: fullname.to_string(),
+            sender,
+        });
+        if pending.waiters.len() >= MAX_BATCH {
+            self.full.notify_one();
+        }
+        (id, !std::mem::replace(&mut pending.leader, true))
+    }
+    /// None if the batch was dropped before it was sent
+    async fn wait(&self, id: u64, mut receiver: oneshot::Receiver<Reply>) -> Option<Reply> {
+        loop {
+            let vacant = self.vacant.notified();
+            pin_mut!(vacant);
+            match select(&mut receiver, vacant).await {
+                Either::Left((reply, _)) => return reply.ok(),
+                Either::Right(((), _)) => {
+                    if self.claim() {
+                        self.lead(id).await;
+                    }
+                }
+            }
+        }
+    }
+    /// Becomes the leader if there is none and callers are waiting
+    fn claim(&self) -> bool {
+        let mut pending = self.lock();
+        if pending.leader || pending.waiters.is_empty() {
+            return false;
+        }
+        pending.leader = true;
+        true
+    }
+    /// Sends batches until the waiter `id` is no longer pending. Then a waiting caller takes over
+    async fn lead(&self, id: u64) {
+        let mut vacate = Vacate {
+            batcher: self,
+            armed: true,
+        };
+        loop {
+            let _ = tokio::time::timeout(self.window, self.full.notified()).await;
+            let (batch, done) = {
+                let mut pending = self.lock();
+                let len = pending.waiters.len().min(MAX_BATCH);
+                let batch: Vec<Waiter> = pending.waiters.drain(..len).collect();
+                // Its own waiter may already be in a batch sent by the leader before
+                let done = !pending.waiters.iter().any(|waiter| waiter.id == id);
+                if done {
+                    self.release(&mut pending);
+                    vacate.armed = false;
+                }
+                (batch, done)
+            };
+            self.send(batch).await;
+            if done {
+                return;
+            }
+        }
+    }
+    /// Gives up leadership. A waiting caller is woken to take over
+    fn release(&self, pending: &mut Pending) {
+        pending.leader = false;
+        if !pending.waiters.is_empty() {
+            self.vacant.notify_one();
+        }
+    }
+    async fn send(&self, batch: Vec<Waiter>) {
+        let mut fullnames: Vec<&str> = Vec::with_capacity(batch.len());
+        for waiter in &batch {
+            if !fullnames.contains(&waiter.fullname.as_str()) {
+                fullnames.push(&waiter.fullname);
+            }
+        }
+        let url = format!("/api/info?id={}", fullnames.join(","));
+        match self
+            .client
+            .get_json::<RedditListing>(&url, Access::Any)
+            .await
+        {
+            Ok(listing) => {
+                let things: HashMap<String, RedditTypeResponse> = listing
+                    .data
+                    .children
+                    .into_iter()
+                    .filter_map(|thing| Some((thing.data.fullname()?, thing.data)))
+                    .collect();
+                for waiter in batch {
+                    let thing = things.get(&waiter.fullname).cloned();
+                    let _ = waiter.sender.send(Ok(thing));
+                }
+            }
+            Err(error) => {
+                let error = Arc::new(error);
+                for waiter in batch {
+                    let _ = waiter.sender.send(Err(Error::Batch(error.clone())));
+                }
+            }
+        }
+    }
+    fn lock(&self) -> MutexGuard<'_, Pending> {
+        self.pending.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// Gives up leadership if the leader is dropped before the batch with its own waiter was taken
+struct Vacate<'b, 'a, A: Authenticator> {
+    batcher: &'b InfoBatcher<'a, A>,
+    armed: bool,
+}
+
+impl<A: Authenticator> Drop for Vacate<'_, '_, A> {
+    fn drop(&mut self) {
+        if !self.armed {
+            return;
+        }
+        let mut pending = self.batcher.lock();
+        self.batcher.release(&mut pending);
+    }
+}
@@ -0,0 +1,218 @@
+use std::time::Duration;
+
+use reqwest::header::HeaderMap;
+use reqwest::redirect::{Attempt, Policy};
+use reqwest::{Client as ReqwestClient, ClientBuilder as ReqwestClientBuilder, Url};
+
+use crate::auth::Authenticator;
+use crate::cache::ResponseCache;
+use crate::error::Error;
+use crate::Client;
+
+/// The max amount of redirects followed by [default_redirect_policy]
+pub const MAX_REDIRECTS: usize = 10;
+
+/// Builds a [Client] with custom settings
+pub struct ClientBuilder<A: Authenticator> {
+    auth: A,
+    user_agent: String,
+    redirect_policy: Policy,
+    base_url: Option<String>,
+    raw_json: bool,
+    pool_max_idle_per_host: Option<usize>,
+    tcp_keepalive: Option<Duration>,
+    http2_prior_knowledge: bool,
+    log_bodies: bool,
+    response_cache: Option<usize>,
+    decompress: bool,
+    default_headers: HeaderMap,
+    #[cfg(feature = "shared_authentication")]
+    relogin_on_unauthorized: bool,
+}
+
+impl<A: Authenticator> ClientBuilder<A> {
+    /// Creates a new Builder with the default settings
+    pub fn new<S: Into<String>>(auth: A, user_agent: S) -> ClientBuilder<A> {
+        ClientBuilder {
+            auth,
+            user_agent: user_agent.into(),
+            redirect_policy: default_redirect_policy(),
+            base_url: None,
+            raw_json: false,
+            pool_max_idle_per_host: None,
+            tcp_keepalive: None,
+            http2_prior_knowledge: false,
+            log_bodies: false,
+            response_cache: None,
+            decompress: true,
+            default_headers: HeaderMap::new(),
+            #[cfg(feature = "shared_authentication")]
+            relogin_on_unauthorized: true,
+        }
+    }
+    /// Sets the redirect policy used by the underlying reqwest client.
+    ///
+    /// Defaults to [default_redirect_policy]. Redirects to the login page are detected and
+    /// returned as [Error::RequiresAuth] as long as the policy does not follow them
+    pub fn redirect_policy(mut self, policy: Policy) -> ClientBuilder<A> {
+        self.redirect_policy = policy;
+        self
+    }
+    /// Sends every request to `base_url` instead of Reddit's hosts. Such as `http://127.0.0.1:8080`.
+    ///
+    /// For proxies and tests. The [CookieAuthenticator](crate::auth::CookieAuthenticator) logs in through it as well.
+    /// OAuth authenticators still get their token from Reddit
+    pub fn base_url<S: Into<String>>(mut self, base_url: S) -> ClientBuilder<A> {
+        self.base_url = Some(base_url.into().trim_end_matches('/').to_string());
+        self
+    }
+    /// Adds `raw_json=1` to every GET request. Reddit then sends `body_html`, `selftext_html` and
+    /// other text fields without escaping `<`, `>` and `&`.
+    ///
+    /// Defaults to false
+    pub fn raw_json(mut self, raw_json: bool) -> ClientBuilder<A> {
+        self.raw_json = raw_json;
+        self
+    }
+    /// The max amount of idle connections kept open per host.
+    ///
+    /// Defaults to reqwest's default, which has no limit
+    pub fn pool_max_idle_per_host(mut self, max: usize) -> ClientBuilder<A> {
+        self.pool_max_idle_per_host = Some(max);
+        self
+    }
+    /// Sends TCP keepalive probes on idle connections at this interval.
+    ///
+    /// Defaults to reqwest's default, which sends none
+    pub fn tcp_keepalive(mut self, interval: Duration) -> ClientBuilder<A> {
+        self.tcp_keepalive = Some(interval);
+        self
+    }
+    /// Only speak HTTP/2 without negotiating it first. Reddit supports HTTP/2.
+    ///
+    /// A `base_url` that only speaks HTTP/1 will fail every request. Defaults to false
+    pub fn http2_prior_knowledge(mut self, http2: bool) -> ClientBuilder<A> {
+        self.http2_prior_knowledge = http2;
+        self
+    }
+    /// Logs every JSON response body at trace level.
+    ///
+    /// Listings can be several megabytes so this is off by default even when trace logging is on
+    pub fn log_bodies(mut self, log_bodies: bool) -> ClientBuilder<A> {
+        self.log_bodies = log_bodies;
+        self
+    }
+    /// Keeps up to `entries` GET responses that had an `ETag` or `Last-Modified` header.
+    ///
+    /// Requesting the same path again sends `If-None-Match` or `If-Modified-Since`. If Reddit responds with
+    /// `304 Not Modified` the cached body is parsed instead of downloading it again. Responses without either header are not kept.
+    /// The least recently used response is dropped once it is full. Disabled by default
+    pub fn response_cache(mut self, entries: usize) -> ClientBuilder<A> {
+        self.response_cache = Some(entries);
+        self
+    }
+    /// Decodes gzip responses. Enabled by default.
+    ///
+    /// When enabled every request sends `Accept-Encoding: gzip` and bodies are decoded before parsing.
+    /// When disabled requests do not send `Accept-Encoding` so Reddit responds uncompressed, except
+    /// [Client::get_bytes] which asks for gzip and returns the compressed stream exactly as it was received
+    pub fn decompress(mut self, decompress: bool) -> ClientBuilder<A> {
+        self.decompress = decompress;
+        self
+    }
+    /// Adds `headers` to every request except the ones made by the [Authenticator] to log in.
+    /// See [Client::with_default_headers] for which headers take precedence
+    pub fn default_headers(mut self, headers: HeaderMap) -> ClientBuilder<A> {
+        self.default_headers = headers;
+        self
+    }
+    /// Logs in again when Reddit responds to an OAuth request with `401 Unauthorized` and sends the request once more.
+    /// Such as after the password was changed or the app was revoked. If it is rejected again [Error::AuthenticationExpired]
+    /// is returned. Requests with a streamed body are not sent again.
+    ///
+    /// Enabled by default. When disabled the 401 is returned as is
+    #[cfg(feature = "shared_authentication")]
+    pub fn relogin_on_unauthorized(mut self, relogin: bool) -> ClientBuilder<A> {
+        self.relogin_on_unauthorized = relogin;
+        self
+    }
+    /// The reqwest client with the connection settings
+    #[cfg(not(target_arch = "wasm32"))]
+    fn reqwest_client(&self, policy: Policy) -> Result<ReqwestClient, Error> {
+        let mut client = ReqwestClientBuilder::new()
+            .user_agent(self.user_agent.clone())
+            .redirect(policy);
+        if let Some(max) = self.pool_max_idle_per_host {
+            client = client.pool_max_idle_per_host(max);
+        }
+        if let Some(interval) = self.tcp_keepalive {
+            client = client.tcp_keepalive(interval);
+        }
+        if self.http2_prior_knowledge {
+            client = client.http2_prior_knowledge();
+        }
+        if !self.decompress {
+            client = client.no_gzip();
+        }
+        Ok(client.build()?)
+    }
+    /// Creates the Client and completes the initial login steps
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn login(mut self) -> Result<Client<A>, Error> {
+        let policy = std::mem::replace(&mut self.redirect_policy, Policy::none());
+        let client = self.reqwest_client(policy)?;
+        let no_redirect = self.reqwest_client(Policy::none())?;
+        let mut auth = self.auth;
+        if let Some(base_url) = &self.base_url {
+            auth.set_base_url(base_url);
+        }
+        let mut client = Client::login_with_client(auth, self.user_agent, client, no_redirect)
+            .await?
+            .with_default_headers(self.default_headers);
+        client.base_url = self.base_url;
+        client.raw_json = self.raw_json;
+        client.log_bodies = self.log_bodies;
+        client.decompress = self.decompress;
+        #[cfg(feature = "shared_authentication")]
+        {
+            client.relogin_on_unauthorized = self.relogin_on_unauthorized;
+        }
+        client.cache = self
+            .response_cache
+            .map(|entries| std::sync::Arc::new(ResponseCache::new(entries)));
+        Ok(client)
+    }
+}
+
+/// Follows redirects as long as they stay on the same host or within Reddit's own hosts.
+///
+/// Redirects to the login page or a `.compact` page are never followed. Reddit sends anonymous
+/// requests to some endpoints there, and following it would result in an HTML page instead of JSON
+pub fn default_redirect_policy() -> Policy {
+    Policy::custom(|attempt: Attempt| {
+        if attempt.previous().len() > MAX_REDIRECTS {
+            return attempt.error("Too many redirects");
+        }
+        if is_login_wall(attempt.url()) {
+            return attempt.stop();
+        }
+        let previous_host = attempt.previous().last().and_then(Url::host_str);
+        let host = attempt.url().host_str();
+        let same_site = previous_host == host
+            || (previous_host.map_or(false, is_reddit_host) && host.map_or(false, is_reddit_host));
+        if same_site {
+            attempt.follow()
+        } else {
+            attempt.stop()
+        }
+    })
+}
+
+/// Is the URL the login page or a `.compact` page
+pub(crate) fn is_login_wall(url: &Url) -> bool {
+    url.path().starts_with("/login") || url.path().ends_with(".compact")
+}
+
+fn is_reddit_host(host: &str) -> bool {
+    host == "reddit.com" || host.ends_with(".reddit.com")
+}
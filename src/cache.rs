@@ -0,0 +1,112 @@
+//! Conditional GET requests. Responses with an `ETag` or `Last-Modified` header are kept with their body
+use std::collections::HashMap;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+use bytes::Bytes;
+
+use reqwest::header::{
+    HeaderMap, HeaderValue, ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED,
+};
+
+/// The path requested
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub(crate) struct CacheKey {
+    url: String,
+}
+
+impl CacheKey {
+    pub(crate) fn new(url: &str) -> CacheKey {
+        CacheKey {
+            url: url.to_string(),
+        }
+    }
+}
+
+struct Entry {
+    etag: Option<HeaderValue>,
+    last_modified: Option<HeaderValue>,
+    body: Bytes,
+    used: u64,
+}
+
+#[derive(Default)]
+struct Entries {
+    map: HashMap<CacheKey, Entry>,
+    tick: u64,
+}
+
+impl Entries {
+    fn touch(&mut self, key: &CacheKey) -> Option<&Entry> {
+        self.tick += 1;
+        let tick = self.tick;
+        let entry = self.map.get_mut(key)?;
+        entry.used = tick;
+        Some(entry)
+    }
+}
+
+/// Keeps up to `capacity` responses. The least recently used one is dropped first
+pub(crate) struct ResponseCache {
+    capacity: usize,
+    entries: Mutex<Entries>,
+}
+
+impl ResponseCache {
+    pub(crate) fn new(capacity: usize) -> ResponseCache {
+        ResponseCache {
+            capacity,
+            entries: Mutex::default(),
+        }
+    }
+    /// `If-None-Match` and `If-Modified-Since` for the cached response. None if nothing is cached
+    pub(crate) fn conditional_headers(&self, key: &CacheKey) -> Option<HeaderMap> {
+        let mut entries = self.lock();
+        let entry = entries.touch(key)?;
+        let mut headers = HeaderMap::new();
+        if let Some(etag) = &entry.etag {
+            headers.insert(IF_NONE_MATCH, etag.clone());
+        }
+        if let Some(last_modified) = &entry.last_modified {
+            headers.insert(IF_MODIFIED_SINCE, last_modified.clone());
+        }
+        Some(headers)
+    }
+    /// The cached body. For a `304 Not Modified` response
+    ///
+    /// [Bytes] is reference counted so the body is not copied
+    pub(crate) fn get(&self, key: &CacheKey) -> Option<Bytes> {
+        let mut entries = self.lock();
+        entries.touch(key).map(|entry| entry.body.clone())
+    }
+    /// Keeps the body if the response had a validator. Otherwise any older body is dropped
+    pub(crate) fn store(&self, key: CacheKey, headers: &HeaderMap, body: Bytes) {
+        let etag = headers.get(ETAG).cloned();
+        let last_modified = headers.get(LAST_MODIFIED).cloned();
+        let mut entries = self.lock();
+        if (etag.is_none() && last_modified.is_none()) || self.capacity == 0 {
+            entries.map.remove(&key);
+            return;
+        }
+        entries.tick += 1;
+        let entry = Entry {
+            etag,
+            last_modified,
+            body,
+            used: entries.tick,
+        };
+        entries.map.insert(key, entry);
+        if entries.map.len() > self.capacity {
+            let oldest = entries
+                .map
+                .iter()
+                .min_by_key(|(_, entry)| entry.used)
+                .map(|(key, _)| key.clone());
+            if let Some(oldest) = oldest {
+                entries.map.remove(&oldest);
+            }
+        }
+    }
+    fn lock(&self) -> MutexGuard<'_, Entries> {
+        self.entries.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
@@ -0,0 +1,75 @@
+pub mod response;
+
+use crate::access::Access;
+use crate::auth::Authorized;
+use crate::error::Error;
+use crate::utils::form_body;
+use crate::utils::fullname::{Fullname, FullnameKind};
+use crate::Client;
+
+/// Manages the collections of the subreddits you moderate.
+///
+/// Collections are created with [Subreddit::create_collection](crate::subreddit::Subreddit::create_collection)
+pub struct Collections<'a, A: Authorized> {
+    pub(crate) client: &'a Client<A>,
+}
+
+impl<'a, A: Authorized> Collections<'a, A> {
+    /// Adds the submission to the end of the collection. `link` may be the fullname or the id
+    pub async fn add_post(&self, collection_id: &str, link: &str) -> Result<(), Error> {
+        self.post_link(
+            "/api/v1/collections/add_post_to_collection",
+            collection_id,
+            link,
+        )
+        .await
+    }
+    /// Removes the submission from the collection. `link` may be the fullname or the id
+    pub async fn remove_post(&self, collection_id: &str, link: &str) -> Result<(), Error> {
+        self.post_link(
+            "/api/v1/collections/remove_post_in_collection",
+            collection_id,
+            link,
+        )
+        .await
+    }
+    /// Sets the order of the submissions. Every submission of the collection must be included
+    pub async fn reorder(&self, collection_id: &str, links: &[&str]) -> Result<(), Error> {
+        let links: Vec<String> = links
+            .iter()
+            .map(|link| Fullname::new(FullnameKind::Link, link).to_string())
+            .collect();
+        let links = links.join(",");
+        let body = form_body(&[("collection_id", collection_id), ("link_ids", &links)]);
+        self.client
+            .post_empty(
+                "/api/v1/collections/reorder_collection",
+                Access::OAuthOnly,
+                body,
+            )
+            .await
+    }
+    /// Deletes the collection. The submissions are not deleted
+    pub async fn delete_collection(&self, collection_id: &str) -> Result<(), Error> {
+        let body = form_body(&[("collection_id", collection_id)]);
+        self.client
+            .post_empty(
+                "/api/v1/collections/delete_collection",
+                Access::OAuthOnly,
+                body,
+            )
+            .await
+    }
+    async fn post_link(
+        &self,
+        endpoint: &str,
+        collection_id: &str,
+        link: &str,
+    ) -> Result<(), Error> {
+        let link = Fullname::new(FullnameKind::Link, link).to_string();
+        let body = form_body(&[("collection_id", collection_id), ("link_fullname", &link)]);
+        self.client
+            .post_empty(endpoint, Access::OAuthOnly, body)
+            .await
+    }
+}
@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+
+use crate::submission::response::{SubmissionResponse, SubmissionsResponse};
+
+/// A collection of submissions. Collection responses are plain JSON without a `kind`
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct Collection {
+    /// A UUID
+    pub collection_id: String,
+    pub title: String,
+    #[serde(default)]
+    pub description: String,
+    pub author_name: Option<String>,
+    /// The fullname of the author
+    pub author_id: Option<String>,
+    /// The fullname of the subreddit
+    pub subreddit_id: String,
+    pub permalink: Option<String>,
+    /// `TIMELINE` or `GALLERY`. None for the default layout
+    pub display_layout: Option<String>,
+    /// The fullnames of the submissions in the order of the collection
+    #[serde(default)]
+    pub link_ids: Vec<String>,
+    #[serde(default)]
+    pub created_at_utc: f64,
+    #[serde(default)]
+    pub last_update_utc: f64,
+    /// The submissions in the order of the collection. Only sent when the links were requested
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sorted_links: Option<SubmissionsResponse>,
+}
+
+impl Collection {
+    /// The submissions of [Collection::sorted_links]. Empty if the links were not requested
+    pub fn submissions(&self) -> impl Iterator<Item = &SubmissionResponse> {
+        self.sorted_links
+            .iter()
+            .flat_map(|listing| listing.data.children.iter())
+            .map(|child| &child.data)
+    }
+}
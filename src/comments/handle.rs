@@ -0,0 +1,62 @@
+use crate::auth::Authorized;
+use crate::comments::response::CommentResponse;
+use crate::error::http_error::HTTPError;
+use crate::error::Error;
+use crate::thing::private::Sealed;
+use crate::thing::{Deletable, Replyable, Reportable, Savable, Votable};
+use crate::utils::fullname::Fullname;
+use crate::Client;
+
+/// A comment known only by its fullname. Created with [Me::comment](crate::user::me::Me::comment)
+///
+/// Nothing is requested until an action is called. Use [CommentHandle::fetch] to load the comment
+pub struct CommentHandle<'a, A: Authorized> {
+    pub(crate) client: &'a Client<A>,
+    pub(crate) fullname: Fullname,
+}
+
+impl<'a, A: Authorized> Sealed for CommentHandle<'a, A> {
+    type Auth = A;
+    fn client(&self) -> &Client<A> {
+        self.client
+    }
+    fn thing_fullname(&self) -> String {
+        self.fullname.to_string()
+    }
+}
+
+impl<'a, A: Authorized> Votable for CommentHandle<'a, A> {}
+impl<'a, A: Authorized> Savable for CommentHandle<'a, A> {}
+impl<'a, A: Authorized> Reportable for CommentHandle<'a, A> {}
+impl<'a, A: Authorized> Replyable for CommentHandle<'a, A> {}
+impl<'a, A: Authorized> Deletable for CommentHandle<'a, A> {}
+
+impl<'a, A: Authorized> CommentHandle<'a, A> {
+    pub fn fullname(&self) -> &Fullname {
+        &self.fullname
+    }
+    /// Loads the comment with `/api/info`. A comment that does not exist is [HTTPError::NotFound].
+    ///
+    /// The replies are not included
+    pub async fn fetch(&self) -> Result<CommentResponse, Error> {
+        self.client
+            .info_single(&self.fullname.to_string())
+            .await?
+            .ok_or_else(|| HTTPError::NotFound.into())
+    }
+    /// Removes the comment as a moderator. `spam` also trains the spam filter
+    pub async fn remove(&self, spam: bool) -> Result<(), Error> {
+        self.client
+            .remove_thing(&self.fullname.to_string(), spam)
+            .await
+    }
+    /// Approves the comment as a moderator. Undoes a removal
+    pub async fn approve(&self) -> Result<(), Error> {
+        self.action("/api/approve").await
+    }
+    async fn action(&self, endpoint: &str) -> Result<(), Error> {
+        self.client
+            .thing_action(endpoint, &self.fullname.to_string())
+            .await
+    }
+}
@@ -1,6 +1,16 @@
+pub mod handle;
 pub mod response;
+pub mod tree;
 
-use crate::auth::Authenticator;
+use crate::access::Access;
+use crate::auth::{Authenticator, Authorized};
+use crate::comments::response::{CommentResponse, CommentsResponse};
+use crate::error::http_error::HTTPError;
+use crate::subreddit::Subreddit;
+use crate::thing::private::Sealed;
+use crate::thing::{Deletable, Replyable, Reportable, Savable, Votable};
+use crate::user::User;
+use crate::utils::form_body;
 use crate::utils::options::CommentOption;
 use crate::Client;
 use async_trait::async_trait;
@@ -43,6 +53,92 @@ impl<'a, A: Authenticator, T: CommentType<'a>> CommentRetriever for Comment<'a,
         if let Some(options) = sort {
             options.extend(&mut path)
         }
-        return self.me.get_json::<ListingArray>(&path, false, false).await;
+        return self.me.get_json::<ListingArray>(&path, Access::Any).await;
+    }
+}
+
+impl<'a, A: Authenticator> Comment<'a, A, CommentResponse> {
+    /// Loads the author. A deleted author is [HTTPError::NotFound] without a request
+    pub async fn author(&self) -> Result<User<'a, A>, Error> {
+        match self.comment.author.as_deref() {
+            Some(author) if !self.comment.is_deleted() => self.me.user(author).await,
+            _ => Err(HTTPError::NotFound.into()),
+        }
+    }
+    /// The author without a request. Only the name and id of the [User] are set until [User::about] is loaded.
+    /// None if the author is deleted
+    pub fn author_user(&self) -> Option<User<'a, A>> {
+        let author = self
+            .comment
+            .author
+            .as_deref()
+            .filter(|_| !self.comment.is_deleted())?;
+        let fullname = self.comment.author_fullname.as_deref();
+        Some(User::unloaded(self.me, author, fullname))
+    }
+    /// Loads the Subreddit the comment was posted in
+    pub async fn subreddit(&self) -> Result<Subreddit<'a, A>, Error> {
+        self.me.subreddit(&self.comment.subreddit).await
+    }
+}
+
+impl CommentsResponse {
+    /// The comments bound to the Client. So they can be acted on directly
+    pub fn bound<'a, A: Authenticator>(
+        &'a self,
+        client: &'a Client<A>,
+    ) -> impl Iterator<Item = Comment<'a, A, CommentResponse>> {
+        self.iter().map(move |child| child.data.to_comment(client))
+    }
+}
+
+impl<'a, A: Authorized> Sealed for Comment<'a, A, CommentResponse> {
+    type Auth = A;
+    fn client(&self) -> &Client<A> {
+        self.me
+    }
+    fn thing_fullname(&self) -> String {
+        self.comment.fullname()
+    }
+}
+
+impl<'a, A: Authorized> Votable for Comment<'a, A, CommentResponse> {}
+impl<'a, A: Authorized> Savable for Comment<'a, A, CommentResponse> {}
+impl<'a, A: Authorized> Reportable for Comment<'a, A, CommentResponse> {}
+impl<'a, A: Authorized> Replyable for Comment<'a, A, CommentResponse> {}
+impl<'a, A: Authorized> Deletable for Comment<'a, A, CommentResponse> {}
+
+impl<'a, A: Authorized> Comment<'a, A, CommentResponse> {
+    /// Edits the body of the comment. Returns the comment as Reddit now has it
+    pub async fn edit(&self, text: &str) -> Result<CommentResponse, Error> {
+        self.me.edit_user_text(&self.comment.fullname(), text).await
+    }
+    /// Distinguishes the comment as a moderator and pins it to the top of the submission.
+    ///
+    /// Only top level comments can be stickied. Others return [Error::InvalidOption] without a request.
+    /// Requires moderator access
+    pub async fn sticky(&self) -> Result<(), Error> {
+        if !self.comment.is_top_level() {
+            return Err(Error::InvalidOption(format!(
+                "{} is not a top level comment",
+                self.comment.fullname()
+            )));
+        }
+        let fullname = self.comment.fullname();
+        let body = form_body(&[
+            ("api_type", "json"),
+            ("id", fullname.as_str()),
+            ("how", "yes"),
+            ("sticky", "true"),
+        ]);
+        self.me
+            .post_empty("/api/distinguish", Access::OAuthOnly, body)
+            .await
+    }
+    /// Enables or disables inbox notifications for replies to the comment
+    pub async fn set_send_replies(&self, enabled: bool) -> Result<(), Error> {
+        self.me
+            .set_send_replies(&self.comment.fullname(), enabled)
+            .await
     }
 }
@@ -1,35 +1,129 @@
+use crate::comments::tree::CommentNode;
 use crate::comments::CommentType;
 use crate::responses::listing::GenericListing;
-use serde::Deserialize;
+use crate::responses::status::{content_status, BannedBy, ContentStatus, DELETED};
+use crate::responses::types::Distinguished;
+use crate::subreddit::response::SubredditCompact;
+use crate::utils::fullname::{Fullname, FullnameKind};
+use serde::{Deserialize, Serialize};
 use std::fmt::{Debug, Formatter};
 
-#[derive(Deserialize)]
+/// Prefixed to permalinks
+pub const REDDIT_URL: &str = "https://www.reddit.com";
+
+#[derive(Deserialize, Serialize, Clone, PartialEq)]
 pub struct CommentResponse {
-    pub link_id: Option<String>,
+    /// The fullname of the submission. `t3_{id}`
+    pub link_id: String,
     pub likes: Option<bool>,
     pub id: String,
+    /// `[deleted]` if the account or the comment was deleted. See [CommentResponse::is_deleted]
     pub author: Option<String>,
     pub created_utc: Option<f64>,
-    pub parent_id: Option<String>,
+    /// The fullname of the parent. The submission(`t3_`) for top level comments otherwise a comment(`t1_`)
+    pub parent_id: String,
+    /// 0 for top level comments. Listings outside of a comment tree such as a user's comments do not include it
+    #[serde(default)]
+    pub depth: u32,
     pub score: f64,
+    /// The fullname of the author. Missing if the author is `[deleted]`
+    #[serde(default)]
     pub author_fullname: Option<String>,
     pub subreddit_id: Option<String>,
     pub subreddit: String,
     pub body: String,
     pub link_title: Option<String>,
     pub name: Option<String>,
+    /// Relative to [REDDIT_URL]. Empty if Reddit did not send it. Use [CommentResponse::permalink_url] for the full URL
+    #[serde(default)]
     pub permalink: String,
     pub downs: Option<i32>,
     pub body_html: Option<String>,
-    pub distinguished: Option<String>,
-    pub stickied: Option<bool>,
+    /// When the comment was last edited
+    #[serde(default, with = "crate::responses::edited")]
+    pub edited: Option<f64>,
+    /// Who the comment was distinguished by
+    pub distinguished: Option<Distinguished>,
+    /// Why the content was removed. Such as `moderator`, `deleted` or `anti_evil_ops`
+    pub removed_by_category: Option<String>,
+    #[serde(default)]
+    pub banned_by: Option<BannedBy>,
+    /// Only sent to moderators
+    #[serde(default)]
+    pub spam: bool,
+    /// Pinned to the top of the comments by a moderator
+    #[serde(default)]
+    pub stickied: bool,
+    /// The author of the comment is the author of the submission
+    #[serde(default)]
+    pub is_submitter: bool,
     pub ups: Option<i32>,
+    /// Empty if the comment has no replies or they were not loaded
+    #[serde(default, with = "crate::comments::tree::replies")]
+    pub replies: Vec<CommentNode>,
+    /// The subreddit of the comment. Only sent when the listing was loaded with [sr_detail](crate::utils::options::FeedOption::sr_detail)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sr_detail: Option<SubredditCompact>,
 }
 impl Debug for CommentResponse {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "Permalink: {}, ID: {}", self.permalink, self.id)
     }
 }
+impl CommentResponse {
+    /// Whether the comment was removed or deleted. See [ContentStatus] for the precedence
+    pub fn status(&self) -> ContentStatus {
+        content_status(
+            self.removed_by_category.as_deref(),
+            self.banned_by.as_ref(),
+            self.spam,
+            self.author.as_deref(),
+            &self.body,
+        )
+    }
+    /// Whether the author is `[deleted]` or missing. The account or the comment was deleted. [CommentResponse::status] tells them apart
+    pub fn is_deleted(&self) -> bool {
+        self.author
+            .as_deref()
+            .map_or(true, |author| author == DELETED)
+    }
+    /// The canonical URL of the comment. Opens the submission focused on the comment.
+    ///
+    /// Built from `link_id` and `id` if the listing did not include the permalink
+    pub fn permalink_url(&self) -> String {
+        if !self.permalink.is_empty() {
+            return format!("{REDDIT_URL}{}", self.permalink);
+        }
+        let link_id = FullnameKind::Link.strip(&self.link_id);
+        format!("{REDDIT_URL}/comments/{link_id}/_/{}/", self.id)
+    }
+    /// The [permalink_url](CommentResponse::permalink_url) showing `context` levels of parent comments above the comment.
+    ///
+    /// Reddit only shows up to [MAX_CONTEXT](crate::MAX_CONTEXT) so `context` is clamped to it
+    pub fn context_url(&self, context: u8) -> String {
+        let context = context.min(crate::MAX_CONTEXT);
+        format!("{}?context={context}", self.permalink_url())
+    }
+    /// Is the parent the submission
+    pub fn is_top_level(&self) -> bool {
+        self.parent_id == self.link_id
+    }
+    /// Was the comment distinguished as a moderator
+    pub fn is_mod_comment(&self) -> bool {
+        self.distinguished == Some(Distinguished::Moderator)
+    }
+    /// The HTML Reddit rendered for the comment. Unescaped if the Client was not built with
+    /// [raw_json](crate::builder::ClientBuilder::raw_json)
+    pub fn rendered_html(&self) -> Option<String> {
+        crate::utils::rendered_html(self.body_html.as_ref())
+    }
+    /// The fullname of the comment. `t1_{id}`
+    pub fn fullname(&self) -> String {
+        self.name
+            .clone()
+            .unwrap_or_else(|| Fullname::new(FullnameKind::Comment, &self.id).to_string())
+    }
+}
 impl<'a> CommentType<'a> for CommentResponse {
     fn get_permalink(&self) -> &String {
         &self.permalink
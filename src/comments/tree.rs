@@ -0,0 +1,474 @@
+use crate::access::Access;
+use crate::auth::Authenticator;
+use crate::comments::response::CommentResponse;
+use crate::error::Error;
+use crate::responses::api::{JsonResponse, Things};
+use crate::responses::listing::Listing;
+use crate::responses::{GenericResponse, RedditDataType};
+use crate::utils::fullname::FullnameKind;
+use crate::Client;
+use serde::de::{IgnoredAny, MapAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::{HashMap, VecDeque};
+use std::fmt::Formatter;
+
+/// A node of a comment tree. A comment(`t1`) or a placeholder for comments that were not loaded(`more`)
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[serde(tag = "kind", content = "data")]
+pub enum CommentNode {
+    #[serde(rename = "t1")]
+    Comment(Box<CommentResponse>),
+    #[serde(rename = "more")]
+    More(MoreComments),
+}
+
+/// Comments Reddit did not include in the response. Load them with `/api/morechildren`.
+///
+/// A `count` of 0 with no children is a "continue this thread" link
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct MoreComments {
+    pub id: String,
+    pub name: String,
+    pub parent_id: String,
+    #[serde(default)]
+    pub depth: u32,
+    #[serde(default)]
+    pub count: u64,
+    /// The ids of the missing comments
+    #[serde(default)]
+    pub children: Vec<String>,
+    /// The fullname of the submission. Reddit does not send it. Filled in from the comments around the placeholder
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub link_id: Option<String>,
+}
+
+impl MoreComments {
+    /// The most ids Reddit accepts in one `/api/morechildren` request
+    pub const MAX_CHILDREN: usize = 100;
+
+    /// Directly below the submission. Very large threads end with one of these
+    pub fn is_top_level(&self) -> bool {
+        self.parent_id.starts_with("t3_")
+    }
+    /// A "continue this thread" link. Its comments are loaded from the permalink of the parent
+    pub fn is_continue_thread(&self) -> bool {
+        self.children.is_empty()
+    }
+    /// Loads the comments the placeholder stands for, so a tree can be expanded one branch at a time.
+    ///
+    /// The loaded comments are nested under their parents. Placeholders Reddit left out again are kept so they can be loaded later.
+    /// Swap them into the tree with [CommentTree::replace_more].
+    ///
+    /// `/api/morechildren` accepts [MoreComments::MAX_CHILDREN] ids at a time. Larger placeholders are loaded in chunks one after another
+    pub async fn load_more<A: Authenticator>(
+        &self,
+        client: &Client<A>,
+    ) -> Result<Vec<CommentNode>, Error> {
+        let link_id = self
+            .link_id
+            .clone()
+            .or_else(|| self.is_top_level().then(|| self.parent_id.clone()));
+        let link_id = link_id.ok_or_else(|| {
+            Error::InvalidOption(format!("The submission of {} is not known", self.name))
+        })?;
+        if self.is_continue_thread() {
+            return self.continue_thread(client, &link_id).await;
+        }
+        let mut things = Vec::with_capacity(self.children.len());
+        for chunk in self.children.chunks(Self::MAX_CHILDREN) {
+            let query = serde_urlencoded::to_string([
+                ("api_type", "json"),
+                ("link_id", link_id.as_str()),
+                ("children", chunk.join(",").as_str()),
+                ("limit_children", "false"),
+            ])
+            .unwrap_or_default();
+            let response = client
+                .get_json::<JsonResponse<Things<CommentNode>>>(
+                    &format!("/api/morechildren?{query}"),
+                    Access::Any,
+                )
+                .await?;
+            if let Some(data) = response.into_result()? {
+                things.extend(data.things);
+            }
+        }
+        let mut nodes = nest(things, &self.parent_id);
+        fill_link_ids(&mut nodes, &link_id);
+        Ok(nodes)
+    }
+    /// The replies of the parent from its permalink
+    async fn continue_thread<A: Authenticator>(
+        &self,
+        client: &Client<A>,
+        link_id: &str,
+    ) -> Result<Vec<CommentNode>, Error> {
+        if self.is_top_level() {
+            return Ok(Vec::new());
+        }
+        let path = format!(
+            "/comments/{}/_/{}",
+            FullnameKind::Link.strip(link_id),
+            FullnameKind::Comment.strip(&self.parent_id)
+        );
+        let (_, tree) = client
+            .get_json::<(IgnoredAny, CommentTree)>(&path, Access::Any)
+            .await?;
+        let replies = tree.nodes.into_iter().find_map(|node| match node {
+            CommentNode::Comment(comment) if comment.fullname() == self.parent_id => {
+                Some(comment.replies)
+            }
+            _ => None,
+        });
+        Ok(replies.unwrap_or_default())
+    }
+}
+
+impl CommentNode {
+    /// The fullname of the parent comment or submission
+    pub fn parent_id(&self) -> &str {
+        match self {
+            CommentNode::Comment(comment) => &comment.parent_id,
+            CommentNode::More(more) => &more.parent_id,
+        }
+    }
+}
+
+/// Nests the flat list `/api/morechildren` returns under `parent_id`. Comments are in the order Reddit sent them
+fn nest(things: Vec<CommentNode>, parent_id: &str) -> Vec<CommentNode> {
+    let mut by_parent: HashMap<String, Vec<CommentNode>> = HashMap::new();
+    for node in things {
+        by_parent
+            .entry(node.parent_id().to_string())
+            .or_default()
+            .push(node);
+    }
+    let mut roots = by_parent.remove(parent_id).unwrap_or_default();
+    let mut stack: Vec<&mut Vec<CommentNode>> = vec![&mut roots];
+    while let Some(nodes) = stack.pop() {
+        for node in nodes.iter_mut() {
+            if let CommentNode::Comment(comment) = node {
+                if let Some(replies) = by_parent.remove(&comment.fullname()) {
+                    comment.replies.extend(replies);
+                }
+                stack.push(&mut comment.replies);
+            }
+        }
+    }
+    roots
+}
+
+/// Sets [MoreComments::link_id] on every placeholder that does not have it
+fn fill_link_ids(nodes: &mut [CommentNode], link_id: &str) {
+    let mut stack = vec![nodes];
+    while let Some(nodes) = stack.pop() {
+        for node in nodes.iter_mut() {
+            match node {
+                CommentNode::Comment(comment) => stack.push(&mut comment.replies),
+                CommentNode::More(more) => {
+                    more.link_id.get_or_insert_with(|| link_id.to_string());
+                }
+            }
+        }
+    }
+}
+
+/// The comments of a submission
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Default)]
+#[serde(from = "GenericResponse<Listing<CommentNode>>")]
+#[serde(into = "GenericResponse<Listing<CommentNode>>")]
+pub struct CommentTree {
+    /// The top level nodes
+    pub nodes: Vec<CommentNode>,
+}
+
+impl CommentTree {
+    /// Every comment depth first in pre-order. A comment is followed by its replies. [MoreComments] are skipped
+    pub fn iter(&self) -> CommentIter<'_> {
+        CommentIter {
+            stack: vec![self.nodes.iter()],
+        }
+    }
+    /// Every comment depth first in pre-order with its depth in the tree. Top level comments are 0
+    pub fn iter_depth_first(&self) -> DepthFirst<'_> {
+        DepthFirst {
+            stack: vec![(self.nodes.iter(), 0)],
+            more: Vec::new(),
+        }
+    }
+    /// Every comment level by level with its depth in the tree. Top level comments are 0
+    pub fn iter_breadth_first(&self) -> BreadthFirst<'_> {
+        BreadthFirst {
+            queue: self.nodes.iter().map(|node| (node, 0)).collect(),
+            more: Vec::new(),
+        }
+    }
+    /// The first comment in [CommentTree::iter] order that matches
+    pub fn find<P>(&self, mut predicate: P) -> Option<&CommentResponse>
+    where
+        P: FnMut(&CommentResponse) -> bool,
+    {
+        self.iter().find(|comment| predicate(comment))
+    }
+    /// The [MoreComments] placeholders anywhere in the tree
+    pub fn more(&self) -> impl Iterator<Item = &MoreComments> {
+        let top = self.nodes.iter();
+        let replies = self.iter().flat_map(|comment| comment.replies.iter());
+        top.chain(replies).filter_map(|node| match node {
+            CommentNode::More(more) => Some(more),
+            CommentNode::Comment(_) => None,
+        })
+    }
+    /// Every comment in the same order as [CommentTree::iter]. The replies of the returned comments are empty,
+    /// `parent_id` still links each comment to its parent
+    pub fn flatten(self) -> Vec<CommentResponse> {
+        let mut comments = Vec::new();
+        flatten_nodes(self.nodes, &mut comments);
+        comments
+    }
+    /// The number of comments. Not counting [MoreComments]
+    pub fn len(&self) -> usize {
+        self.iter().count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.iter().next().is_none()
+    }
+    /// True if there are no [MoreComments] placeholders left. A truncated tree needs `/api/morechildren` to be complete
+    pub fn is_complete(&self) -> bool {
+        self.more().next().is_none()
+    }
+    /// The sum of [MoreComments::count] over every placeholder. "Continue this thread" placeholders count as 0
+    pub fn missing_count(&self) -> u64 {
+        self.more().map(|more| more.count).sum()
+    }
+    /// Replaces the placeholder with the comments loaded by [MoreComments::load_more].
+    /// Returns false if the placeholder is not in the tree
+    /// ```no_run
+    /// # async fn expand<A: rraw::auth::Authenticator>(client: &rraw::Client<A>, mut tree: rraw::comments::tree::CommentTree) -> Result<(), rraw::error::Error> {
+    /// loop {
+    ///     let more = match tree.more().find(|more| more.is_top_level()) {
+    ///         Some(more) => more.clone(),
+    ///         None => break,
+    ///     };
+    ///     let comments = more.load_more(client).await?;
+    ///     tree.replace_more(&more, comments);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn replace_more(&mut self, more: &MoreComments, nodes: Vec<CommentNode>) -> bool {
+        let mut stack = vec![&mut self.nodes];
+        while let Some(siblings) = stack.pop() {
+            let position = siblings
+                .iter()
+                .position(|node| matches!(node, CommentNode::More(other) if other == more));
+            if let Some(index) = position {
+                siblings.splice(index..=index, nodes);
+                return true;
+            }
+            stack.extend(siblings.iter_mut().filter_map(|node| match node {
+                CommentNode::Comment(comment) => Some(&mut comment.replies),
+                CommentNode::More(_) => None,
+            }));
+        }
+        false
+    }
+}
+
+fn flatten_nodes(nodes: Vec<CommentNode>, comments: &mut Vec<CommentResponse>) {
+    let mut stack = vec![nodes.into_iter()];
+    while let Some(nodes) = stack.last_mut() {
+        match nodes.next() {
+            Some(CommentNode::Comment(mut comment)) => {
+                let replies = std::mem::take(&mut comment.replies);
+                comments.push(*comment);
+                stack.push(replies.into_iter());
+            }
+            Some(CommentNode::More(_)) => {}
+            None => {
+                stack.pop();
+            }
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a CommentTree {
+    type Item = &'a CommentResponse;
+    type IntoIter = CommentIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl From<GenericResponse<Listing<CommentNode>>> for CommentTree {
+    fn from(listing: GenericResponse<Listing<CommentNode>>) -> Self {
+        let mut nodes = listing.data.children;
+        let link_id = nodes.iter().find_map(|node| match node {
+            CommentNode::Comment(comment) => Some(comment.link_id.clone()),
+            CommentNode::More(more) => more
+                .link_id
+                .clone()
+                .or_else(|| more.is_top_level().then(|| more.parent_id.clone())),
+        });
+        if let Some(link_id) = link_id {
+            fill_link_ids(&mut nodes, &link_id);
+        }
+        CommentTree { nodes }
+    }
+}
+
+impl From<CommentTree> for GenericResponse<Listing<CommentNode>> {
+    fn from(tree: CommentTree) -> Self {
+        listing(tree.nodes)
+    }
+}
+
+fn listing(children: Vec<CommentNode>) -> GenericResponse<Listing<CommentNode>> {
+    GenericResponse {
+        kind: RedditDataType::Listing,
+        data: Listing {
+            modhash: None,
+            after: None,
+            before: None,
+            children,
+        },
+    }
+}
+
+/// Iterates over a comment tree in pre-order. Created with [CommentTree::iter]
+pub struct CommentIter<'a> {
+    stack: Vec<std::slice::Iter<'a, CommentNode>>,
+}
+
+impl<'a> Iterator for CommentIter<'a> {
+    type Item = &'a CommentResponse;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let node = match self.stack.last_mut()?.next() {
+                Some(node) => node,
+                None => {
+                    self.stack.pop();
+                    continue;
+                }
+            };
+            if let CommentNode::Comment(comment) = node {
+                self.stack.push(comment.replies.iter());
+                return Some(comment);
+            }
+        }
+    }
+}
+
+/// Iterates over a comment tree in pre-order with the depth of each comment. Created with [CommentTree::iter_depth_first]
+pub struct DepthFirst<'a> {
+    stack: Vec<(std::slice::Iter<'a, CommentNode>, usize)>,
+    more: Vec<&'a MoreComments>,
+}
+
+impl<'a> DepthFirst<'a> {
+    /// The [MoreComments] placeholders skipped so far
+    pub fn more(&self) -> &[&'a MoreComments] {
+        &self.more
+    }
+}
+
+impl<'a> Iterator for DepthFirst<'a> {
+    type Item = (&'a CommentResponse, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (nodes, depth) = self.stack.last_mut()?;
+            let depth = *depth;
+            match nodes.next() {
+                Some(CommentNode::Comment(comment)) => {
+                    self.stack.push((comment.replies.iter(), depth + 1));
+                    return Some((comment, depth));
+                }
+                Some(CommentNode::More(more)) => self.more.push(more),
+                None => {
+                    self.stack.pop();
+                }
+            }
+        }
+    }
+}
+
+/// Iterates over a comment tree level by level with the depth of each comment. Created with [CommentTree::iter_breadth_first]
+pub struct BreadthFirst<'a> {
+    queue: VecDeque<(&'a CommentNode, usize)>,
+    more: Vec<&'a MoreComments>,
+}
+
+impl<'a> BreadthFirst<'a> {
+    /// The [MoreComments] placeholders skipped so far
+    pub fn more(&self) -> &[&'a MoreComments] {
+        &self.more
+    }
+}
+
+impl<'a> Iterator for BreadthFirst<'a> {
+    type Item = (&'a CommentResponse, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.queue.pop_front()? {
+                (CommentNode::Comment(comment), depth) => {
+                    let replies = comment.replies.iter().map(|node| (node, depth + 1));
+                    self.queue.extend(replies);
+                    return Some((comment, depth));
+                }
+                (CommentNode::More(more), _) => self.more.push(more),
+            }
+        }
+    }
+}
+
+/// Reddit sends `""` if a comment has no replies otherwise a Listing of [CommentNode]
+pub(crate) mod replies {
+    use super::*;
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<CommentNode>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(RepliesVisitor)
+    }
+
+    pub fn serialize<S>(value: &[CommentNode], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if value.is_empty() {
+            serializer.serialize_str("")
+        } else {
+            listing(value.to_vec()).serialize(serializer)
+        }
+    }
+
+    struct RepliesVisitor;
+
+    impl<'de> Visitor<'de> for RepliesVisitor {
+        type Value = Vec<CommentNode>;
+
+        fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+            formatter.write_str("an empty string or a Listing of comments")
+        }
+
+        fn visit_str<E: serde::de::Error>(self, _: &str) -> Result<Self::Value, E> {
+            Ok(Vec::new())
+        }
+
+        fn visit_unit<E: serde::de::Error>(self) -> Result<Self::Value, E> {
+            Ok(Vec::new())
+        }
+
+        fn visit_map<M: MapAccess<'de>>(self, map: M) -> Result<Self::Value, M::Error> {
+            let listing = GenericResponse::<Listing<CommentNode>>::deserialize(
+                serde::de::value::MapAccessDeserializer::new(map),
+            )?;
+            Ok(listing.data.children)
+        }
+    }
+}
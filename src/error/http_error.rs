@@ -1,6 +1,12 @@
 use reqwest::StatusCode;
+use serde::Deserialize;
+use serde_json::Value;
+use std::fmt::{Display, Formatter};
 use thiserror::Error;
 
+/// The max amount of characters of an error body kept within [ErrorResponse]
+pub const ERROR_BODY_LENGTH: usize = 4096;
+
 pub trait IntoResult {
     fn into_result(self) -> Result<(), HTTPError>;
 }
@@ -21,13 +27,104 @@ pub enum HTTPError {
     Other(StatusCode),
     #[error("The requested value is not found")]
     NotFound,
+    /// Converted into [Error::Forbidden](crate::error::Error::Forbidden) when it becomes an [Error](crate::error::Error)
+    #[error("You do not have permission to access this value")]
+    Forbidden,
+    #[error("The subreddit is private")]
+    Private,
+    #[error("You are banned from this subreddit")]
+    Banned,
+    #[error("The subreddit is quarantined")]
+    Quarantined,
+    #[error("The subreddit is only available to Reddit Premium members")]
+    GoldOnly,
 }
 
 impl From<StatusCode> for HTTPError {
     fn from(err: StatusCode) -> HTTPError {
         match err {
             StatusCode::NOT_FOUND => HTTPError::NotFound,
+            StatusCode::FORBIDDEN => HTTPError::Forbidden,
             value => HTTPError::Other(value),
         }
     }
 }
+
+impl HTTPError {
+    /// Picks the error based on the `reason` Reddit gave falling back to the status
+    pub fn from_reason(status: StatusCode, reason: Option<&str>) -> HTTPError {
+        match reason {
+            Some("private") => HTTPError::Private,
+            Some("banned") => HTTPError::Banned,
+            Some("quarantined") => HTTPError::Quarantined,
+            Some("gold_only") => HTTPError::GoldOnly,
+            _ => HTTPError::from(status),
+        }
+    }
+}
+
+/// Details about a non success response
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ErrorResponse {
+    /// The URL that was requested
+    pub url: String,
+    pub status: StatusCode,
+    /// The `reason` value of the body. Such as `private` or `banned`
+    pub reason: Option<String>,
+    /// The `message` value of the body
+    pub message: Option<String>,
+    /// Up to [ERROR_BODY_LENGTH] characters of the body
+    pub body: String,
+}
+
+#[derive(Deserialize)]
+struct ErrorBody {
+    reason: Option<String>,
+    message: Option<String>,
+    error: Option<Value>,
+}
+
+impl ErrorResponse {
+    /// Parses the `{"reason", "message", "error"}` body Reddit responds with
+    pub fn new(url: impl Into<String>, status: StatusCode, body: &str) -> ErrorResponse {
+        let parsed = serde_json::from_str::<ErrorBody>(body).ok();
+        let (reason, message) = match parsed {
+            Some(ErrorBody {
+                reason,
+                message,
+                error,
+            }) => {
+                let message = message.or(match error {
+                    Some(Value::String(error)) => Some(error),
+                    _ => None,
+                });
+                (reason, message)
+            }
+            None => (None, None),
+        };
+        ErrorResponse {
+            url: url.into(),
+            status,
+            reason,
+            message,
+            body: body.chars().take(ERROR_BODY_LENGTH).collect(),
+        }
+    }
+    /// The error based on the reason and status
+    pub fn http_error(&self) -> HTTPError {
+        HTTPError::from_reason(self.status, self.reason.as_deref())
+    }
+}
+
+impl Display for ErrorResponse {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} responded with {}", self.url, self.status)?;
+        if let Some(reason) = &self.reason {
+            write!(f, ". Reason: {reason}")?;
+        }
+        if let Some(message) = &self.message {
+            write!(f, ". Message: {message}")?;
+        }
+        Ok(())
+    }
+}
@@ -1,15 +1,109 @@
 use thiserror::Error;
 
+/// The max amount of characters of the response body kept within a [InternalError::DeserializeError]
+pub const SNIPPET_LENGTH: usize = 500;
+
+/// Variants are added by the `rss` and `simd-json` features. Matches need a wildcard arm
 #[derive(Error, Debug)]
+#[non_exhaustive]
 pub enum InternalError {
     #[error("Reqwest had an Error {0}")]
-    ReqwestError(reqwest::Error),
+    ReqwestError(#[source] reqwest::Error),
     #[error("Serde Json Parse Error {0}")]
-    JSONError(serde_json::Error),
+    JSONError(#[source] serde_json::Error),
+    #[error("Unable to parse the response of {url} at `{path}`: {source}. Body: {snippet}")]
+    DeserializeError {
+        /// The URL the response came from
+        url: String,
+        /// The path to the value that failed. Such as `data.children[3].data.edited`
+        path: String,
+        /// Up to [SNIPPET_LENGTH] characters of the body surrounding the failure
+        snippet: String,
+        source: serde_json::Error,
+    },
+    /// A `.rss` feed that is not valid XML
+    #[cfg(feature = "rss")]
+    #[error("Unable to parse the feed of {url}: {source}")]
+    FeedError {
+        url: String,
+        source: roxmltree::Error,
+    },
+    /// A response simd-json could not parse
+    #[cfg(feature = "simd-json")]
+    #[error("Unable to parse the response of {url} at `{path}`: {source}. Body: {snippet}")]
+    SimdDeserializeError {
+        /// The URL the response came from
+        url: String,
+        /// The path to the value that failed. Such as `data.children[3].data.edited`
+        path: String,
+        /// Up to [SNIPPET_LENGTH] characters of the body surrounding the failure
+        snippet: String,
+        /// Boxed to keep [Error](crate::error::Error) as small as with serde_json
+        source: Box<simd_json::Error>,
+    },
     #[error("Internal Error {0}")]
     Custom(String),
 }
 
+impl InternalError {
+    /// Creates a [InternalError::DeserializeError] taking a snippet of the body around the failure
+    pub fn deserialize(
+        url: impl Into<String>,
+        path: impl Into<String>,
+        body: &str,
+        source: serde_json::Error,
+    ) -> InternalError {
+        InternalError::DeserializeError {
+            url: url.into(),
+            path: path.into(),
+            snippet: snippet(body, source.line(), source.column()),
+            source,
+        }
+    }
+}
+
+#[cfg(feature = "simd-json")]
+impl InternalError {
+    /// Creates a [InternalError::SimdDeserializeError]. simd-json does not report where in the body it failed,
+    /// so `position` is the line and column serde_json reports for the same body
+    pub fn simd_deserialize(
+        url: impl Into<String>,
+        path: impl Into<String>,
+        body: &str,
+        position: (usize, usize),
+        source: simd_json::Error,
+    ) -> InternalError {
+        InternalError::SimdDeserializeError {
+            url: url.into(),
+            path: path.into(),
+            snippet: snippet(body, position.0, position.1),
+            source: Box::new(source),
+        }
+    }
+}
+
+/// Takes up to [SNIPPET_LENGTH] characters of the body centered on the line and column serde_json reported
+fn snippet(body: &str, line: usize, column: usize) -> String {
+    let line_start = if line <= 1 {
+        0
+    } else {
+        body.match_indices('\n')
+            .nth(line - 2)
+            .map(|(index, _)| index + 1)
+            .unwrap_or(body.len())
+    };
+    let position = body[line_start..]
+        .char_indices()
+        .nth(column.saturating_sub(1))
+        .map(|(index, _)| line_start + index)
+        .unwrap_or(body.len());
+    let characters_before = body[..position].chars().count();
+    body.chars()
+        .skip(characters_before.saturating_sub(SNIPPET_LENGTH / 2))
+        .take(SNIPPET_LENGTH)
+        .collect()
+}
+
 impl From<reqwest::Error> for InternalError {
     fn from(err: reqwest::Error) -> InternalError {
         InternalError::ReqwestError(err)
@@ -1,27 +1,326 @@
 pub mod http_error;
 pub mod internal_error;
 pub mod reddit_error;
+pub mod url_error;
 
-use crate::error::http_error::HTTPError;
+use crate::error::http_error::{ErrorResponse, HTTPError};
 use crate::error::internal_error::InternalError;
-use crate::error::reddit_error::RedditError;
-use reqwest::StatusCode;
+use crate::error::reddit_error::{RedditApiError, RedditError};
+use crate::error::url_error::RedditUrlError;
+use crate::ratelimit::wait_from_secs;
+use crate::responses::api::JsonResponse;
+use flate2::read::GzDecoder;
+use reqwest::header::{HeaderMap, CONTENT_ENCODING, RETRY_AFTER, WWW_AUTHENTICATE};
+use reqwest::{Response, StatusCode};
+use serde::de::IgnoredAny;
+use std::io::{ErrorKind, Read};
+use std::time::Duration;
 use thiserror::Error;
 
 pub type Result<T> = std::result::Result<T, Error>;
 
+/// The single Error type returned by RRAW.
+///
+/// The wrapped errors are exposed through [std::error::Error::source]. New variants may be added in future releases
 #[derive(Error, Debug)]
+#[non_exhaustive]
 pub enum Error {
-    #[error("Http Error: {0}")]
-    HTTPError(HTTPError),
+    /// A non success response. `response` is set when the body could be read
+    #[error("Http Error: {error}{}", display_response(response))]
+    HTTPError {
+        #[source]
+        error: HTTPError,
+        response: Option<Box<ErrorResponse>>,
+    },
     #[error("Internal Error: {0}")]
-    InternalError(InternalError),
+    InternalError(#[source] InternalError),
     #[error("Reddit Error: {0}")]
-    RedditError(RedditError),
+    RedditError(#[source] RedditError),
+    /// The errors inside a `json.errors` response
+    #[error("Reddit API Error: {}", display_api_errors(.0))]
+    Api(Vec<RedditApiError>),
     #[error("{0}")]
     Other(String),
     #[error("The Token Has Expired")]
     TokenExpired,
+    #[error("Reddit redirected to the login page. This endpoint requires you to be logged in")]
+    RequiresAuth,
+    /// The endpoint requires OAuth and the authenticator is anonymous or has no token.
+    ///
+    /// Returned before the request is sent
+    #[error("This endpoint requires OAuth, but the authenticator is not logged in")]
+    NotLoggedIn,
+    /// An option passed to RRAW was not valid. Such as a [FeedOption](crate::utils::options::FeedOption) limit over 100
+    #[error("Invalid Option: {0}")]
+    InvalidOption(String),
+    /// A URL passed to RRAW could not be parsed. Returned before any request is sent
+    #[error("Invalid URL: {0}")]
+    Url(#[source] RedditUrlError),
+    /// Reddit refused the request with a 403. `reason` is the `reason` of the body if Reddit sent one.
+    ///
+    /// Subreddits that are private, banned, quarantined or premium only are returned as their [HTTPError] instead
+    #[error("Reddit refused the request{}", display_response(response))]
+    Forbidden {
+        reason: Option<String>,
+        response: Option<Box<ErrorResponse>>,
+    },
+    /// The token was not granted the scope this endpoint requires
+    #[error("The token is missing the required scope{}", display_scope(required))]
+    InsufficientScope { required: Option<String> },
+    /// Reddit wants a captcha solved before accepting the submission or message. Retrying will not help.
+    ///
+    /// `captcha` is the iden of the captcha if Reddit sent one
+    #[error("Reddit requires a captcha to be solved: {error}")]
+    CaptchaRequired {
+        captcha: Option<String>,
+        #[source]
+        error: RedditApiError,
+    },
+    /// The automatic token refresh failed. Retryable errors were already tried again
+    #[error("Refreshing the token failed: {0}")]
+    AuthFailed(#[source] Box<Error>),
+    /// Reddit rejected the request with a 401 again after logging in again.
+    ///
+    /// See [ClientBuilder::relogin_on_unauthorized](crate::builder::ClientBuilder::relogin_on_unauthorized)
+    #[error("Reddit rejected the token after logging in again")]
+    AuthenticationExpired,
+    /// A request made for several callers failed. Each of them gets the same error.
+    ///
+    /// Returned by [InfoBatcher](crate::batch::InfoBatcher)
+    #[error("The batched request failed: {0}")]
+    Batch(#[source] std::sync::Arc<Error>),
+    /// The deadline of a [Paginator](crate::paginator::Paginator) passed before the end of the listing.
+    ///
+    /// The items loaded before it were already yielded
+    #[error("The deadline passed before the listing was fully loaded")]
+    DeadlineExceeded,
+    /// Reddit wants you to slow down
+    #[error("Rate Limited by Reddit. Retry After: {retry_after:?}")]
+    RateLimited {
+        retry_after: Option<Duration>,
+        source: RateLimitSource,
+    },
+}
+
+/// How Reddit told us we were rate limited
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum RateLimitSource {
+    /// A 429 response
+    #[error("HTTP 429 Too Many Requests")]
+    TooManyRequests,
+    /// A `RATELIMIT` error inside a `json.errors` response
+    #[error("{0}")]
+    Api(RedditApiError),
+}
+
+impl Error {
+    /// Is it worth trying the request again.
+    ///
+    /// True for rate limits, 5xx responses and timeouts
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Error::RateLimited { .. } => true,
+            Error::HTTPError {
+                error: HTTPError::Other(status),
+                ..
+            } => status.is_server_error(),
+            Error::InternalError(InternalError::ReqwestError(error)) => error.is_timeout(),
+            _ => false,
+        }
+    }
+    /// The HTTP Error if this is one
+    pub fn http_error(&self) -> Option<&HTTPError> {
+        match self {
+            Error::HTTPError { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+    /// How long Reddit wants you to wait before trying again
+    pub fn retry_after(&self) -> Option<Duration> {
+        match self {
+            Error::RateLimited { retry_after, .. } => *retry_after,
+            _ => None,
+        }
+    }
+    /// The closest [std::io::ErrorKind]. Used when converting into a [std::io::Error]
+    pub fn io_kind(&self) -> ErrorKind {
+        match self {
+            Error::DeadlineExceeded => ErrorKind::TimedOut,
+            Error::InternalError(InternalError::ReqwestError(error)) if error.is_timeout() => {
+                ErrorKind::TimedOut
+            }
+            Error::HTTPError {
+                error: HTTPError::NotFound,
+                ..
+            } => ErrorKind::NotFound,
+            Error::HTTPError {
+                error: HTTPError::Other(StatusCode::UNAUTHORIZED),
+                ..
+            }
+            | Error::HTTPError {
+                error:
+                    HTTPError::Forbidden
+                    | HTTPError::Private
+                    | HTTPError::Banned
+                    | HTTPError::Quarantined
+                    | HTTPError::GoldOnly,
+                ..
+            }
+            | Error::Forbidden { .. }
+            | Error::TokenExpired
+            | Error::RequiresAuth
+            | Error::NotLoggedIn
+            | Error::InsufficientScope { .. }
+            | Error::AuthFailed(_)
+            | Error::AuthenticationExpired => ErrorKind::PermissionDenied,
+            Error::InvalidOption(_) | Error::Url(_) => ErrorKind::InvalidInput,
+            Error::InternalError(
+                InternalError::JSONError(_) | InternalError::DeserializeError { .. },
+            ) => ErrorKind::InvalidData,
+            #[cfg(feature = "simd-json")]
+            Error::InternalError(InternalError::SimdDeserializeError { .. }) => {
+                ErrorKind::InvalidData
+            }
+            Error::Batch(error) => error.io_kind(),
+            _ => ErrorKind::Other,
+        }
+    }
+}
+
+/// Lets `?` bridge into code returning [std::io::Result]. The [Error] is kept as the inner error,
+/// so [std::io::Error::get_ref] and the source chain still reach it
+impl From<Error> for std::io::Error {
+    fn from(error: Error) -> Self {
+        std::io::Error::new(error.io_kind(), error)
+    }
+}
+
+/// Checks the status of the response. 429s become [Error::RateLimited].
+///
+/// Other non success responses have their body read into an [ErrorResponse]
+pub(crate) async fn check_response(response: Response) -> Result<Response> {
+    let status = response.status();
+    if status.is_success() {
+        return Ok(response);
+    }
+    if let Some(error) = header_error(&response) {
+        return Err(error);
+    }
+    let url = response.url().to_string();
+    match response.text().await {
+        Ok(body) => Err(ErrorResponse::new(url, status, &body).into()),
+        Err(_) => Err(status.into()),
+    }
+}
+
+/// [check_response] for a client that does not decode gzip. A gzip body is decoded before it is read into the [ErrorResponse]
+pub(crate) async fn check_gzip_response(response: Response) -> Result<Response> {
+    let status = response.status();
+    let gzip = response
+        .headers()
+        .get(CONTENT_ENCODING)
+        .map_or(false, |encoding| {
+            encoding.as_bytes().eq_ignore_ascii_case(b"gzip")
+        });
+    if status.is_success() || !gzip {
+        return check_response(response).await;
+    }
+    if let Some(error) = header_error(&response) {
+        return Err(error);
+    }
+    let url = response.url().to_string();
+    let body = match response.bytes().await {
+        Ok(body) => body,
+        Err(_) => return Err(status.into()),
+    };
+    let mut decoded = String::new();
+    match GzDecoder::new(&body[..]).read_to_string(&mut decoded) {
+        Ok(_) => Err(ErrorResponse::new(url, status, &decoded).into()),
+        Err(_) => Err(status.into()),
+    }
+}
+
+/// The errors decided by the headers without reading the body
+fn header_error(response: &Response) -> Option<Error> {
+    let status = response.status();
+    if status == StatusCode::TOO_MANY_REQUESTS {
+        return Some(Error::RateLimited {
+            retry_after: retry_after(response.headers()),
+            source: RateLimitSource::TooManyRequests,
+        });
+    }
+    if status == StatusCode::FORBIDDEN {
+        if let Some(required) = insufficient_scope(response.headers()) {
+            return Some(Error::InsufficientScope { required });
+        }
+    }
+    None
+}
+
+/// Checks a response body for the `api_type=json` envelope. `{"json": {"errors": [...]}}`
+///
+/// Bodies that are not an envelope are Ok. See [JsonResponse::into_result] for the errors returned
+pub fn check_api_envelope(body: impl AsRef<[u8]>) -> Result<()> {
+    match serde_json::from_slice::<JsonResponse<IgnoredAny>>(body.as_ref()) {
+        Ok(envelope) => envelope.into_result().map(|_| ()),
+        Err(_) => Ok(()),
+    }
+}
+
+/// Reads the `Retry-After` header falling back to Reddit's `X-Ratelimit-Reset`. Both are in seconds.
+///
+/// Waits that are not finite are skipped and ones longer than [MAX_WAIT](crate::ratelimit::MAX_WAIT) are cut down to it
+pub fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    [RETRY_AFTER.as_str(), "x-ratelimit-reset"]
+        .iter()
+        .filter_map(|name| headers.get(*name))
+        .filter_map(|value| value.to_str().ok())
+        .filter_map(|value| value.trim().parse::<f64>().ok())
+        .find_map(wait_from_secs)
+}
+
+/// Reddit sets `WWW-Authenticate: Bearer realm="reddit", error="insufficient_scope"` when the token lacks a scope.
+///
+/// Returns the `scope` attribute if Reddit included one
+pub fn insufficient_scope(headers: &HeaderMap) -> Option<Option<String>> {
+    let value = headers.get(WWW_AUTHENTICATE)?.to_str().ok()?;
+    if !value.contains("insufficient_scope") {
+        return None;
+    }
+    let scope = value
+        .split(',')
+        .map(|attribute| {
+            let attribute = attribute.trim();
+            attribute
+                .strip_prefix("Bearer ")
+                .unwrap_or(attribute)
+                .trim()
+        })
+        .find_map(|attribute| attribute.strip_prefix("scope="))
+        .map(|scope| scope.trim_matches('"').to_string());
+    Some(scope)
+}
+
+fn display_scope(required: &Option<String>) -> String {
+    match required {
+        Some(scope) => format!(" `{scope}`"),
+        None => String::new(),
+    }
+}
+
+fn display_response(response: &Option<Box<ErrorResponse>>) -> String {
+    match response {
+        Some(response) => format!(". {response}"),
+        None => String::new(),
+    }
+}
+
+fn display_api_errors(errors: &[RedditApiError]) -> String {
+    errors
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
 }
 
 impl From<RedditError> for Error {
@@ -30,9 +329,40 @@ impl From<RedditError> for Error {
     }
 }
 
+/// [HTTPError::Forbidden] becomes [Error::Forbidden] so every 403 is matched the same way
 impl From<HTTPError> for Error {
     fn from(value: HTTPError) -> Self {
-        Error::HTTPError(value)
+        match value {
+            HTTPError::Forbidden => Error::Forbidden {
+                reason: None,
+                response: None,
+            },
+            error => Error::HTTPError {
+                error,
+                response: None,
+            },
+        }
+    }
+}
+
+impl From<ErrorResponse> for Error {
+    fn from(value: ErrorResponse) -> Self {
+        match value.http_error() {
+            HTTPError::Forbidden => Error::Forbidden {
+                reason: value.reason.clone(),
+                response: Some(Box::new(value)),
+            },
+            error => Error::HTTPError {
+                error,
+                response: Some(Box::new(value)),
+            },
+        }
+    }
+}
+
+impl From<RedditUrlError> for Error {
+    fn from(value: RedditUrlError) -> Self {
+        Error::Url(value)
     }
 }
 
@@ -44,7 +374,13 @@ impl From<InternalError> for Error {
 
 impl From<StatusCode> for Error {
     fn from(err: StatusCode) -> Error {
-        Error::HTTPError(http_error::HTTPError::from(err))
+        match err {
+            StatusCode::FORBIDDEN => Error::Forbidden {
+                reason: None,
+                response: None,
+            },
+            status => HTTPError::from(status).into(),
+        }
     }
 }
 
@@ -1,3 +1,7 @@
+use serde::de::Error as DeError;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt::{Display, Formatter};
+use std::time::Duration;
 use thiserror::Error;
 
 #[derive(Error, Debug, Eq, PartialEq)]
@@ -5,3 +9,94 @@ pub enum RedditError {
     #[error("The Data Type specified in not valid {0}")]
     InvalidDataType(String),
 }
+
+/// An entry of the `json.errors` array Reddit responds with when `api_type=json` is sent.
+///
+/// Reddit sends them as `["RATELIMIT", "you are doing that too much. try again in 6 minutes.", "ratelimit"]`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RedditApiError {
+    /// Such as `RATELIMIT` or `SUBREDDIT_NOEXIST`
+    pub code: String,
+    /// The human readable message
+    pub message: String,
+    /// The form field the error is about
+    pub field: Option<String>,
+    /// How long Reddit wants you to wait. Only parsed for `RATELIMIT` errors
+    pub retry_after: Option<Duration>,
+}
+
+impl RedditApiError {
+    pub fn new(code: impl Into<String>, message: impl Into<String>, field: Option<String>) -> Self {
+        let code = code.into();
+        let message = message.into();
+        let retry_after = if code == "RATELIMIT" {
+            parse_retry_after(&message)
+        } else {
+            None
+        };
+        RedditApiError {
+            code,
+            message,
+            field: field.filter(|field| !field.is_empty()),
+            retry_after,
+        }
+    }
+}
+
+impl Display for RedditApiError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.field {
+            Some(field) => write!(f, "{}: {} (field: {})", self.code, self.message, field),
+            None => write!(f, "{}: {}", self.code, self.message),
+        }
+    }
+}
+
+impl std::error::Error for RedditApiError {}
+
+impl<'de> Deserialize<'de> for RedditApiError {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let mut values: Vec<Option<String>> = Vec::deserialize(deserializer)?;
+        if values.len() < 2 {
+            return Err(DeError::invalid_length(values.len(), &"at least 2 values"));
+        }
+        let field = values.get_mut(2).and_then(Option::take);
+        let message = values[1].take().unwrap_or_default();
+        let code = values[0].take().unwrap_or_default();
+        Ok(RedditApiError::new(code, message, field))
+    }
+}
+
+impl Serialize for RedditApiError {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        (&self.code, &self.message, &self.field).serialize(serializer)
+    }
+}
+
+/// Parses the wait time out of a `RATELIMIT` message. `you are doing that too much. try again in 6 minutes.`
+///
+/// None if the amount does not fit in a [Duration]
+pub fn parse_retry_after(message: &str) -> Option<Duration> {
+    let message = message.to_lowercase();
+    let (_, wait) = message.split_once("try again in ")?;
+    let mut words = wait.split_whitespace();
+    let amount: u64 = words.next()?.parse().ok()?;
+    let unit = words.next()?;
+    if unit.starts_with("millisecond") {
+        Some(Duration::from_millis(amount))
+    } else if unit.starts_with("second") {
+        Some(Duration::from_secs(amount))
+    } else if unit.starts_with("minute") {
+        amount.checked_mul(60).map(Duration::from_secs)
+    } else if unit.starts_with("hour") {
+        amount.checked_mul(60 * 60).map(Duration::from_secs)
+    } else {
+        None
+    }
+}
@@ -0,0 +1,15 @@
+use thiserror::Error;
+
+/// Why a URL could not be parsed as a [RedditUrl](crate::utils::reddit_url::RedditUrl)
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum RedditUrlError {
+    /// Not a valid URL
+    #[error("{0} is not a URL")]
+    Malformed(String),
+    /// The host is not reddit.com or redd.it
+    #[error("{0} is not a Reddit URL")]
+    NotReddit(String),
+    /// A Reddit URL that does not point to a subreddit, submission, comment or user
+    #[error("{0} does not point to a subreddit, submission, comment or user")]
+    Unrecognized(String),
+}
@@ -1,39 +1,89 @@
+pub mod access;
 pub mod auth;
+#[cfg(feature = "batch")]
+pub mod batch;
+pub mod builder;
+mod cache;
+pub mod collection;
 pub mod comments;
 pub mod error;
+pub mod live;
 pub mod message;
+pub mod modmail;
+#[cfg(feature = "stream")]
+pub mod paginator;
+pub mod ratelimit;
 pub mod responses;
+#[cfg(feature = "rss")]
+pub mod rss;
 pub mod submission;
 pub mod subreddit;
+pub mod thing;
 pub mod user;
 pub mod utils;
 
-use log::trace;
+#[cfg(feature = "shared_authentication")]
+use log::warn;
+use log::{debug, trace};
 use std::fmt::Write;
 use std::fmt::{Debug, Formatter};
+#[cfg(feature = "batch")]
+use std::time::Duration;
 
-use reqwest::header::HeaderMap;
-use reqwest::{Body, Client as ReqwestClient, ClientBuilder, Response};
+use bytes::Bytes;
+#[cfg(feature = "stream")]
+use futures_util::{stream, StreamExt};
+use reqwest::header::{
+    HeaderMap, HeaderValue, ACCEPT, ACCEPT_ENCODING, AUTHORIZATION, CONTENT_TYPE, LOCATION,
+    USER_AGENT,
+};
+use reqwest::{Body, Client as ReqwestClient, Request, Response, StatusCode, Url};
 use serde::de::DeserializeOwned;
-use submission::response::SubmissionsResponse;
+use serde::Serialize;
+use serde_json::Value;
+use submission::response::{SubmissionResponse, SubmissionsResponse};
+use submission::{is_media_host, submission_id};
 
+use crate::access::Access;
 use crate::auth::{Authenticator, Authorized};
-use crate::error::http_error::IntoResult;
+#[cfg(feature = "batch")]
+use crate::batch::InfoBatcher;
+use crate::builder::ClientBuilder;
+use crate::cache::{CacheKey, ResponseCache};
+use crate::collection::response::Collection;
+use crate::comments::response::CommentResponse;
+use crate::comments::tree::CommentTree;
+use crate::error::http_error::HTTPError;
 use crate::error::internal_error::InternalError;
 use crate::error::Error;
-use crate::subreddit::response::{SubredditResponse, Subreddits};
+use crate::error::{check_api_envelope, check_gzip_response, check_response};
+use crate::live::LiveThread;
+#[cfg(feature = "stream")]
+use crate::paginator::Paginator;
+use crate::ratelimit::{RateLimit, RateLimitState};
+use crate::responses::api::{JsonResponse, Things};
+use crate::responses::listing::GenericListing;
+use crate::responses::GenericResponse;
+use crate::subreddit::combined::CombinedSubreddits;
+use crate::subreddit::response::{
+    RecommendedSubreddit, SubredditResponse, Subreddits, TrendingSubreddits,
+};
 use crate::subreddit::Subreddit;
 use crate::user::me::Me;
 use crate::user::response::{MeResponse, UserResponse, Users};
 use crate::user::User;
-use crate::utils::options::FeedOption;
+use crate::utils::fullname::{Fullname, FullnameKind};
+use crate::utils::options::{extend_query, FeedOption, VoteDirection};
+use crate::utils::redact::{redact_headers, redact_url};
+use crate::utils::reddit_url::RedditUrl;
+use crate::utils::{append_form, form_body};
+
+/// The most parent comments Reddit returns with [Client::comment_context]
+pub const MAX_CONTEXT: u8 = 8;
 
 macro_rules! get_auth {
     ($se:ident) => {{
-        #[cfg(not(feature = "shared_authentication"))]
         let auth = $se.get_authenticator();
-        #[cfg(feature = "shared_authentication")]
-        let auth = $se.get_authenticator().await;
         if auth.needs_token_refresh() {
             trace!("Token Expired.");
             #[cfg(not(feature = "shared_authentication"))]
@@ -44,8 +94,8 @@ macro_rules! get_auth {
             {
                 trace!("Refreshing Token");
                 drop(auth);
-                $se.re_login().await?;
-                $se.get_authenticator().await
+                $se.refresh_expired().await?;
+                $se.get_authenticator()
             }
         } else {
             auth
@@ -53,59 +103,199 @@ macro_rules! get_auth {
     }};
 }
 
+/// Attempts made by the automatic token refresh before returning [Error::AuthFailed]
+#[cfg(feature = "shared_authentication")]
+pub const REFRESH_ATTEMPTS: u32 = 3;
+/// The wait before the second refresh attempt. Doubled after each attempt
+#[cfg(feature = "shared_authentication")]
+pub const REFRESH_BACKOFF: std::time::Duration = std::time::Duration::from_millis(500);
+/// The longest wait between refresh attempts. Even if Reddit asks for more
+#[cfg(feature = "shared_authentication")]
+const MAX_REFRESH_BACKOFF: std::time::Duration = std::time::Duration::from_secs(10);
+
 /// This is who you are. This is your identity and you access point to the Reddit API
+///
+/// # Cancellation
+/// Methods that send a single request are cancel safe. Dropping the future cancels the request, such as when it
+/// loses a `tokio::select!` or a `tokio::time::timeout`. The Client can be used again right away.
+/// The shared state is the authenticator, the last rate limit headers and the [ClientBuilder::response_cache].
+/// None of them is left half updated
+///
+/// Methods that send several requests lose the work already done when dropped. Some to keep in mind
+/// - [MoreComments::load_more](crate::comments::tree::MoreComments::load_more) loads 100 comments per request.
+///   The chunks already loaded are dropped, so a retry starts over
+/// - The caller of `InfoBatcher::get` that sends the batch for everyone. Dropping it cancels the request,
+///   and the other callers of that batch add their fullnames again for the next one
+/// - A `Paginator` keeps its place between pages. A page still loading is dropped
+///
+/// With `shared_authentication` requests read the authenticator without locking.
+/// A token refresh works on a copy and swaps it in when done, so requests never wait on it.
+/// Tasks that find the token expired at the same time share one refresh
 #[derive(Clone)]
 pub struct Client<A: Authenticator> {
     #[cfg(feature = "shared_authentication")]
     #[cfg(not(target_arch = "wasm32"))]
-    auth: std::sync::Arc<tokio::sync::RwLock<A>>,
+    auth: std::sync::Arc<arc_swap::ArcSwap<A>>,
+    /// Held while refreshing the token
+    #[cfg(feature = "shared_authentication")]
+    #[cfg(not(target_arch = "wasm32"))]
+    refresh: std::sync::Arc<tokio::sync::Mutex<()>>,
     #[cfg(not(feature = "shared_authentication"))]
     auth: A,
     client: ReqwestClient,
+    /// The same settings as `client` but redirects are never followed. For reading where a link redirects to
+    no_redirect: ReqwestClient,
     user_agent: String,
     refresh_token: Option<String>,
+    rate_limit: RateLimitState,
+    base_url: Option<String>,
+    raw_json: bool,
+    /// Copied into every request before the authenticator adds its headers
+    base_headers: HeaderMap,
+    pub(crate) cache: Option<std::sync::Arc<ResponseCache>>,
+    log_bodies: bool,
+    /// Set by [ClientBuilder::decompress]
+    decompress: bool,
+    /// Set by [ClientBuilder::relogin_on_unauthorized]
+    #[cfg(feature = "shared_authentication")]
+    relogin_on_unauthorized: bool,
     pub oauth: bool,
 }
 
 impl<A: Authenticator> Client<A> {
     /// Creates a Instance of the Client. Complete Initial Login Steps
+    ///
+    /// Use [Client::builder] to change the settings of the Client
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn login<S: Into<String>>(auth: A, user_agent: S) -> Result<Client<A>, Error> {
+        ClientBuilder::new(auth, user_agent).login().await
+    }
+    /// Creates a [ClientBuilder] for customizing the Client before logging in
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() ->anyhow::Result<()>{
+    ///    use rraw::auth::AnonymousAuthenticator;
+    ///    use rraw::Client;
+    ///    use reqwest::redirect::Policy;
+    ///    let client = Client::builder(AnonymousAuthenticator::new(), "RRAW Test (by u/KingTuxWH)")
+    ///        .redirect_policy(Policy::none())
+    ///        .login()
+    ///        .await?;
+    ///    Ok(())
+    /// }
+    /// ```
+    pub fn builder<S: Into<String>>(auth: A, user_agent: S) -> ClientBuilder<A> {
+        ClientBuilder::new(auth, user_agent)
+    }
     #[cfg(not(target_arch = "wasm32"))]
     #[cfg(feature = "shared_authentication")]
-    pub async fn login<S: Into<String>>(mut auth: A, user_agent: S) -> Result<Client<A>, Error> {
-        let user_agent = user_agent.into();
-        let client = ClientBuilder::new()
-            .user_agent(user_agent.clone())
-            .build()?;
+    pub(crate) async fn login_with_client(
+        mut auth: A,
+        user_agent: String,
+        client: ReqwestClient,
+        no_redirect: ReqwestClient,
+    ) -> Result<Client<A>, Error> {
         let b = auth.oauth();
         let _x = auth.login(&client, &user_agent).await?;
         let r_t = auth.get_refresh_token();
         Ok(Client {
-            auth: std::sync::Arc::new(tokio::sync::RwLock::new(auth)),
+            auth: std::sync::Arc::new(arc_swap::ArcSwap::from_pointee(auth)),
+            refresh: std::sync::Arc::default(),
             client,
+            no_redirect,
+            base_headers: Self::base_headers(&user_agent),
             user_agent,
             oauth: b,
             refresh_token: r_t,
+            rate_limit: RateLimitState::default(),
+            base_url: None,
+            raw_json: false,
+            log_bodies: false,
+            decompress: true,
+            relogin_on_unauthorized: true,
+            cache: None,
         })
     }
     #[cfg(not(target_arch = "wasm32"))]
     #[cfg(not(feature = "shared_authentication"))]
-    pub async fn login<S: Into<String>>(mut auth: A, user_agent: S) -> Result<Client<A>, Error> {
-        let user_agent = user_agent.into();
-        let client = ClientBuilder::new()
-            .user_agent(user_agent.clone())
-            .build()?;
+    pub(crate) async fn login_with_client(
+        mut auth: A,
+        user_agent: String,
+        client: ReqwestClient,
+        no_redirect: ReqwestClient,
+    ) -> Result<Client<A>, Error> {
         let b = auth.oauth();
         let r_t = auth.get_refresh_token();
         auth.login(&client, &user_agent).await?;
         Ok(Client {
             auth,
             client,
+            no_redirect,
+            base_headers: Self::base_headers(&user_agent),
             user_agent,
             oauth: b,
             refresh_token: r_t,
+            rate_limit: RateLimitState::default(),
+            base_url: None,
+            raw_json: false,
+            log_bodies: false,
+            decompress: true,
+            cache: None,
         })
     }
 
+    /// The user agent and accept headers shared by every request
+    fn base_headers(user_agent: &str) -> HeaderMap {
+        let mut headers = HeaderMap::with_capacity(4);
+        if let Ok(value) = HeaderValue::from_str(user_agent) {
+            headers.insert(USER_AGENT, value);
+        }
+        headers.insert(ACCEPT, HeaderValue::from_static("application/json"));
+        headers
+    }
+
+    /// Adds `headers` to every request. Such as `X-Forwarded-For` or a tracing header for a gateway.
+    /// Replaces the headers set by an earlier call or [ClientBuilder::default_headers].
+    ///
+    /// The `User-Agent`, `Accept` and the headers of the authenticator such as `Authorization` always take precedence.
+    /// For headers on some requests only, use them on a clone of the Client.
+    ///
+    /// They are not sent with the requests of the authenticator. Logging in, refreshing and revoking the token
+    /// only send the headers the [Authenticator] sets
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() ->anyhow::Result<()>{
+    ///    use reqwest::header::{HeaderMap, HeaderValue};
+    ///    use rraw::auth::AnonymousAuthenticator;
+    ///    use rraw::Client;
+    ///    let client = Client::login(AnonymousAuthenticator::new(), "RRAW Test (by u/KingTuxWH)").await?;
+    ///    let mut headers = HeaderMap::new();
+    ///    headers.insert("x-request-id", HeaderValue::from_static("export-42"));
+    ///    let traced = client.clone().with_default_headers(headers);
+    ///    let subreddit = traced.subreddit("rust").await?;
+    ///    Ok(())
+    /// }
+    /// ```
+    pub fn with_default_headers(mut self, headers: HeaderMap) -> Client<A> {
+        let mut merged = headers;
+        merged.extend(Self::base_headers(&self.user_agent));
+        self.base_headers = merged;
+        self
+    }
+
+    /// Batches `/api/info` lookups made within `window` of each other. See [InfoBatcher]
+    #[cfg(feature = "batch")]
+    pub fn info_batcher(&self, window: Duration) -> InfoBatcher<'_, A> {
+        InfoBatcher::new(self, window)
+    }
+
+    /// A Reddit Live thread. No request is made until one of its methods is called
+    pub fn live_thread<S: Into<String>>(&self, id: S) -> LiveThread<'_, A> {
+        LiveThread {
+            client: self,
+            id: id.into(),
+        }
+    }
     /// Loads a Domain listing
     /// ```rust
     /// #[tokio::main]
@@ -129,9 +319,30 @@ impl<A: Authenticator> Client<A> {
             options.extend(&mut path)
         }
         return self
-            .get_json::<SubmissionsResponse>(&path, false, true)
+            .get_json::<SubmissionsResponse>(&path, Access::WebOnly)
             .await;
     }
+    /// Several subreddits read as one. Reddit merges their listings, so one request replaces one per subreddit.
+    /// No request is made until one of its methods is called.
+    ///
+    /// Returns [Error::InvalidOption] if a name is not valid or the joined names are longer than [MAX_COMBINED_LENGTH](crate::subreddit::combined::MAX_COMBINED_LENGTH)
+    /// ```rust
+    /// #[tokio::main]
+    /// async fn main() ->anyhow::Result<()>{
+    /// use rraw::auth::AnonymousAuthenticator;
+    ///    use rraw::Client;
+    ///    let client = Client:: login(AnonymousAuthenticator::new(), "RRAW Test (by u/KingTuxWH)").await?;
+    ///    let subreddits = client.subreddits(&["rust", "programming"])?;
+    ///    assert_eq!(subreddits.joined(), "rust+programming");
+    ///    Ok(())
+    /// }
+    /// ```
+    pub fn subreddits<S: AsRef<str>>(
+        &self,
+        names: &[S],
+    ) -> Result<CombinedSubreddits<'_, A>, Error> {
+        CombinedSubreddits::new(self, names)
+    }
     /// Loads SubReddit
     /// ```rust
     /// #[tokio::main]
@@ -148,7 +359,7 @@ impl<A: Authenticator> Client<A> {
     pub async fn subreddit<T: Into<String>>(&self, name: T) -> Result<Subreddit<'_, A>, Error> {
         let string = format!("/r/{}/about.json", name.into());
         let subreddit = self
-            .get_json::<SubredditResponse>(&string, false, false)
+            .get_json::<SubredditResponse>(&string, Access::Any)
             .await?;
         Ok(Subreddit {
             me: self,
@@ -157,6 +368,9 @@ impl<A: Authenticator> Client<A> {
     }
 
     /// Creates a User struct.
+    ///
+    /// A deleted or nonexistent account is [HTTPError::NotFound].
+    /// A suspended account is Ok with [is_suspended](crate::user::response::AboutUser::is_suspended) set
     /// ```rust
     /// #[tokio::main]
     /// async fn main() ->anyhow::Result<()>{
@@ -170,12 +384,171 @@ impl<A: Authenticator> Client<A> {
     /// }
     /// ```
     pub async fn user<T: Into<String>>(&self, name: T) -> Result<User<'_, A>, Error> {
-        let string = format!("/u/{}/about", name.into());
-        let user = self.get_json::<UserResponse>(&string, false, false).await?;
-        Ok(User {
-            me: self,
-            user: user.data,
-        })
+        let user = self.about_user(&name.into()).await?;
+        Ok(User::loaded(self, user.data))
+    }
+
+    /// Loads the about of each user with up to `concurrency` requests at a time.
+    ///
+    /// Results are in the order the requests complete. A suspended or deleted account is an error for that name only.
+    /// Once Reddit reports no requests remaining the next requests wait for the rate limit to reset
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() ->anyhow::Result<()>{
+    ///    use rraw::auth::AnonymousAuthenticator;
+    ///    use rraw::Client;
+    ///    let client = Client::login(AnonymousAuthenticator::new(), "RRAW Test (by u/KingTuxWH)").await?;
+    ///    for (name, about) in client.users_about(&["KingTuxWH", "TheSmartKing"], 4).await {
+    ///        match about {
+    ///            Ok(about) => println!("{name}: {} karma", about.data.link_karma),
+    ///            Err(error) => println!("{name}: {error}"),
+    ///        }
+    ///    }
+    ///    Ok(())
+    /// }
+    /// ```
+    #[cfg(feature = "stream")]
+    pub async fn users_about(
+        &self,
+        names: &[&str],
+        concurrency: usize,
+    ) -> Vec<(String, Result<UserResponse, Error>)> {
+        let requests = names.iter().map(|name| async move {
+            if let Some(reset_in) = self.rate_limit.exhausted() {
+                debug!(
+                    "No requests remaining. Waiting {:?} to load {}",
+                    reset_in, name
+                );
+                tokio::time::sleep(reset_in).await;
+            }
+            (name.to_string(), self.about_user(name).await)
+        });
+        stream::iter(requests)
+            .buffer_unordered(concurrency.max(1))
+            .collect()
+            .await
+    }
+
+    /// Suspended accounts may be missing the name. It is filled in with the requested one
+    pub(crate) async fn about_user(&self, name: &str) -> Result<UserResponse, Error> {
+        let string = format!("/u/{name}/about");
+        let mut user = self.get_json::<UserResponse>(&string, Access::Any).await?;
+        if user.data.name.is_empty() {
+            user.data.name = name.to_string();
+        }
+        Ok(user)
+    }
+    /// Loads a comment with `context` levels of its parents. Returns the submission and the comments as a [CommentTree].
+    /// The top level of the tree is the oldest parent loaded, or the comment itself with a `context` of 0.
+    ///
+    /// Reddit only accepts 0 to 8 levels so `context` is clamped to [MAX_CONTEXT]
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() ->anyhow::Result<()>{
+    ///    use rraw::auth::AnonymousAuthenticator;
+    ///    use rraw::Client;
+    ///    let client = Client::login(AnonymousAuthenticator::new(), "RRAW Test (by u/KingTuxWH)").await?;
+    ///    let (submission, comments) = client.comment_context("t3_15bfi0", "t1_jtq9zz1", 3).await?;
+    ///    println!("{} comments on {}", comments.len(), submission.title);
+    ///    Ok(())
+    /// }
+    /// ```
+    pub async fn comment_context(
+        &self,
+        link_id: &str,
+        comment_id: &str,
+        context: u8,
+    ) -> Result<(SubmissionResponse, CommentTree), Error> {
+        if context > MAX_CONTEXT {
+            debug!(
+                "Comment context {context} is outside of 0..={MAX_CONTEXT}. Using {MAX_CONTEXT}"
+            );
+        }
+        let string = format!(
+            "/comments/{}/_/{}.json?context={}",
+            FullnameKind::Link.strip(link_id),
+            FullnameKind::Comment.strip(comment_id),
+            context.min(MAX_CONTEXT)
+        );
+        let (submission, tree) = self
+            .get_json::<(SubmissionsResponse, CommentTree)>(&string, Access::Any)
+            .await?;
+        let submission = submission
+            .data
+            .children
+            .into_iter()
+            .next()
+            .ok_or_else(|| Error::from("Reddit did not return the submission"))?;
+        Ok((submission.data, tree))
+    }
+
+    /// Looks up the submission a link points to. Such as `https://redd.it/15bfi0` or a full permalink.
+    ///
+    /// Links are parsed with [RedditUrl]. Media links such as `https://v.redd.it/{id}` and share links are requested
+    /// to find the submission they redirect to.
+    /// A link to a submission that does not exist is returned as [HTTPError::NotFound].
+    /// Links that do not point to a submission are returned as [Error::InvalidOption]
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() ->anyhow::Result<()>{
+    ///    use rraw::auth::AnonymousAuthenticator;
+    ///    use rraw::Client;
+    ///    let client = Client::login(AnonymousAuthenticator::new(), "RRAW Test (by u/KingTuxWH)").await?;
+    ///    let submission = client.resolve_shortlink("https://redd.it/15bfi0").await?;
+    ///    println!("{}", submission.title);
+    ///    Ok(())
+    /// }
+    /// ```
+    pub async fn resolve_shortlink(&self, url: &str) -> Result<SubmissionResponse, Error> {
+        let url = Url::parse(url)
+            .map_err(|error| Error::InvalidOption(format!("{url} is not a URL: {error}")))?;
+        let id = match RedditUrl::parse(url.as_str()) {
+            Ok(RedditUrl::Submission { id, .. }) => id,
+            Ok(RedditUrl::Comment { submission_id, .. }) => submission_id,
+            Ok(RedditUrl::Share(_)) => self.media_submission_id(url).await?,
+            Err(_) if is_media_host(&url) => self.media_submission_id(url).await?,
+            _ => {
+                return Err(Error::InvalidOption(format!(
+                    "{url} is not a link to a submission"
+                )))
+            }
+        };
+        self.info_single(&Fullname::new(FullnameKind::Link, id).to_string())
+            .await?
+            .ok_or_else(|| HTTPError::NotFound.into())
+    }
+    /// Requests the media or share link and takes the submission id from where it redirects to
+    async fn media_submission_id(&self, url: Url) -> Result<String, Error> {
+        let target = self.redirect_target(url).await?;
+        match submission_id(&target) {
+            Some(id) => Ok(id),
+            None => {
+                debug!("{} is not a submission", target);
+                Err(HTTPError::NotFound.into())
+            }
+        }
+    }
+    /// Requests the link without following the redirect and returns the `Location` it redirects to.
+    ///
+    /// The link itself if it does not redirect. The body is never read
+    pub(crate) async fn redirect_target(&self, url: Url) -> Result<Url, Error> {
+        debug!("GET {} for its redirect", redact_url(url.as_str()));
+        let request = self
+            .no_redirect
+            .get(url.clone())
+            .headers(self.base_headers.clone())
+            .build()?;
+        let response = self.execute_with(&self.no_redirect, request).await?;
+        if !response.status().is_redirection() {
+            check_response(response).await?;
+            return Ok(url);
+        }
+        let location = response
+            .headers()
+            .get(LOCATION)
+            .and_then(|location| location.to_str().ok())
+            .and_then(|location| url.join(location).ok());
+        Ok(location.unwrap_or(url))
     }
 
     /// Searches for Subreddits by name
@@ -199,12 +572,12 @@ impl<A: Authenticator> Client<A> {
     ) -> crate::error::Result<Subreddits> {
         let mut url = format!("/subreddits/search?q={}", name.into());
         if let Some(options) = feed {
-            url.push_str(options.url().as_str());
+            options.extend(&mut url);
         }
         if let Some(limit) = limit {
             let _ = write!(url, "&limit={}", limit);
         }
-        self.get_json::<Subreddits>(&url, false, false).await
+        self.get_json::<Subreddits>(&url, Access::Any).await
     }
 
     /// Searches for Subreddits by name
@@ -228,12 +601,115 @@ impl<A: Authenticator> Client<A> {
     ) -> crate::error::Result<Users> {
         let mut url = format!("/users/search?raw_json=1&q={}", name.into());
         if let Some(options) = feed {
-            url.push_str(options.url().as_str());
+            options.extend(&mut url);
         }
         if let Some(limit) = limit {
             let _ = write!(url, "&limit={}", limit);
         }
-        self.get_json::<Users>(&url, false, false).await
+        self.get_json::<Users>(&url, Access::Any).await
+    }
+
+    /// Gets the most popular Subreddits. Use the `after` value of the FeedOption to page through them
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() ->anyhow::Result<()>{
+    ///    use rraw::auth::AnonymousAuthenticator;
+    ///    use rraw::Client;
+    ///    let client = Client:: login(AnonymousAuthenticator::new(), "RRAW Test (by u/KingTuxWH)").await?;
+    ///    let subreddits = client.popular_subreddits(None).await?;
+    ///    Ok(())
+    /// }
+    /// ```
+    pub async fn popular_subreddits(
+        &self,
+        feed: Option<FeedOption>,
+    ) -> crate::error::Result<Subreddits> {
+        let mut url = "/subreddits/popular.json".to_string();
+        if let Some(options) = feed {
+            options.extend(&mut url);
+        }
+        self.get_json::<Subreddits>(&url, Access::Any).await
+    }
+
+    /// Gets the newest Subreddits. Use the `after` value of the FeedOption to page through them
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() ->anyhow::Result<()>{
+    ///    use rraw::auth::AnonymousAuthenticator;
+    ///    use rraw::Client;
+    ///    let client = Client:: login(AnonymousAuthenticator::new(), "RRAW Test (by u/KingTuxWH)").await?;
+    ///    let subreddits = client.new_subreddits(None).await?;
+    ///    Ok(())
+    /// }
+    /// ```
+    pub async fn new_subreddits(
+        &self,
+        feed: Option<FeedOption>,
+    ) -> crate::error::Result<Subreddits> {
+        let mut url = "/subreddits/new.json".to_string();
+        if let Some(options) = feed {
+            options.extend(&mut url);
+        }
+        self.get_json::<Subreddits>(&url, Access::Any).await
+    }
+    /// The subreddits trending today. Only served from the non OAuth host
+    pub async fn trending_subreddits(&self) -> crate::error::Result<TrendingSubreddits> {
+        self.get_json::<TrendingSubreddits>("/api/trending_subreddits.json", Access::WebOnly)
+            .await
+    }
+    /// Subreddits similar to the `seeds`. Subreddits in `omit` are left out.
+    ///
+    /// Returns [Error::InvalidOption] if there are no seeds
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() ->anyhow::Result<()>{
+    ///    use rraw::auth::AnonymousAuthenticator;
+    ///    use rraw::Client;
+    ///    let client = Client:: login(AnonymousAuthenticator::new(), "RRAW Test (by u/KingTuxWH)").await?;
+    ///    let recommended = client.recommend_subreddits(&["rust"], &["programming"]).await?;
+    ///    Ok(())
+    /// }
+    /// ```
+    pub async fn recommend_subreddits(
+        &self,
+        seeds: &[&str],
+        omit: &[&str],
+    ) -> crate::error::Result<Vec<RecommendedSubreddit>> {
+        if seeds.is_empty() {
+            return Err(Error::InvalidOption(
+                "at least one seed subreddit is required".to_string(),
+            ));
+        }
+        let mut url = format!("/api/recommend/sr/{}", seeds.join(","));
+        if !omit.is_empty() {
+            let query = serde_urlencoded::to_string([("omit", omit.join(","))]).unwrap_or_default();
+            extend_query(&mut url, &query);
+        }
+        self.get_json::<Vec<RecommendedSubreddit>>(&url, Access::Any)
+            .await
+    }
+    /// Loads the collection with its submissions
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() ->anyhow::Result<()>{
+    ///    use rraw::auth::AnonymousAuthenticator;
+    ///    use rraw::Client;
+    ///    let client = Client::login(AnonymousAuthenticator::new(), "RRAW Test (by u/KingTuxWH)").await?;
+    ///    let collection = client.collection("a8c1c0b4-5b5e-4a4b-9f6d-0e5f0c1e2d3f").await?;
+    ///    for submission in collection.submissions() {
+    ///        println!("{}", submission.title);
+    ///    }
+    ///    Ok(())
+    /// }
+    /// ```
+    pub async fn collection(&self, collection_id: &str) -> Result<Collection, Error> {
+        let query = serde_urlencoded::to_string([
+            ("collection_id", collection_id),
+            ("include_links", "true"),
+        ])
+        .unwrap_or_default();
+        let string = format!("/api/v1/collections/collection?{query}");
+        self.get_json::<Collection>(&string, Access::Any).await
     }
     #[cfg(not(feature = "shared_authentication"))]
     pub async fn re_login(&mut self) -> Result<bool, error::Error> {
@@ -241,109 +717,632 @@ impl<A: Authenticator> Client<A> {
             .token_refresh(&self.client, &self.user_agent)
             .await
     }
+    /// Refreshes the token. Requests made meanwhile keep using the old token
     #[cfg(feature = "shared_authentication")]
     pub async fn re_login(&self) -> Result<bool, error::Error> {
-        let mut guard = self.auth.write().await;
-        guard.token_refresh(&self.client, &self.user_agent).await
+        let _refreshing = self.refresh.lock().await;
+        self.swap_refreshed().await
+    }
+    /// Forgets the token without revoking it. Requests return [Error::TokenExpired] until [Client::re_login]
+    #[cfg(not(feature = "shared_authentication"))]
+    pub fn invalidate_token(&mut self) {
+        self.auth.invalidate_token();
+    }
+    /// Forgets the token without revoking it, so the next request logs in again.
+    /// Useful for testing how a token refresh is handled.
+    ///
+    /// Authenticators without a refresh token, such as a [CodeAuthenticator](crate::auth::CodeAuthenticator)
+    /// created without one, can not log in again.
+    ///
+    /// Waits for a token refresh in progress, so the refreshed token does not replace the invalidated one
+    #[cfg(feature = "shared_authentication")]
+    pub async fn invalidate_token(&self) {
+        let _refreshing = self.refresh.lock().await;
+        let mut auth = A::clone(&self.auth.load());
+        auth.invalidate_token();
+        self.auth.store(std::sync::Arc::new(auth));
+    }
+    /// Refreshes the token unless another task refreshed it while we waited.
+    ///
+    /// Retryable failures such as a 503 are tried again up to [REFRESH_ATTEMPTS] times. Waiting [REFRESH_BACKOFF]
+    /// doubled after each attempt. The last failure is returned as [Error::AuthFailed]
+    #[cfg(feature = "shared_authentication")]
+    async fn refresh_expired(&self) -> Result<(), error::Error> {
+        let _refreshing = self.refresh.lock().await;
+        if !self.auth.load().needs_token_refresh() {
+            trace!("Token was refreshed by another request");
+            return Ok(());
+        }
+        let mut backoff = REFRESH_BACKOFF;
+        let mut attempt = 1;
+        loop {
+            match self.swap_refreshed().await {
+                Ok(_) => return Ok(()),
+                Err(error) if error.is_retryable() && attempt < REFRESH_ATTEMPTS => {
+                    let wait = error
+                        .retry_after()
+                        .unwrap_or(backoff)
+                        .min(MAX_REFRESH_BACKOFF);
+                    warn!(
+                        "Refreshing the token failed on attempt {attempt} of {REFRESH_ATTEMPTS}. Retrying in {wait:?}: {error}"
+                    );
+                    tokio::time::sleep(wait).await;
+                    backoff *= 2;
+                    attempt += 1;
+                }
+                Err(error) => return Err(Error::AuthFailed(Box::new(error))),
+            }
+        }
+    }
+    /// Refreshes a copy of the authenticator then stores it. Dropping the future leaves the old one in place
+    #[cfg(feature = "shared_authentication")]
+    async fn swap_refreshed(&self) -> Result<bool, error::Error> {
+        let mut auth = A::clone(&self.auth.load());
+        let refreshed = auth.token_refresh(&self.client, &self.user_agent).await?;
+        self.auth.store(std::sync::Arc::new(auth));
+        Ok(refreshed)
     }
 }
 
 impl<A: Authenticator> Client<A> {
     /// Gets the authenticator. Internal use
     #[cfg(feature = "shared_authentication")]
-    pub(crate) async fn get_authenticator(&self) -> tokio::sync::RwLockReadGuard<'_, A> {
-        self.auth.read().await
+    pub(crate) fn get_authenticator(&self) -> arc_swap::Guard<std::sync::Arc<A>> {
+        self.auth.load()
     }
     #[cfg(not(feature = "shared_authentication"))]
     pub(crate) fn get_authenticator(&self) -> &A {
         &self.auth
     }
-    pub(crate) async fn get(
+    pub(crate) async fn get(&self, url: &str, access: Access) -> Result<Response, Error> {
+        self.send_get(url, access, None).await
+    }
+    /// `extra` is added to the base headers. Such as the `If-None-Match` of a cached response
+    async fn send_get(
         &self,
         url: &str,
-        oauth: bool,
-        private_api: bool,
+        access: Access,
+        extra: Option<HeaderMap>,
     ) -> Result<Response, Error> {
         let authenticator = get_auth!(self);
-        let string = self.build_url(url, oauth, authenticator.oauth(), private_api);
-        let mut headers = HeaderMap::new();
+        let path = access.path(url, authenticator.oauth());
+        let mut string = self.build_url(&path, access, authenticator.oauth())?;
+        if self.raw_json && !string.contains("raw_json=") {
+            extend_query(&mut string, "raw_json=1");
+        }
+        let mut headers = self.base_headers.clone();
+        if let Some(extra) = extra {
+            headers.extend(extra);
+        }
         authenticator.headers(&mut headers);
         #[cfg(feature = "shared_authentication")]
         drop(authenticator);
-        self.client
-            .get(string)
-            .headers(headers)
-            .send()
-            .await
-            .map_err(|error| Error::InternalError(InternalError::ReqwestError(error)))
+        Self::check_logged_in(access, &headers)?;
+        debug!("GET {}", redact_url(&string));
+        trace!(
+            "GET {} Headers: {:?}",
+            redact_url(&string),
+            redact_headers(&headers)
+        );
+        let request = self.client.get(string).headers(headers).build()?;
+        let response = self.execute(request).await?;
+        Self::check_login_wall(response)
     }
     /// Makes a post request with Reqwest response
-    pub(crate) async fn post(&self, url: &str, oauth: bool, body: Body) -> Result<Response, Error> {
+    pub(crate) async fn post(
+        &self,
+        url: &str,
+        access: Access,
+        body: Body,
+    ) -> Result<Response, Error> {
+        self.send_post(url, access, body, false).await
+    }
+    /// The modhash is only added to form bodies. [CookieAuthenticator](crate::auth::CookieAuthenticator) also sends it as a header
+    async fn send_post(
+        &self,
+        url: &str,
+        access: Access,
+        body: Body,
+        json: bool,
+    ) -> Result<Response, Error> {
         let authenticator = get_auth!(self);
 
-        let string = self.build_url(url, oauth, authenticator.oauth(), false);
-        let mut headers = HeaderMap::new();
+        let string = self.build_url(url, access, authenticator.oauth())?;
+        let mut headers = self.base_headers.clone();
         authenticator.headers(&mut headers);
+        let body = match authenticator.modhash() {
+            Some(modhash) if !json => append_form(body, "uh", &modhash),
+            _ => body,
+        };
+        if json {
+            headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        }
         #[cfg(feature = "shared_authentication")]
         drop(authenticator);
-        self.client
+        Self::check_logged_in(access, &headers)?;
+        debug!("POST {}", redact_url(&string));
+        trace!(
+            "POST {} Headers: {:?}",
+            redact_url(&string),
+            redact_headers(&headers)
+        );
+        let request = self
+            .client
             .post(string)
             .body(body)
             .headers(headers)
-            .send()
+            .build()?;
+        let response = self.execute(request).await?;
+        Self::check_login_wall(response)
+    }
+    /// Sends the request and records the rate limit headers.
+    ///
+    /// With [ClientBuilder::relogin_on_unauthorized] a 401 to an OAuth request logs in again and sends it once more
+    async fn execute(&self, request: Request) -> Result<Response, Error> {
+        self.execute_with(&self.client, request).await
+    }
+    /// [Client::execute] with another reqwest client. Such as the one that does not follow redirects
+    async fn execute_with(
+        &self,
+        client: &ReqwestClient,
+        request: Request,
+    ) -> Result<Response, Error> {
+        #[cfg(feature = "shared_authentication")]
+        let retry = if self.relogin_on_unauthorized && self.oauth {
+            request.try_clone()
+        } else {
+            None
+        };
+        let response = client.execute(request).await?;
+        self.rate_limit.update(response.headers());
+        #[cfg(feature = "shared_authentication")]
+        if let Some(retry) = retry.filter(|_| response.status() == StatusCode::UNAUTHORIZED) {
+            return self.retry_unauthorized(client, retry).await;
+        }
+        Ok(response)
+    }
+    /// Logs in again and sends the request with the new token. A second 401 is [Error::AuthenticationExpired]
+    #[cfg(feature = "shared_authentication")]
+    async fn retry_unauthorized(
+        &self,
+        client: &ReqwestClient,
+        mut request: Request,
+    ) -> Result<Response, Error> {
+        warn!(
+            "Reddit rejected the token for {}. Logging in again",
+            redact_url(request.url().as_str())
+        );
+        let rejected = request.headers().get(AUTHORIZATION).cloned();
+        self.relogin_rejected(rejected.as_ref()).await?;
+        self.get_authenticator().headers(request.headers_mut());
+        let response = client.execute(request).await?;
+        self.rate_limit.update(response.headers());
+        if response.status() == StatusCode::UNAUTHORIZED {
+            return Err(Error::AuthenticationExpired);
+        }
+        Ok(response)
+    }
+    /// Replaces the rejected token unless another request already did
+    #[cfg(feature = "shared_authentication")]
+    async fn relogin_rejected(&self, rejected: Option<&HeaderValue>) -> Result<(), Error> {
+        let _refreshing = self.refresh.lock().await;
+        let mut current = HeaderMap::new();
+        self.get_authenticator().headers(&mut current);
+        if current.get(AUTHORIZATION) != rejected {
+            trace!("The token was replaced by another request");
+            return Ok(());
+        }
+        let mut auth = A::clone(&self.auth.load());
+        auth.invalidate_token();
+        auth.token_refresh(&self.client, &self.user_agent)
             .await
-            .map_err(Error::from)
+            .map_err(|error| Error::AuthFailed(Box::new(error)))?;
+        self.auth.store(std::sync::Arc::new(auth));
+        Ok(())
+    }
+    /// The rate limit headers of the last response. None if Reddit has not sent them yet
+    pub fn rate_limit(&self) -> Option<RateLimit> {
+        self.rate_limit.get()
     }
-    /// Makes a get request with JSON response
+    /// Creates a [Paginator] that waits between pages based on Reddit's rate limit headers
+    #[cfg(feature = "stream")]
+    pub(crate) fn paginate<'a, T, F, Fut>(&self, options: FeedOption, fetch: F) -> Paginator<'a, T>
+    where
+        T: Debug,
+        F: FnMut(FeedOption) -> Fut + 'a,
+        Fut: std::future::Future<Output = crate::error::Result<GenericListing<T>>> + 'a,
+    {
+        Paginator::new(options, fetch).rate_limited(self.rate_limit.clone())
+    }
+    /// Reddit redirects anonymous requests for some endpoints to the login page or a `.compact` page.
+    pub(crate) fn check_login_wall(response: Response) -> Result<Response, Error> {
+        if response.status().is_redirection() {
+            let location = response
+                .headers()
+                .get(LOCATION)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|location| response.url().join(location).ok());
+            if let Some(location) = location.filter(builder::is_login_wall) {
+                trace!("{} redirected to {}", response.url(), location);
+                return Err(Error::RequiresAuth);
+            }
+        }
+        Ok(response)
+    }
+    /// Makes a get request with JSON response.
+    ///
+    /// With [ClientBuilder::response_cache] the request is conditional if the last response had an `ETag` or `Last-Modified`.
+    /// A `304 Not Modified` parses the cached body instead of downloading it again
     pub(crate) async fn get_json<T: DeserializeOwned>(
         &self,
         url: &str,
-        oauth: bool,
-        private_api: bool,
+        access: Access,
     ) -> crate::error::Result<T> {
-        let response = self.get(url, oauth, private_api).await?;
-        response.status().into_result()?;
-        response
-            .json()
-            .await
-            .map_err(|error| Error::InternalError(InternalError::ReqwestError(error)))
+        let cache = match &self.cache {
+            Some(cache) => cache,
+            None => {
+                let response = self.get(url, access).await?;
+                let response = check_response(response).await?;
+                return self.parse_json(response).await;
+            }
+        };
+        let key = CacheKey::new(url);
+        let conditional = cache.conditional_headers(&key);
+        let mut response = self.send_get(url, access, conditional).await?;
+        if response.status() == StatusCode::NOT_MODIFIED {
+            if let Some(body) = cache.get(&key) {
+                trace!("{} was not modified. Using the cached response", url);
+                let url = response.url().to_string();
+                return Self::parse_json_slice(&url, body);
+            }
+            // Dropped from the cache since the request was made
+            response = self.get(url, access).await?;
+        }
+        let response = check_response(response).await?;
+        let headers = response.headers().clone();
+        let url = response.url().to_string();
+        let body = self.read_body(response).await?;
+        cache.store(key, &headers, body.clone());
+        Self::parse_json_slice(&url, body)
+    }
+    /// Makes a get request for a `.rss` feed. Always sent to the website
+    #[cfg(feature = "rss")]
+    pub(crate) async fn get_feed(&self, url: &str) -> crate::error::Result<Vec<rss::RssItem>> {
+        let response = self.get(url, Access::WebOnly).await?;
+        let response = check_response(response).await?;
+        let url = response.url().to_string();
+        let body = self.read_body(response).await?;
+        rss::parse_feed(&url, &String::from_utf8_lossy(&body))
+    }
+    /// Makes a get request returning the parsed value alongside the raw body.
+    ///
+    /// Useful for logging the response when Reddit changes the schema of an endpoint
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() ->anyhow::Result<()>{
+    ///    use rraw::auth::AnonymousAuthenticator;
+    ///    use rraw::subreddit::response::SubredditResponse;
+    ///    use rraw::access::Access;
+    ///    use rraw::Client;
+    ///    let client = Client:: login(AnonymousAuthenticator::new(), "RRAW Test (by u/KingTuxWH)").await?;
+    ///    let (subreddit, raw) = client.get_json_with_raw::<SubredditResponse>("/r/rust/about.json", Access::Any).await?;
+    ///    println!("{raw}");
+    ///    Ok(())
+    /// }
+    /// ```
+    pub async fn get_json_with_raw<T: DeserializeOwned>(
+        &self,
+        url: &str,
+        access: Access,
+    ) -> crate::error::Result<(T, String)> {
+        let response = self.get(url, access).await?;
+        let response = check_response(response).await?;
+        let url = response.url().to_string();
+        let body = self.read_body(response).await?;
+        let raw = String::from_utf8_lossy(&body).into_owned();
+        let value = Self::parse_json_slice(&url, body)?;
+        Ok((value, raw))
+    }
+    /// Makes a get request to an endpoint RRAW does not wrap yet.
+    ///
+    /// This is an escape hatch. Prefer the typed methods. Its behavior is not covered by semver stability
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() ->anyhow::Result<()>{
+    ///    use rraw::auth::AnonymousAuthenticator;
+    ///    use rraw::access::Access;
+    ///    use rraw::Client;
+    ///    let client = Client::login(AnonymousAuthenticator::new(), "RRAW Test (by u/KingTuxWH)").await?;
+    ///    let value = client.get_raw("/r/rust/about/rules.json", Access::Any).await?;
+    ///    println!("{}", value["rules"]);
+    ///    Ok(())
+    /// }
+    /// ```
+    pub async fn get_raw(&self, path: &str, access: Access) -> crate::error::Result<Value> {
+        self.get_json::<Value>(path, access).await
+    }
+    /// Makes a get request returning the body without parsing it.
+    ///
+    /// If the Client was built with [ClientBuilder::decompress] disabled the request sends `Accept-Encoding: gzip`
+    /// and the body is the gzip stream exactly as Reddit sent it. Otherwise it is the decoded body.
+    /// The body of an error is always decoded before it is read into the [ErrorResponse](crate::error::http_error::ErrorResponse)
+    pub async fn get_bytes(&self, path: &str, access: Access) -> crate::error::Result<Bytes> {
+        let extra = (!self.decompress).then(|| {
+            let mut headers = HeaderMap::new();
+            headers.insert(ACCEPT_ENCODING, HeaderValue::from_static("gzip"));
+            headers
+        });
+        let response = self.send_get(path, access, extra).await?;
+        let response = check_gzip_response(response).await?;
+        Ok(response.bytes().await?)
     }
-    /// Makes a post request with JSON response
+    /// Makes a form post request to an endpoint RRAW does not wrap yet. The values are percent encoded.
+    ///
+    /// This is an escape hatch. Prefer the typed methods. Its behavior is not covered by semver stability
+    pub async fn post_raw(
+        &self,
+        path: &str,
+        form: &[(&str, &str)],
+        access: Access,
+    ) -> crate::error::Result<Value> {
+        self.post_json::<Value>(path, access, form_body(form)).await
+    }
+    /// Makes a post request with JSON response.
+    ///
+    /// Reddit responds with 200 OK to failed `api_type=json` requests. Errors in the envelope are returned
+    /// as [Error::Api], [Error::RateLimited] or [Error::CaptchaRequired]
     pub(crate) async fn post_json<T: DeserializeOwned>(
         &self,
         url: &str,
-        oauth: bool,
+        access: Access,
         body: Body,
     ) -> crate::error::Result<T> {
-        let response = self.post(url, oauth, body).await?;
-        response.status().into_result()?;
-        response
-            .json()
+        let response = self.post(url, access, body).await?;
+        self.parse_api_json(response).await
+    }
+    /// [Client::post_json] with a JSON body
+    pub(crate) async fn post_json_body<T: DeserializeOwned, B: Serialize + ?Sized>(
+        &self,
+        url: &str,
+        access: Access,
+        body: &B,
+    ) -> crate::error::Result<T> {
+        let body = serde_json::to_vec(body)?;
+        let response = self.send_post(url, access, Body::from(body), true).await?;
+        self.parse_api_json(response).await
+    }
+    /// Makes a post request ignoring the body of the response. Any success status such as `202 Accepted` is Ok.
+    ///
+    /// The body is still checked for an `api_type=json` error envelope
+    pub(crate) async fn post_empty(
+        &self,
+        url: &str,
+        access: Access,
+        body: Body,
+    ) -> crate::error::Result<()> {
+        let response = self.post(url, access, body).await?;
+        let response = check_response(response).await?;
+        let body = self.read_body(response).await?;
+        check_api_envelope(&body)
+    }
+    /// Makes a delete request ignoring the body of the response
+    pub(crate) async fn delete(&self, url: &str, access: Access) -> crate::error::Result<()> {
+        let authenticator = get_auth!(self);
+        let string = self.build_url(url, access, authenticator.oauth())?;
+        let mut headers = self.base_headers.clone();
+        authenticator.headers(&mut headers);
+        #[cfg(feature = "shared_authentication")]
+        drop(authenticator);
+        Self::check_logged_in(access, &headers)?;
+        debug!("DELETE {}", redact_url(&string));
+        let request = self.client.delete(string).headers(headers).build()?;
+        let response = self.execute(request).await?;
+        check_response(response).await?;
+        Ok(())
+    }
+    /// Returns [Error::Api] if the `json.errors` array is not empty
+    pub(crate) async fn parse_api_json<T: DeserializeOwned>(
+        &self,
+        response: Response,
+    ) -> crate::error::Result<T> {
+        let response = check_response(response).await?;
+        let url = response.url().to_string();
+        let body = self.read_body(response).await?;
+        check_api_envelope(&body)?;
+        Self::parse_json_slice(&url, body)
+    }
+    /// Looks up a single thing by its fullname using `/api/info`
+    pub(crate) async fn info_single<T: DeserializeOwned + Debug + Clone + Send + Sync + 'static>(
+        &self,
+        fullname: &str,
+    ) -> crate::error::Result<Option<T>> {
+        let url = format!("/api/info?id={fullname}");
+        let listing = self
+            .get_json::<GenericListing<T>>(&url, Access::Any)
+            .await?;
+        Ok(listing
+            .data
+            .children
+            .into_iter()
+            .next()
+            .map(|child| child.data))
+    }
+    /// Edits the text of a comment or self post. Returning the thing as Reddit now has it.
+    ///
+    /// If Reddit does not include the thing in the response it is looked up with `/api/info`
+    pub(crate) async fn edit_user_text<
+        T: DeserializeOwned + Debug + Clone + Send + Sync + 'static,
+    >(
+        &self,
+        fullname: &str,
+        text: &str,
+    ) -> crate::error::Result<T> {
+        let body = form_body(&[("api_type", "json"), ("thing_id", fullname), ("text", text)]);
+        let response: JsonResponse<Things<GenericResponse<T>>> = self
+            .post_json("/api/editusertext", Access::OAuthOnly, body)
+            .await?;
+        let thing = response
+            .into_result()?
+            .and_then(|things| things.things.into_iter().next());
+        match thing {
+            Some(thing) => Ok(thing.data),
+            None => self
+                .info_single(fullname)
+                .await?
+                .ok_or_else(|| HTTPError::NotFound.into()),
+        }
+    }
+    /// Hides or unhides the submissions. `endpoint` is `/api/hide` or `/api/unhide`
+    pub(crate) async fn hide_things(
+        &self,
+        endpoint: &str,
+        fullnames: &[&str],
+    ) -> crate::error::Result<()> {
+        let body = form_body(&[("id", &fullnames.join(","))]);
+        self.post_empty(endpoint, Access::OAuthOnly, body).await
+    }
+    /// Toggles inbox notifications for replies to the submission or comment
+    pub(crate) async fn set_send_replies(
+        &self,
+        fullname: &str,
+        enabled: bool,
+    ) -> crate::error::Result<()> {
+        let state = enabled.to_string();
+        let body = form_body(&[("id", fullname), ("state", state.as_str())]);
+        self.post_empty("/api/sendreplies", Access::OAuthOnly, body)
             .await
-            .map_err(|error| Error::InternalError(InternalError::ReqwestError(error)))
     }
+    /// Votes on the submission or comment
+    pub(crate) async fn vote(
+        &self,
+        fullname: &str,
+        direction: VoteDirection,
+    ) -> crate::error::Result<()> {
+        let direction = direction.to_string();
+        let body = form_body(&[("id", fullname), ("dir", direction.as_str())]);
+        self.post_empty("/api/vote", Access::OAuthOnly, body).await
+    }
+    /// Posts to an endpoint that only takes the `id` of a thing. Such as `/api/save` or `/api/del`
+    pub(crate) async fn thing_action(
+        &self,
+        endpoint: &str,
+        fullname: &str,
+    ) -> crate::error::Result<()> {
+        self.post_empty(endpoint, Access::OAuthOnly, form_body(&[("id", fullname)]))
+            .await
+    }
+    /// Reports the thing to the moderators
+    pub(crate) async fn report(&self, fullname: &str, reason: &str) -> crate::error::Result<()> {
+        let body = form_body(&[
+            ("api_type", "json"),
+            ("thing_id", fullname),
+            ("reason", reason),
+        ]);
+        self.post_empty("/api/report", Access::OAuthOnly, body)
+            .await
+    }
+    /// Removes the thing as a moderator. `spam` trains the spam filter
+    pub(crate) async fn remove_thing(
+        &self,
+        fullname: &str,
+        spam: bool,
+    ) -> crate::error::Result<()> {
+        let spam = spam.to_string();
+        let body = form_body(&[("id", fullname), ("spam", spam.as_str())]);
+        self.post_empty("/api/remove", Access::OAuthOnly, body)
+            .await
+    }
+    /// Replies to a submission, comment or message. Returning the new comment
+    pub(crate) async fn reply(
+        &self,
+        fullname: &str,
+        text: &str,
+    ) -> crate::error::Result<CommentResponse> {
+        let body = form_body(&[("api_type", "json"), ("thing_id", fullname), ("text", text)]);
+        let response: JsonResponse<Things<GenericResponse<CommentResponse>>> = self
+            .post_json("/api/comment", Access::OAuthOnly, body)
+            .await?;
+        response
+            .into_result()?
+            .and_then(|things| things.things.into_iter().next())
+            .map(|thing| thing.data)
+            .ok_or_else(|| Error::from("Reddit did not return the comment"))
+    }
+    /// Reads the body and deserializes it. Errors will contain the path to the failing value
+    pub(crate) async fn parse_json<T: DeserializeOwned>(
+        &self,
+        response: Response,
+    ) -> crate::error::Result<T> {
+        let url = response.url().to_string();
+        let body = self.read_body(response).await?;
+        Self::parse_json_slice(&url, body)
+    }
+    /// Reads the body as bytes. Logged at trace if [ClientBuilder::log_bodies] is set
+    async fn read_body(&self, response: Response) -> crate::error::Result<Bytes> {
+        let url = response.url().to_string();
+        let body = response.bytes().await?;
+        if self.log_bodies {
+            trace!(
+                "{} responded with {}",
+                redact_url(&url),
+                String::from_utf8_lossy(&body)
+            );
+        }
+        Ok(body)
+    }
+    /// The body is only copied into a String to take the snippet for a failure.
+    ///
+    /// With the `simd-json` feature the body is copied into a buffer simd-json parses in place.
+    /// Its failures are returned as [InternalError::SimdDeserializeError]. Only then serde_json parses the kept body to find the snippet
+    pub(crate) fn parse_json_slice<T: DeserializeOwned>(
+        url: &str,
+        body: Bytes,
+    ) -> crate::error::Result<T> {
+        #[cfg(feature = "simd-json")]
+        {
+            // simd-json rewrites escaped strings in the buffer. The original is kept for the snippet
+            let mut buffer = body.to_vec();
+            let simd_error = |path: String, source| {
+                let position = serde_json::from_slice::<T>(&body)
+                    .err()
+                    .map_or((1, 1), |error| (error.line(), error.column()));
+                let text = String::from_utf8_lossy(&body);
+                InternalError::simd_deserialize(url, path, &text, position, source)
+            };
+            let mut deserializer = simd_json::Deserializer::from_slice(&mut buffer)
+                .map_err(|error| simd_error(String::new(), error))?;
+            serde_path_to_error::deserialize(&mut deserializer).map_err(|error| {
+                let path = error.path().to_string();
+                simd_error(path, error.into_inner()).into()
+            })
+        }
+        #[cfg(not(feature = "simd-json"))]
+        {
+            let deserializer = &mut serde_json::Deserializer::from_slice(&body);
+            serde_path_to_error::deserialize(deserializer).map_err(|error| {
+                let path = error.path().to_string();
+                let body = String::from_utf8_lossy(&body);
+                InternalError::deserialize(url, path, &body, error.into_inner()).into()
+            })
+        }
+    }
+    /// OAuth only endpoints need a bearer token. Fail before sending the request if we have none
+    pub(crate) fn check_logged_in(access: Access, headers: &HeaderMap) -> Result<(), Error> {
+        if access.requires_oauth() && !headers.contains_key(AUTHORIZATION) {
+            return Err(Error::NotLoggedIn);
+        }
+        Ok(())
+    }
+    /// The host is picked by [Access::host]. Returns [Error::NotLoggedIn] if OAuth is required and the authenticator does not support it
     pub(crate) fn build_url(
         &self,
         dest: &str,
-        oauth_required: bool,
+        access: Access,
         oauth_supported: bool,
-        private_api: bool,
-    ) -> String {
-        let stem = if private_api {
-            "https://reddit.com"
-        } else if oauth_required || oauth_supported {
-            // All endpoints support OAuth, but some do not support the regular endpoint. If we are
-            // required to use it or support it, we will use it.
-            assert!(
-                oauth_supported,
-                "OAuth is required to use this endpoint, but your authenticator does not \
-                     support it."
-            );
-            "https://oauth.reddit.com"
-        } else {
-            "https://api.reddit.com"
-        };
-        format!("{stem}{dest}")
+    ) -> Result<String, Error> {
+        let stem = access.host(oauth_supported)?;
+        let stem = self.base_url.as_deref().unwrap_or(stem);
+        Ok(format!("{stem}{dest}"))
     }
 }
 
@@ -363,8 +1362,12 @@ impl<A: Authorized> Client<A> {
     /// }
     /// ```
     pub async fn me(&self) -> Result<Me<'_, A>, Error> {
-        let me: MeResponse = self.get_json("/api/v1/me", true, false).await?;
-        Ok(Me { client: self, me })
+        let me: MeResponse = self.get_json("/api/v1/me", Access::OAuthOnly).await?;
+        Ok(Me {
+            client: self,
+            me,
+            default_feed: None,
+        })
     }
     /// Gets the Refresh Token if exist
     ///
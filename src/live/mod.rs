@@ -0,0 +1,130 @@
+pub mod response;
+
+use crate::access::Access;
+use crate::auth::Authenticator;
+use crate::error::Error;
+#[cfg(feature = "stream")]
+use crate::live::response::LiveUpdate;
+use crate::live::response::{
+    ContributorsResponse, LiveContributor, LiveThreadResponse, LiveUpdates,
+};
+use crate::utils::options::FeedOption;
+use crate::Client;
+#[cfg(feature = "stream")]
+use futures_util::{stream, Stream};
+#[cfg(feature = "stream")]
+use std::collections::VecDeque;
+#[cfg(feature = "stream")]
+use std::time::Duration;
+
+/// A Reddit Live thread. Reading does not require logging in
+#[derive(Clone)]
+pub struct LiveThread<'a, A: Authenticator> {
+    pub(crate) client: &'a Client<A>,
+    pub id: String,
+}
+
+impl<'a, A: Authenticator> LiveThread<'a, A> {
+    /// The title, description and state of the thread
+    pub async fn about(&self) -> Result<LiveThreadResponse, Error> {
+        let string = format!("/live/{}/about.json", self.id);
+        self.client
+            .get_json::<LiveThreadResponse>(&string, Access::Any)
+            .await
+    }
+    /// The updates of the thread. Newest first
+    pub async fn updates(&self, feed: Option<FeedOption>) -> Result<LiveUpdates, Error> {
+        let mut string = format!("/live/{}.json", self.id);
+        if let Some(options) = feed {
+            options.extend(&mut string);
+        }
+        self.client
+            .get_json::<LiveUpdates>(&string, Access::Any)
+            .await
+    }
+    /// The users allowed to post updates
+    pub async fn contributors(&self) -> Result<Vec<LiveContributor>, Error> {
+        let string = format!("/live/{}/contributors.json", self.id);
+        self.client
+            .get_json::<ContributorsResponse>(&string, Access::Any)
+            .await
+            .map(ContributorsResponse::contributors)
+    }
+    /// Polls the thread every `interval`. Yielding each update posted after the stream started once. Oldest first.
+    ///
+    /// Uses the `before` cursor of the newest update seen. Errors from polling are yielded and polling continues
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() ->anyhow::Result<()>{
+    ///    use std::time::Duration;
+    ///    use futures_util::StreamExt;
+    ///    use rraw::auth::AnonymousAuthenticator;
+    ///    use rraw::Client;
+    ///    let client = Client::login(AnonymousAuthenticator::new(), "RRAW Test (by u/KingTuxWH)").await?;
+    ///    let thread = client.live_thread("18hnzysb1elcs");
+    ///    let mut updates = Box::pin(thread.stream_updates(Duration::from_secs(30)));
+    ///    while let Some(update) = updates.next().await {
+    ///        println!("{}", update?.body);
+    ///    }
+    ///    Ok(())
+    /// }
+    /// ```
+    #[cfg(feature = "stream")]
+    pub fn stream_updates(
+        &self,
+        interval: Duration,
+    ) -> impl Stream<Item = Result<LiveUpdate, Error>> + 'a {
+        let state = UpdatesState {
+            thread: self.clone(),
+            buffer: VecDeque::new(),
+            cursor: None,
+            polled: false,
+        };
+        stream::unfold(state, move |mut state| async move {
+            loop {
+                if let Some(update) = state.buffer.pop_front() {
+                    return Some((Ok(update), state));
+                }
+                if state.polled {
+                    tokio::time::sleep(interval).await;
+                }
+                let first = !state.polled;
+                state.polled = true;
+                // The first poll only needs the newest update for the cursor
+                let options = FeedOption {
+                    before: state.cursor.clone(),
+                    limit: Some(if first { 1 } else { FeedOption::MAX_LIMIT }),
+                    ..FeedOption::default()
+                };
+                match state.thread.updates(Some(options)).await {
+                    Ok(updates) => state.push_new(updates, first),
+                    Err(error) => return Some((Err(error), state)),
+                }
+            }
+        })
+    }
+}
+
+#[cfg(feature = "stream")]
+struct UpdatesState<'a, A: Authenticator> {
+    thread: LiveThread<'a, A>,
+    buffer: VecDeque<LiveUpdate>,
+    /// The fullname of the newest update seen
+    cursor: Option<String>,
+    polled: bool,
+}
+
+#[cfg(feature = "stream")]
+impl<'a, A: Authenticator> UpdatesState<'a, A> {
+    /// Moves the cursor to the newest update. The first poll only sets the cursor
+    fn push_new(&mut self, updates: LiveUpdates, first: bool) {
+        // Reddit returns the newest first
+        if let Some(newest) = updates.data.children.first() {
+            self.cursor = Some(newest.data.name.clone());
+        }
+        if !first {
+            self.buffer
+                .extend(updates.into_iter().rev().map(|update| update.data));
+        }
+    }
+}
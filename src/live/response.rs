@@ -0,0 +1,108 @@
+use crate::responses::listing::GenericListing;
+use crate::responses::GenericResponse;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Debug, Formatter};
+
+/// An embedded link in a live update. Such as a tweet or video
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct LiveEmbed {
+    pub url: String,
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+}
+
+/// A single update posted to a live thread
+#[derive(Deserialize, Serialize, Clone, PartialEq)]
+pub struct LiveUpdate {
+    /// A UUID
+    pub id: String,
+    /// The fullname. `LiveUpdate_{id}`
+    pub name: String,
+    pub body: String,
+    pub body_html: Option<String>,
+    /// None if the account was deleted
+    pub author: Option<String>,
+    pub created_utc: f64,
+    #[serde(default)]
+    pub embeds: Vec<LiveEmbed>,
+    /// The update was struck out by a contributor. It is shown crossed out
+    #[serde(default)]
+    pub stricken: bool,
+}
+
+impl Debug for LiveUpdate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[LiveUpdate] ID: {}", self.id)
+    }
+}
+
+/// About a live thread. Reddit's kind is `LiveUpdateEvent`
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct LiveThreadAbout {
+    pub id: String,
+    pub title: String,
+    pub description: String,
+    pub description_html: Option<String>,
+    pub resources: String,
+    pub resources_html: Option<String>,
+    /// `live` or `complete`
+    pub state: String,
+    pub viewer_count: Option<u64>,
+    #[serde(default)]
+    pub nsfw: bool,
+    pub created_utc: f64,
+    pub websocket_url: Option<String>,
+    pub announcement_url: Option<String>,
+}
+
+impl LiveThreadAbout {
+    /// The thread still accepts updates
+    pub fn is_live(&self) -> bool {
+        self.state == "live"
+    }
+}
+
+/// A user allowed to post updates
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct LiveContributor {
+    pub name: String,
+    /// The fullname of the account
+    pub id: String,
+    #[serde(default)]
+    pub permissions: Vec<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub(crate) struct UserList {
+    pub(crate) data: UserListData,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub(crate) struct UserListData {
+    pub(crate) children: Vec<LiveContributor>,
+}
+
+/// Reddit sends a second list of invited contributors if you are a contributor
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(untagged)]
+pub(crate) enum ContributorsResponse {
+    Single(UserList),
+    WithInvites(Vec<UserList>),
+}
+
+impl ContributorsResponse {
+    /// The current contributors. Invites are dropped
+    pub(crate) fn contributors(self) -> Vec<LiveContributor> {
+        match self {
+            ContributorsResponse::Single(list) => list.data.children,
+            ContributorsResponse::WithInvites(lists) => lists
+                .into_iter()
+                .next()
+                .map(|list| list.data.children)
+                .unwrap_or_default(),
+        }
+    }
+}
+
+pub type LiveThreadResponse = GenericResponse<LiveThreadAbout>;
+pub type LiveUpdates = GenericListing<LiveUpdate>;
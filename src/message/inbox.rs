@@ -0,0 +1,193 @@
+use crate::access::Access;
+use crate::auth::Authorized;
+use crate::error::Error;
+#[cfg(feature = "stream")]
+use crate::message::response::InboxItem;
+use crate::message::response::InboxListing;
+use crate::user::response::MeResponse;
+use crate::utils::form_body;
+use crate::utils::options::FeedOption;
+use crate::Client;
+#[cfg(feature = "stream")]
+use futures_util::{stream, Stream};
+#[cfg(feature = "stream")]
+use log::warn;
+use reqwest::Body;
+#[cfg(feature = "stream")]
+use std::collections::{HashSet, VecDeque};
+#[cfg(feature = "stream")]
+use std::time::Duration;
+
+/// The max number of fullnames sent in a single mark read or unread request
+pub const MARK_BATCH_SIZE: usize = 100;
+
+/// The Inbox of the logged in user. Requires the `privatemessages` scope
+#[derive(Clone)]
+pub struct Inbox<'a, A: Authorized> {
+    pub(crate) client: &'a Client<A>,
+}
+
+impl<'a, A: Authorized> Inbox<'a, A> {
+    /// Everything in the Inbox
+    pub async fn all(&self, feed: Option<FeedOption>) -> Result<InboxListing, Error> {
+        self.listing("inbox", feed).await
+    }
+    /// Unread messages and comment replies
+    pub async fn unread(&self, feed: Option<FeedOption>) -> Result<InboxListing, Error> {
+        self.listing("unread", feed).await
+    }
+    /// Messages you have sent
+    pub async fn sent(&self, feed: Option<FeedOption>) -> Result<InboxListing, Error> {
+        self.listing("sent", feed).await
+    }
+    /// Comments that mention you
+    pub async fn mentions(&self, feed: Option<FeedOption>) -> Result<InboxListing, Error> {
+        self.listing("mentions", feed).await
+    }
+    /// The number of unread items. Uses `/api/v1/me` so it is cheaper than loading the unread listing
+    pub async fn count(&self) -> Result<u64, Error> {
+        let me: MeResponse = self
+            .client
+            .get_json("/api/v1/me", Access::OAuthOnly)
+            .await?;
+        Ok(me.inbox_count)
+    }
+    /// Marks the messages or comment replies as read. Sent in batches of [MARK_BATCH_SIZE]
+    pub async fn mark_read(&self, fullnames: &[&str]) -> Result<(), Error> {
+        self.mark("/api/read_message", fullnames).await
+    }
+    /// Marks the messages or comment replies as unread. Sent in batches of [MARK_BATCH_SIZE]
+    pub async fn mark_unread(&self, fullnames: &[&str]) -> Result<(), Error> {
+        self.mark("/api/unread_message", fullnames).await
+    }
+    /// Marks everything as read.
+    ///
+    /// Reddit responds with `202 Accepted` and processes it in the background. So unread items may still show up for a short time
+    pub async fn mark_all_read(&self) -> Result<(), Error> {
+        self.client
+            .post_empty("/api/read_all_messages", Access::OAuthOnly, Body::from(""))
+            .await
+    }
+    /// Polls the unread messages every `interval`. Yielding each item once. Oldest first.
+    ///
+    /// If `mark_read` is true the items are marked read as they are yielded. If marking one fails its fullname is
+    /// retried on the next poll instead of yielding the item again. Errors from polling are yielded and polling continues
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() ->anyhow::Result<()>{
+    ///    use std::env;
+    ///    use std::time::Duration;
+    ///    use futures_util::StreamExt;
+    ///    use rraw::auth::PasswordAuthenticator;
+    ///    use rraw::Client;
+    ///    let client = Client::login(PasswordAuthenticator::new(env::var("CLIENT_ID")?,env::var("CLIENT_SECRET")?,env::var("USERNAME")?,env::var("PASSWORD")?), "RRAW Test (by u/KingTuxWH)").await?;
+    ///    let me = client.me().await?;
+    ///    let inbox = me.inbox();
+    ///    let mut unread = Box::pin(inbox.stream_unread(Duration::from_secs(30), true));
+    ///    while let Some(item) = unread.next().await {
+    ///        println!("{}", item?.subject());
+    ///    }
+    ///    Ok(())
+    /// }
+    /// ```
+    #[cfg(feature = "stream")]
+    pub fn stream_unread(
+        &self,
+        interval: Duration,
+        mark_read: bool,
+    ) -> impl Stream<Item = Result<InboxItem, Error>> + 'a {
+        let state = UnreadState {
+            inbox: self.clone(),
+            buffer: VecDeque::new(),
+            seen: HashSet::new(),
+            unmarked: Vec::new(),
+            polled: false,
+        };
+        stream::unfold(state, move |mut state| async move {
+            loop {
+                if let Some(item) = state.buffer.pop_front() {
+                    if mark_read {
+                        if let Err(error) = state.inbox.mark_read(&[item.name()]).await {
+                            warn!(
+                                "Unable to mark {} read. Retrying on the next poll: {}",
+                                item.name(),
+                                error
+                            );
+                            state.unmarked.push(item.name().to_string());
+                        }
+                    }
+                    return Some((Ok(item), state));
+                }
+                if state.polled {
+                    tokio::time::sleep(interval).await;
+                }
+                state.polled = true;
+                if !state.unmarked.is_empty() {
+                    let unmarked: Vec<&str> = state.unmarked.iter().map(String::as_str).collect();
+                    match state.inbox.mark_read(&unmarked).await {
+                        Ok(_) => state.unmarked.clear(),
+                        Err(error) => {
+                            warn!("Unable to mark {} items read: {}", unmarked.len(), error)
+                        }
+                    }
+                }
+                let options = FeedOption {
+                    limit: Some(FeedOption::MAX_LIMIT),
+                    ..FeedOption::default()
+                };
+                match state.inbox.unread(Some(options)).await {
+                    Ok(listing) => state.push_new(listing),
+                    Err(error) => return Some((Err(error), state)),
+                }
+            }
+        })
+    }
+    async fn mark(&self, endpoint: &str, fullnames: &[&str]) -> Result<(), Error> {
+        for batch in fullnames.chunks(MARK_BATCH_SIZE) {
+            let body = form_body(&[("id", &batch.join(","))]);
+            self.client
+                .post_empty(endpoint, Access::OAuthOnly, body)
+                .await?;
+        }
+        Ok(())
+    }
+    async fn listing(
+        &self,
+        listing: &str,
+        feed: Option<FeedOption>,
+    ) -> Result<InboxListing, Error> {
+        let mut string = format!("/message/{listing}.json");
+        if let Some(options) = feed {
+            options.extend(&mut string);
+        }
+        self.client
+            .get_json::<InboxListing>(&string, Access::OAuthOnly)
+            .await
+    }
+}
+
+#[cfg(feature = "stream")]
+struct UnreadState<'a, A: Authorized> {
+    inbox: Inbox<'a, A>,
+    buffer: VecDeque<InboxItem>,
+    /// Fullnames already yielded that are still unread
+    seen: HashSet<String>,
+    /// Fullnames that failed to be marked read
+    unmarked: Vec<String>,
+    polled: bool,
+}
+
+#[cfg(feature = "stream")]
+impl<'a, A: Authorized> UnreadState<'a, A> {
+    /// Buffers the items that have not been yielded. Forgets the items that are no longer unread
+    fn push_new(&mut self, listing: InboxListing) {
+        let unread: HashSet<String> = listing.iter().map(|item| item.name().to_string()).collect();
+        self.seen.retain(|name| unread.contains(name));
+        // Reddit returns the newest first
+        for item in listing.into_iter().rev() {
+            if self.seen.insert(item.name().to_string()) {
+                self.buffer.push_back(item);
+            }
+        }
+    }
+}
@@ -1,8 +1,23 @@
+pub mod inbox;
 pub mod response;
 
 use core::fmt;
 use std::fmt::{Display, Formatter};
 
+use crate::access::Access;
+use crate::auth::Authorized;
+use crate::comments::response::{CommentResponse, REDDIT_URL};
+use crate::error::Error;
+use crate::message::inbox::Inbox;
+use crate::message::response::{CommentReply, InboxItem};
+use crate::submission::response::SubmissionResponse;
+use crate::thing::private::Sealed;
+use crate::thing::{Deletable, Replyable, Reportable};
+use crate::utils::form_body;
+use crate::utils::fullname::FullnameKind;
+use crate::Client;
+use async_trait::async_trait;
+
 /// What Inbox you want to look at
 pub enum WhereMessage {
     /// Everything
@@ -23,3 +38,97 @@ impl Display for WhereMessage {
         write!(f, "{}", string)
     }
 }
+
+/// A Message or Comment Reply from the Inbox with access to the Client
+pub struct Message<'a, A: Authorized> {
+    pub message: &'a InboxItem,
+    pub(crate) me: &'a Client<A>,
+}
+
+impl InboxItem {
+    pub fn to_message<'a, A: Authorized>(&'a self, me: &'a Client<A>) -> Message<'a, A> {
+        Message { message: self, me }
+    }
+}
+
+impl<'a, A: Authorized> Sealed for Message<'a, A> {
+    type Auth = A;
+    fn client(&self) -> &Client<A> {
+        self.me
+    }
+    fn thing_fullname(&self) -> String {
+        self.message.name().to_string()
+    }
+}
+
+impl<'a, A: Authorized> Reportable for Message<'a, A> {}
+
+#[async_trait(?Send)]
+impl<'a, A: Authorized> Deletable for Message<'a, A> {
+    /// Deletes the private message from your Inbox. It is not deleted for the other user.
+    ///
+    /// Returns [Error::InvalidOption] for comment replies. Only `t4` private messages can be deleted
+    async fn delete(&self) -> Result<(), Error> {
+        let name = self.message.name();
+        if !matches!(self.message, InboxItem::Message(_)) || !FullnameKind::Message.matches(name) {
+            return Err(Error::InvalidOption(format!(
+                "{name} is not a private message"
+            )));
+        }
+        self.me
+            .post_empty(
+                "/api/del_msg",
+                Access::OAuthOnly,
+                form_body(&[("id", name)]),
+            )
+            .await
+    }
+}
+
+#[async_trait(?Send)]
+impl<'a, A: Authorized> Replyable for Message<'a, A> {
+    /// Replies to the comment. Returns the new comment.
+    ///
+    /// Returns [Error::InvalidOption] for private messages
+    async fn reply(&self, text: &str) -> Result<CommentResponse, Error> {
+        let reply = self.comment_reply()?;
+        self.me.reply(&reply.message.name, text).await
+    }
+}
+
+impl<'a, A: Authorized> Message<'a, A> {
+    /// Blocks the author of the message. Works for private messages and comment replies
+    pub async fn block_author(&self) -> Result<(), Error> {
+        let body = form_body(&[("id", self.message.name())]);
+        self.me
+            .post_empty("/api/block", Access::OAuthOnly, body)
+            .await
+    }
+    /// Marks the message or comment reply as read
+    pub async fn mark_read(&self) -> Result<(), Error> {
+        Inbox { client: self.me }
+            .mark_read(&[self.message.name()])
+            .await
+    }
+    /// Loads the submission the comment was made on. Found from the `context` of the comment.
+    ///
+    /// Returns [Error::InvalidOption] for private messages
+    pub async fn parent_submission(&self) -> Result<SubmissionResponse, Error> {
+        let reply = self.comment_reply()?;
+        let context = reply.message.context.as_deref().ok_or_else(|| {
+            Error::InvalidOption(format!("{} does not have a context", reply.message.name))
+        })?;
+        self.me
+            .resolve_shortlink(&format!("{REDDIT_URL}{context}"))
+            .await
+    }
+    fn comment_reply(&self) -> Result<&'a CommentReply, Error> {
+        match self.message {
+            InboxItem::CommentReply(reply) => Ok(reply),
+            InboxItem::Message(message) => Err(Error::InvalidOption(format!(
+                "{} is not a comment reply",
+                message.name
+            ))),
+        }
+    }
+}
@@ -1,9 +1,10 @@
-use crate::responses::listing::GenericListing;
+use crate::responses::listing::{GenericListing, Listing};
+use crate::responses::types::Distinguished;
 use crate::responses::{FullName, GenericResponse};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
 pub struct Message {
     pub associated_awarding_id: Option<Value>,
     pub author: String,
@@ -14,7 +15,7 @@ pub struct Message {
     pub created: f64,
     pub created_utc: f64,
     pub dest: Option<String>,
-    pub distinguished: Option<String>,
+    pub distinguished: Option<Distinguished>,
     pub first_message: Option<Value>,
     pub first_message_name: Option<Value>,
     pub id: String,
@@ -28,7 +29,7 @@ pub struct Message {
     pub subject: String,
     pub subreddit: Option<String>,
     pub subreddit_name_prefixed: Option<String>,
-    #[serde(rename(deserialize = "type"))]
+    #[serde(rename = "type")]
     pub type_: Option<String>,
     #[serde(default)]
     pub was_comment: bool,
@@ -37,3 +38,52 @@ pub struct Message {
 pub type MessageResponse = GenericResponse<Message>;
 /// A listing of user abouts
 pub type MessageListing = GenericListing<Message>;
+
+/// A comment reply or mention in the Inbox. Reddit sends these as `t1` with the message fields
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct CommentReply {
+    #[serde(flatten)]
+    pub message: Message,
+    /// The title of the submission the comment is on
+    pub link_title: Option<String>,
+}
+
+/// An item in the Inbox. Either a private message(`t4`) or a comment reply(`t1`)
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[serde(tag = "kind", content = "data")]
+pub enum InboxItem {
+    #[serde(rename = "t4")]
+    Message(Message),
+    #[serde(rename = "t1")]
+    CommentReply(CommentReply),
+}
+
+impl InboxItem {
+    /// The fields shared between messages and comment replies
+    pub fn message(&self) -> &Message {
+        match self {
+            InboxItem::Message(message) => message,
+            InboxItem::CommentReply(reply) => &reply.message,
+        }
+    }
+    /// The fullname of the item
+    pub fn name(&self) -> &str {
+        &self.message().name
+    }
+    pub fn author(&self) -> &str {
+        &self.message().author
+    }
+    pub fn subject(&self) -> &str {
+        &self.message().subject
+    }
+    pub fn body(&self) -> Option<&str> {
+        self.message().body.as_deref()
+    }
+    /// True if the item is unread
+    pub fn is_new(&self) -> bool {
+        self.message().new.unwrap_or_default()
+    }
+}
+
+/// A listing of Inbox items
+pub type InboxListing = GenericResponse<Listing<InboxItem>>;
@@ -0,0 +1,129 @@
+pub mod response;
+
+use core::fmt;
+use std::fmt::{Display, Formatter};
+
+use crate::access::Access;
+use crate::auth::Authorized;
+use crate::error::Error;
+use crate::modmail::response::{ConversationResponse, Conversations};
+use crate::utils::form_body;
+use crate::utils::options::FeedOption;
+use crate::Client;
+
+/// The Modmail conversation filters
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConversationState {
+    All,
+    New,
+    InProgress,
+    Archived,
+    Appeals,
+    Highlighted,
+    Mod,
+    Notifications,
+    JoinRequests,
+    Filtered,
+}
+
+impl Display for ConversationState {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let string = match self {
+            ConversationState::All => "all",
+            ConversationState::New => "new",
+            ConversationState::InProgress => "inprogress",
+            ConversationState::Archived => "archived",
+            ConversationState::Appeals => "appeals",
+            ConversationState::Highlighted => "highlighted",
+            ConversationState::Mod => "mod",
+            ConversationState::Notifications => "notifications",
+            ConversationState::JoinRequests => "join_requests",
+            ConversationState::Filtered => "filtered",
+        };
+        write!(f, "{}", string)
+    }
+}
+
+/// New Modmail. Requires the `modmail` scope
+pub struct Modmail<'a, A: Authorized> {
+    pub(crate) client: &'a Client<A>,
+}
+
+impl<'a, A: Authorized> Modmail<'a, A> {
+    /// Conversations of the subreddits you moderate. `after` is a conversation id
+    pub async fn conversations(
+        &self,
+        state: ConversationState,
+        feed: Option<FeedOption>,
+    ) -> Result<Conversations, Error> {
+        let mut string = format!("/api/mod/conversations?state={state}");
+        if let Some(options) = feed {
+            options.extend(&mut string);
+        }
+        self.client
+            .get_json::<Conversations>(&string, Access::OAuthOnly)
+            .await
+    }
+    /// The messages and mod actions of the conversation. Does not mark it read
+    pub async fn conversation(&self, id: &str) -> Result<ConversationResponse, Error> {
+        let string = format!("/api/mod/conversations/{id}?markRead=false");
+        self.client
+            .get_json::<ConversationResponse>(&string, Access::OAuthOnly)
+            .await
+    }
+    /// Replies to the conversation.
+    ///
+    /// `is_internal` makes a private moderator note. `is_author_hidden` replies as the subreddit
+    pub async fn reply(
+        &self,
+        id: &str,
+        body: &str,
+        is_internal: bool,
+        is_author_hidden: bool,
+    ) -> Result<ConversationResponse, Error> {
+        let string = format!("/api/mod/conversations/{id}");
+        let form = form_body(&[
+            ("body", body),
+            ("isInternal", &is_internal.to_string()),
+            ("isAuthorHidden", &is_author_hidden.to_string()),
+        ]);
+        self.client
+            .post_json::<ConversationResponse>(&string, Access::OAuthOnly, form)
+            .await
+    }
+    pub async fn archive(&self, id: &str) -> Result<ConversationResponse, Error> {
+        self.action(id, "archive").await
+    }
+    pub async fn unarchive(&self, id: &str) -> Result<ConversationResponse, Error> {
+        self.action(id, "unarchive").await
+    }
+    /// Starts a conversation from the subreddit with the user `to`
+    pub async fn create(
+        &self,
+        subreddit: &str,
+        to: &str,
+        subject: &str,
+        body: &str,
+    ) -> Result<ConversationResponse, Error> {
+        let form = form_body(&[
+            ("srName", subreddit),
+            ("to", to),
+            ("subject", subject),
+            ("body", body),
+            ("isAuthorHidden", "false"),
+        ]);
+        self.client
+            .post_json::<ConversationResponse>("/api/mod/conversations", Access::OAuthOnly, form)
+            .await
+    }
+    async fn action(&self, id: &str, action: &str) -> Result<ConversationResponse, Error> {
+        let string = format!("/api/mod/conversations/{id}/{action}");
+        self.client
+            .post_json::<ConversationResponse>(
+                &string,
+                Access::OAuthOnly,
+                form_body(&[("conversation_id", id)]),
+            )
+            .await
+    }
+}
@@ -0,0 +1,141 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+/// A user or moderator in a conversation
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Participant {
+    pub name: String,
+    pub id: Option<u64>,
+    #[serde(default)]
+    pub is_mod: bool,
+    #[serde(default)]
+    pub is_admin: bool,
+    #[serde(default)]
+    pub is_op: bool,
+    #[serde(default)]
+    pub is_participant: bool,
+    /// The moderator replied as the subreddit
+    #[serde(default)]
+    pub is_hidden: bool,
+    #[serde(default)]
+    pub is_deleted: bool,
+}
+
+/// The subreddit that owns the conversation
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ConversationOwner {
+    pub display_name: String,
+    /// The fullname of the subreddit
+    pub id: String,
+    #[serde(rename = "type")]
+    pub owner_type: String,
+}
+
+/// A reference to a message or mod action in the conversation. In order
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct ObjectId {
+    pub id: String,
+    /// `messages` or `modActions`
+    pub key: String,
+}
+
+/// A Modmail Conversation
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Conversation {
+    pub id: String,
+    pub subject: String,
+    /// 0 New, 1 In Progress, 2 Archived
+    pub state: i64,
+    pub last_updated: Option<String>,
+    pub last_user_update: Option<String>,
+    pub last_mod_update: Option<String>,
+    #[serde(default)]
+    pub is_internal: bool,
+    #[serde(default)]
+    pub is_highlighted: bool,
+    #[serde(default)]
+    pub is_auto: bool,
+    #[serde(default)]
+    pub num_messages: u64,
+    pub participant: Option<Participant>,
+    #[serde(default)]
+    pub authors: Vec<Participant>,
+    pub owner: ConversationOwner,
+    #[serde(default)]
+    pub obj_ids: Vec<ObjectId>,
+}
+
+/// A message in a Modmail Conversation
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ModmailMessage {
+    pub id: String,
+    /// The rendered HTML
+    pub body: String,
+    pub body_markdown: String,
+    pub author: Participant,
+    /// Only visible to moderators
+    #[serde(default)]
+    pub is_internal: bool,
+    pub date: String,
+}
+
+/// An action such as archiving or highlighting the conversation
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ModAction {
+    pub id: String,
+    pub action_type_id: i64,
+    pub date: String,
+    pub author: Participant,
+}
+
+/// Response from `GET /api/mod/conversations`
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Conversations {
+    /// Conversations by id
+    pub conversations: HashMap<String, Conversation>,
+    /// The ids of the conversations in order
+    pub conversation_ids: Vec<String>,
+    /// The latest message of each conversation by message id
+    #[serde(default)]
+    pub messages: HashMap<String, ModmailMessage>,
+}
+
+impl Conversations {
+    /// The conversations in the order Reddit sorted them
+    pub fn ordered(&self) -> impl Iterator<Item = &Conversation> {
+        self.conversation_ids
+            .iter()
+            .filter_map(|id| self.conversations.get(id))
+    }
+}
+
+/// Response from `GET /api/mod/conversations/{id}` and the write endpoints
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ConversationResponse {
+    pub conversation: Conversation,
+    /// Messages by id
+    #[serde(default)]
+    pub messages: HashMap<String, ModmailMessage>,
+    /// Mod actions by id
+    #[serde(default)]
+    pub mod_actions: HashMap<String, ModAction>,
+}
+
+impl ConversationResponse {
+    /// The messages in the order they were sent
+    pub fn ordered_messages(&self) -> impl Iterator<Item = &ModmailMessage> {
+        self.conversation
+            .obj_ids
+            .iter()
+            .filter(|object| object.key == "messages")
+            .filter_map(|object| self.messages.get(&object.id))
+    }
+}
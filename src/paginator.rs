@@ -0,0 +1,243 @@
+use std::collections::VecDeque;
+use std::fmt::Debug;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
+
+use futures_util::future::LocalBoxFuture;
+use futures_util::{FutureExt, Stream, StreamExt};
+use log::trace;
+use thiserror::Error;
+use tokio::time::{sleep_until, Sleep};
+
+use crate::error::Error;
+use crate::ratelimit::{RateLimitState, DEFAULT_DELAY};
+use crate::responses::listing::GenericListing;
+use crate::utils::options::FeedOption;
+
+type Fetch<'a, T> =
+    Box<dyn FnMut(FeedOption) -> LocalBoxFuture<'a, Result<GenericListing<T>, Error>> + 'a>;
+
+/// A [Stream] over every item of a listing. Pages are loaded by following `after` until the end of the listing.
+///
+/// The stream ends when
+/// - Reddit returns a listing without an `after`
+/// - Reddit returns an empty page. This happens around the ~1000 item cap even if `after` is set
+/// - The item limit is reached
+/// - A request fails. The error is yielded first
+/// - The deadline passes. [Error::DeadlineExceeded] is yielded after the items already loaded
+///
+/// A Paginator is not [Send]. The requests may log in again and [Authenticator](crate::auth::Authenticator) futures
+/// are not [Send]. Poll it on the task that created it, or inside a `tokio::task::LocalSet` to spawn it
+/// ```no_run
+/// #[tokio::main]
+/// async fn main() ->anyhow::Result<()>{
+///    use futures_util::StreamExt;
+///    use rraw::auth::AnonymousAuthenticator;
+///    use rraw::utils::options::FeedOption;
+///    use rraw::Client;
+///    let client = Client::login(AnonymousAuthenticator::new(), "RRAW Test (by u/KingTuxWH)").await?;
+///    let user = client.user("KingTuxWH").await?;
+///    let mut submissions = user.submissions_stream(FeedOption::default()).limit(250);
+///    while let Some(submission) = submissions.next().await {
+///        println!("{}", submission?.title);
+///    }
+///    Ok(())
+/// }
+/// ```
+pub struct Paginator<'a, T: Debug> {
+    fetch: Fetch<'a, T>,
+    options: FeedOption,
+    buffer: VecDeque<T>,
+    state: State<'a, T>,
+    delay: Delay,
+    limit: Option<usize>,
+    yielded: usize,
+    deadline: Option<Pin<Box<Sleep>>>,
+}
+
+enum State<'a, T: Debug> {
+    First,
+    Next,
+    Sleeping(Pin<Box<Sleep>>),
+    Fetching(LocalBoxFuture<'a, Result<GenericListing<T>, Error>>),
+    Done,
+}
+
+enum Delay {
+    Fixed(Duration),
+    RateLimit(RateLimitState),
+}
+
+impl<'a, T: Debug> Paginator<'a, T> {
+    /// Creates a Paginator calling `fetch` for each page with the options for that page.
+    ///
+    /// Waits [DEFAULT_DELAY] between pages. Paginators created by RRAW wait based on Reddit's rate limit headers
+    pub fn new<F, Fut>(options: FeedOption, mut fetch: F) -> Self
+    where
+        F: FnMut(FeedOption) -> Fut + 'a,
+        Fut: Future<Output = Result<GenericListing<T>, Error>> + 'a,
+    {
+        Paginator {
+            fetch: Box::new(move |options| fetch(options).boxed_local()),
+            options,
+            buffer: VecDeque::new(),
+            state: State::First,
+            delay: Delay::Fixed(DEFAULT_DELAY),
+            limit: None,
+            yielded: 0,
+            deadline: None,
+        }
+    }
+    /// Stops after `limit` items
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+    /// Stops loading pages at `deadline`. A page still loading is dropped.
+    ///
+    /// The items already loaded are yielded first, then [Error::DeadlineExceeded]
+    pub fn deadline(mut self, deadline: Instant) -> Self {
+        let deadline = tokio::time::Instant::from_std(deadline);
+        self.deadline = Some(Box::pin(sleep_until(deadline)));
+        self
+    }
+    /// Stops loading pages `timeout` from now. See [Paginator::deadline]
+    ///
+    /// A timeout too large to add to the current time is no deadline
+    pub fn timeout(self, timeout: Duration) -> Self {
+        match Instant::now().checked_add(timeout) {
+            Some(deadline) => self.deadline(deadline),
+            None => self,
+        }
+    }
+    /// Waits a fixed amount of time between pages
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = Delay::Fixed(delay);
+        self
+    }
+    /// Waits between pages based on the rate limit headers the Client has seen
+    pub(crate) fn rate_limited(mut self, state: RateLimitState) -> Self {
+        self.delay = Delay::RateLimit(state);
+        self
+    }
+    /// Collects every item. If a request fails or the deadline passes the items loaded so far are returned inside the [PartialError]
+    pub async fn collect_partial(mut self) -> Result<Vec<T>, PartialError<T>> {
+        let mut items = Vec::new();
+        while let Some(item) = self.next().await {
+            match item {
+                Ok(item) => items.push(item),
+                Err(error) => return Err(PartialError { items, error }),
+            }
+        }
+        Ok(items)
+    }
+    fn next_delay(&self) -> Duration {
+        match &self.delay {
+            Delay::Fixed(delay) => *delay,
+            Delay::RateLimit(state) => state.delay(),
+        }
+    }
+    fn limit_reached(&self) -> bool {
+        self.limit.map_or(false, |limit| self.yielded >= limit)
+    }
+    /// Polling the timer wakes the stream at the deadline while a page is loading.
+    /// The clock is checked too because the timer only fires once the runtime gets to run it
+    fn deadline_passed(&mut self, cx: &mut Context<'_>) -> bool {
+        match &mut self.deadline {
+            Some(deadline) => {
+                deadline.deadline() <= tokio::time::Instant::now()
+                    || deadline.as_mut().poll(cx).is_ready()
+            }
+            None => false,
+        }
+    }
+    /// Buffers the children and moves the cursor to the next page
+    fn handle_page(&mut self, listing: GenericListing<T>) {
+        let next = listing.next_options(&self.options);
+        let len = listing.len();
+        self.buffer
+            .extend(listing.into_iter().map(|child| child.data));
+        match next {
+            Some(mut next) if len > 0 => {
+                next.count = Some(next.count.unwrap_or_default() + len as u32);
+                self.options = next;
+                self.state = State::Next;
+            }
+            _ => {
+                trace!("Listing finished after {} items", self.yielded + len);
+                self.state = State::Done;
+            }
+        }
+    }
+}
+
+/// A request failed part way through a listing
+#[derive(Error, Debug)]
+#[error("{error}. {} items were loaded before the error", .items.len())]
+pub struct PartialError<T: Debug> {
+    /// The items loaded before the error
+    pub items: Vec<T>,
+    #[source]
+    pub error: Error,
+}
+
+/// Drops the items
+impl<T: Debug> From<PartialError<T>> for Error {
+    fn from(value: PartialError<T>) -> Self {
+        value.error
+    }
+}
+
+// The items are never pinned
+impl<'a, T: Debug> Unpin for Paginator<'a, T> {}
+
+impl<'a, T: Debug> Stream for Paginator<'a, T> {
+    type Item = Result<T, Error>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+        loop {
+            if this.limit_reached() {
+                this.state = State::Done;
+                this.buffer.clear();
+                return Poll::Ready(None);
+            }
+            if let Some(item) = this.buffer.pop_front() {
+                this.yielded += 1;
+                return Poll::Ready(Some(Ok(item)));
+            }
+            if !matches!(this.state, State::Done) && this.deadline_passed(cx) {
+                trace!("Deadline passed after {} items", this.yielded);
+                this.state = State::Done;
+                return Poll::Ready(Some(Err(Error::DeadlineExceeded)));
+            }
+            match &mut this.state {
+                State::First => {
+                    this.state = State::Fetching((this.fetch)(this.options.clone()));
+                }
+                State::Next => {
+                    let delay = this.next_delay();
+                    trace!("Waiting {:?} before loading the next page", delay);
+                    this.state = State::Sleeping(Box::pin(tokio::time::sleep(delay)));
+                }
+                State::Sleeping(sleep) => {
+                    if sleep.as_mut().poll(cx).is_pending() {
+                        return Poll::Pending;
+                    }
+                    this.state = State::Fetching((this.fetch)(this.options.clone()));
+                }
+                State::Fetching(future) => match future.as_mut().poll(cx) {
+                    Poll::Pending => return Poll::Pending,
+                    Poll::Ready(Ok(listing)) => this.handle_page(listing),
+                    Poll::Ready(Err(error)) => {
+                        this.state = State::Done;
+                        return Poll::Ready(Some(Err(error)));
+                    }
+                },
+                State::Done => return Poll::Ready(None),
+            }
+        }
+    }
+}
@@ -0,0 +1,104 @@
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use reqwest::header::HeaderMap;
+
+/// The delay used between pages when Reddit has not sent any rate limit headers
+pub const DEFAULT_DELAY: Duration = Duration::from_secs(1);
+/// The longest wait read from Reddit. Longer ones are cut down to it
+pub const MAX_WAIT: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Converts a wait in seconds sent by Reddit. Negative values are zero and values over [MAX_WAIT] are [MAX_WAIT].
+///
+/// None if it is infinite or NaN
+pub(crate) fn wait_from_secs(seconds: f64) -> Option<Duration> {
+    if !seconds.is_finite() {
+        return None;
+    }
+    let seconds = seconds.max(0.0).min(MAX_WAIT.as_secs_f64());
+    Some(Duration::from_secs_f64(seconds))
+}
+
+/// Reddit's `X-Ratelimit-*` headers from the last response
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RateLimit {
+    /// Requests used in the current period
+    pub used: u32,
+    /// Requests remaining in the current period. Reddit sends this as a float
+    pub remaining: f64,
+    /// Time until the period resets. Relative to `received`
+    pub reset: Duration,
+    /// When the headers were received
+    pub received: Instant,
+}
+
+impl RateLimit {
+    /// Reads the rate limit headers. None if Reddit did not send them or they are not finite numbers
+    pub fn from_headers(headers: &HeaderMap) -> Option<RateLimit> {
+        let header = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.trim().parse::<f64>().ok())
+                .filter(|value| value.is_finite())
+        };
+        Some(RateLimit {
+            used: header("x-ratelimit-used").unwrap_or_default() as u32,
+            remaining: header("x-ratelimit-remaining")?,
+            reset: wait_from_secs(header("x-ratelimit-reset")?)?,
+            received: Instant::now(),
+        })
+    }
+    /// True if no requests are remaining. Also true for a NaN `remaining`
+    pub fn is_exhausted(&self) -> bool {
+        self.remaining.is_nan() || self.remaining < 1.0
+    }
+    /// The time left until the period resets
+    pub fn reset_in(&self) -> Duration {
+        self.reset.saturating_sub(self.received.elapsed())
+    }
+    /// How long to wait before the next request to spread the remaining requests evenly over the period.
+    ///
+    /// If none are remaining this is the time until the reset
+    pub fn delay(&self) -> Duration {
+        let reset_in = self.reset_in();
+        if self.is_exhausted() {
+            reset_in
+        } else {
+            reset_in.div_f64(self.remaining)
+        }
+    }
+}
+
+/// The last [RateLimit] seen by the Client. Shared between clones of the Client
+#[derive(Debug, Clone, Default)]
+pub(crate) struct RateLimitState(Arc<Mutex<Option<RateLimit>>>);
+
+impl RateLimitState {
+    /// Updates the state if the response contains the rate limit headers
+    pub(crate) fn update(&self, headers: &HeaderMap) {
+        if let Some(rate_limit) = RateLimit::from_headers(headers) {
+            if let Ok(mut state) = self.0.lock() {
+                *state = Some(rate_limit);
+            }
+        }
+    }
+    pub(crate) fn get(&self) -> Option<RateLimit> {
+        self.0.lock().ok().and_then(|state| *state)
+    }
+    /// The time until the reset if no requests are remaining
+    #[cfg(feature = "stream")]
+    pub(crate) fn exhausted(&self) -> Option<Duration> {
+        self.get()
+            .filter(RateLimit::is_exhausted)
+            .map(|rate_limit| rate_limit.reset_in())
+            .filter(|reset_in| !reset_in.is_zero())
+    }
+    /// The delay before the next request. [DEFAULT_DELAY] if no rate limit has been seen
+    #[cfg(feature = "stream")]
+    pub(crate) fn delay(&self) -> Duration {
+        self.get()
+            .map(|rate_limit| rate_limit.delay())
+            .unwrap_or(DEFAULT_DELAY)
+    }
+}
@@ -0,0 +1,66 @@
+use crate::error::reddit_error::RedditApiError;
+use crate::error::{Error, RateLimitSource};
+use crate::ratelimit::wait_from_secs;
+use serde::{Deserialize, Serialize};
+use std::fmt::Debug;
+
+/// The response Reddit sends when `api_type=json` is sent. `{"json": {"errors": [], "data": {}}}`
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct JsonResponse<T> {
+    pub json: ApiResponse<T>,
+}
+
+/// The inner object of a [JsonResponse]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct ApiResponse<T> {
+    /// Reddit responds with 200 OK even if the request failed. The reasons are in here
+    #[serde(default)]
+    pub errors: Vec<RedditApiError>,
+    #[serde(default = "Option::default")]
+    pub data: Option<T>,
+    /// Seconds until the rate limit resets. Sent with a `RATELIMIT` error
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ratelimit: Option<f64>,
+    /// The iden of the captcha Reddit wants solved. Sent with a `BAD_CAPTCHA` error
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub captcha: Option<String>,
+}
+
+impl<T> JsonResponse<T> {
+    /// Converts a non empty errors array into [Error::Api].
+    ///
+    /// A `RATELIMIT` error becomes [Error::RateLimited] using the `ratelimit` field as the retry after and a `BAD_CAPTCHA` error becomes [Error::CaptchaRequired]
+    pub fn into_result(self) -> Result<Option<T>, Error> {
+        if self.json.errors.is_empty() {
+            return Ok(self.json.data);
+        }
+        let mut errors = self.json.errors;
+        if let Some(index) = errors.iter().position(|error| error.code == "RATELIMIT") {
+            let error = errors.swap_remove(index);
+            // The ratelimit field is more precise than the minutes in the message
+            let retry_after = self
+                .json
+                .ratelimit
+                .and_then(wait_from_secs)
+                .or(error.retry_after);
+            return Err(Error::RateLimited {
+                retry_after,
+                source: RateLimitSource::Api(error),
+            });
+        }
+        if let Some(index) = errors.iter().position(|error| error.code == "BAD_CAPTCHA") {
+            return Err(Error::CaptchaRequired {
+                captcha: self.json.captcha,
+                error: errors.swap_remove(index),
+            });
+        }
+        Err(Error::Api(errors))
+    }
+}
+
+/// The `data` of a [JsonResponse] containing the things that were created or changed
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct Things<T> {
+    #[serde(default = "Vec::new")]
+    pub things: Vec<T>,
+}
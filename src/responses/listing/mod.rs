@@ -1,8 +1,15 @@
-use crate::responses::{GenericResponse, RedditResponse};
-use serde::Deserialize;
+use crate::comments::response::CommentResponse;
+use crate::responses::{deserialize_kind_data, GenericResponse, RedditResponse};
+use crate::submission::response::SubmissionResponse;
+use crate::utils::options::FeedOption;
+use serde::de::DeserializeSeed;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+use std::collections::hash_map::RandomState;
 use std::fmt::{Debug, Formatter};
+use std::hash::{BuildHasher, Hasher};
 
-#[derive(Deserialize)]
+#[derive(Deserialize, Serialize, Clone, PartialEq)]
 /// The Listing API for async RRAW
 pub struct Listing<T> {
     /// Modhash from Reddit
@@ -21,9 +28,259 @@ impl<T: Debug> Debug for Listing<T> {
     }
 }
 
+impl<T> Listing<T> {
+    /// The number of children
+    pub fn len(&self) -> usize {
+        self.children.len()
+    }
+    /// True if there are no children
+    pub fn is_empty(&self) -> bool {
+        self.children.is_empty()
+    }
+    /// Iterates over the children
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.children.iter()
+    }
+}
+
+impl<T> IntoIterator for Listing<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.children.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Listing<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.children.iter()
+    }
+}
+
+impl<T: Debug> GenericResponse<Listing<T>> {
+    /// The fullname to pass as `after` to get the next page. None on the last page
+    pub fn after(&self) -> Option<&str> {
+        self.data.after.as_deref()
+    }
+    /// The modhash for cookie based sessions. Reddit sends null or an empty string for OAuth
+    pub fn modhash(&self) -> Option<&str> {
+        self.data
+            .modhash
+            .as_deref()
+            .filter(|modhash| !modhash.is_empty())
+    }
+    /// The fullname to pass as `before` to get the previous page
+    pub fn before(&self) -> Option<&str> {
+        self.data.before.as_deref()
+    }
+    /// The options for the next page. A clone of `options` with `after` swapped for the new cursor.
+    ///
+    /// Returns None at the end of the listing
+    pub fn next_options(&self, options: &FeedOption) -> Option<FeedOption> {
+        let after = self.after()?;
+        let mut options = options.clone();
+        options.after = Some(after.to_string());
+        options.before = None;
+        Some(options)
+    }
+    /// The number of children
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+    /// True if there are no children
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+    /// Iterates over the children
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.data.iter()
+    }
+}
+
+impl<T: Debug> IntoIterator for GenericResponse<Listing<T>> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_iter()
+    }
+}
+
+impl<'a, T: Debug> IntoIterator for &'a GenericResponse<Listing<T>> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.iter()
+    }
+}
+
+impl<T: Debug> GenericListing<T> {
+    /// A random item of the page. No request is made. None if the page is empty.
+    ///
+    /// The same `seed` picks the same item from the same page. Without one the pick is different every time
+    /// ```
+    /// use rraw::submission::response::SubmissionsResponse;
+    /// # let listing: SubmissionsResponse = serde_json::from_str(r#"{"kind": "Listing", "data": {"children": []}}"#).unwrap();
+    /// // A reproducible daily pick
+    /// let pick = listing.random(Some(20231016));
+    /// # assert!(pick.is_none());
+    /// ```
+    pub fn random(&self, seed: Option<u64>) -> Option<&T> {
+        if self.is_empty() {
+            return None;
+        }
+        let seed = seed.unwrap_or_else(|| RandomState::new().build_hasher().finish());
+        let index = split_mix(seed) % self.len() as u64;
+        self.data
+            .children
+            .get(index as usize)
+            .map(|item| &item.data)
+    }
+}
+
+/// One step of SplitMix64. Spreads similar seeds over the whole range
+fn split_mix(seed: u64) -> u64 {
+    let mut z = seed.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
 /// GenericListing mixes the GenericResponse and Listing for simplicity
 pub type GenericListing<T> = GenericResponse<Listing<GenericResponse<T>>>;
 /// RedditListing uses a RedditResponse
 pub type RedditListing = GenericResponse<Listing<RedditResponse>>;
 /// Due to a Random Response this is a type that Returns an Array of RedditListings.
 pub type ListingArray = Vec<RedditListing>;
+
+/// A child of a listing that mixes comments and submissions. Such as the overview of a user or the saved items
+#[derive(Debug, Clone, PartialEq)]
+pub enum MixedItem {
+    /// `t1`
+    Comment(CommentResponse),
+    /// `t3`
+    Submission(SubmissionResponse),
+    /// Any other kind with its data as sent by Reddit
+    Other(String, Value),
+}
+
+impl MixedItem {
+    /// The kind Reddit sent. Such as `t1`
+    pub fn kind(&self) -> &str {
+        match self {
+            MixedItem::Comment(_) => "t1",
+            MixedItem::Submission(_) => "t3",
+            MixedItem::Other(kind, _) => kind,
+        }
+    }
+    /// None if it is not a comment
+    pub fn as_comment(&self) -> Option<&CommentResponse> {
+        match self {
+            MixedItem::Comment(comment) => Some(comment),
+            _ => None,
+        }
+    }
+    /// None if it is not a submission
+    pub fn as_submission(&self) -> Option<&SubmissionResponse> {
+        match self {
+            MixedItem::Submission(submission) => Some(submission),
+            _ => None,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for MixedItem {
+    fn deserialize<D>(deserializer: D) -> Result<MixedItem, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_kind_data(deserializer, |kind| MixedSeed { kind })
+    }
+}
+
+/// Reads the data of a [MixedItem] as the type of its kind
+struct MixedSeed {
+    kind: String,
+}
+
+impl<'de> DeserializeSeed<'de> for MixedSeed {
+    type Value = MixedItem;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(match self.kind.as_str() {
+            "t1" => MixedItem::Comment(Deserialize::deserialize(deserializer)?),
+            "t3" => MixedItem::Submission(Deserialize::deserialize(deserializer)?),
+            _ => MixedItem::Other(self.kind, Deserialize::deserialize(deserializer)?),
+        })
+    }
+}
+
+impl Serialize for MixedItem {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        #[derive(Serialize)]
+        struct Raw<'a, T: Serialize> {
+            kind: &'a str,
+            data: &'a T,
+        }
+        match self {
+            MixedItem::Comment(comment) => Raw {
+                kind: self.kind(),
+                data: comment,
+            }
+            .serialize(serializer),
+            MixedItem::Submission(submission) => Raw {
+                kind: self.kind(),
+                data: submission,
+            }
+            .serialize(serializer),
+            MixedItem::Other(kind, data) => Raw { kind, data }.serialize(serializer),
+        }
+    }
+}
+
+/// A listing of comments and submissions. Kinds other than `t1` and `t3` are kept as [MixedItem::Other]
+/// ```
+/// use rraw::responses::listing::MixedListing;
+/// let listing: MixedListing = serde_json::from_str(r#"{"kind": "Listing", "data": {"children": [
+///     {"kind": "t4", "data": {"id": "1vbma6c"}}
+/// ]}}"#).unwrap();
+/// assert_eq!(listing.comments().count(), 0);
+/// assert_eq!(listing.iter().next().unwrap().kind(), "t4");
+/// ```
+pub type MixedListing = GenericResponse<Listing<MixedItem>>;
+
+impl GenericResponse<Listing<MixedItem>> {
+    /// The comments of the page in order
+    pub fn comments(&self) -> impl Iterator<Item = &CommentResponse> {
+        self.iter().filter_map(MixedItem::as_comment)
+    }
+    /// The submissions of the page in order
+    pub fn submissions(&self) -> impl Iterator<Item = &SubmissionResponse> {
+        self.iter().filter_map(MixedItem::as_submission)
+    }
+    /// Takes the comments of the page in order
+    pub fn into_comments(self) -> impl Iterator<Item = CommentResponse> {
+        self.into_iter().filter_map(|item| match item {
+            MixedItem::Comment(comment) => Some(comment),
+            _ => None,
+        })
+    }
+    /// Takes the submissions of the page in order
+    pub fn into_submissions(self) -> impl Iterator<Item = SubmissionResponse> {
+        self.into_iter().filter_map(|item| match item {
+            MixedItem::Submission(submission) => Some(submission),
+            _ => None,
+        })
+    }
+}
@@ -1,12 +1,14 @@
 use crate::comments::response::CommentResponse;
 use crate::error::reddit_error::RedditError;
 use crate::error::reddit_error::RedditError::InvalidDataType;
+use crate::live::response::LiveUpdate;
 use crate::message::response::Message;
 use crate::responses::listing::Listing;
 use crate::Error;
 use serde::de::Error as DeError;
+use serde::de::{DeserializeSeed, IgnoredAny, MapAccess, Visitor};
 pub use serde::Deserialize;
-use serde::Deserializer;
+use serde::{Deserializer, Serialize, Serializer};
 use std::fmt;
 use std::fmt::{Debug, Display, Formatter};
 use std::str::FromStr;
@@ -15,11 +17,47 @@ use crate::submission::response::SubmissionResponse;
 use crate::subreddit::response::AboutSubreddit;
 use crate::user::response::AboutUser;
 
+pub mod api;
 pub mod listing;
+pub mod status;
+pub mod types;
+
+/// Reddit sends `false` if the thing was never edited otherwise the time it was edited
+pub(crate) mod edited {
+    use serde::de::IgnoredAny;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Edited {
+        Time(f64),
+        NotEdited(IgnoredAny),
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<f64>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(match Option::<Edited>::deserialize(deserializer)? {
+            Some(Edited::Time(time)) => Some(time),
+            _ => None,
+        })
+    }
+
+    pub fn serialize<S>(value: &Option<f64>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match value {
+            Some(time) => serializer.serialize_f64(*time),
+            None => serializer.serialize_bool(false),
+        }
+    }
+}
 
 /// A Generic Response from Reddit the type is pre determined by API
 /// Data from Reddit usually follows this format
-#[derive(Deserialize)]
+#[derive(Deserialize, Serialize, Clone, PartialEq)]
 pub struct GenericResponse<T: Debug> {
     /// The kind value from Reddit
     pub kind: RedditDataType,
@@ -33,6 +71,7 @@ impl<T: Debug> Debug for GenericResponse<T> {
     }
 }
 /// An Enum To Represent the Different Types of Data Reddit will respond with
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum RedditDataType {
     /// Type: `Listing`
     Listing,
@@ -48,6 +87,10 @@ pub enum RedditDataType {
     Subreddit,
     /// Type: `t6`
     Award,
+    /// Type: `LiveUpdate`
+    LiveUpdate,
+    /// Type: `LiveUpdateEvent`. A live thread
+    LiveUpdateEvent,
 }
 impl Display for RedditDataType {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
@@ -58,6 +101,8 @@ impl Display for RedditDataType {
             RedditDataType::Message => "t4",
             RedditDataType::Subreddit => "t5",
             RedditDataType::Award => "t6",
+            RedditDataType::LiveUpdate => "LiveUpdate",
+            RedditDataType::LiveUpdateEvent => "LiveUpdateEvent",
             RedditDataType::Listing => "Listing",
         };
         write!(f, "{}", data)
@@ -74,7 +119,9 @@ impl FromStr for RedditDataType {
             "t3" => Ok(RedditDataType::Link),
             "t4" => Ok(RedditDataType::Message),
             "t5" => Ok(RedditDataType::Subreddit),
-            "t6" => Ok(RedditDataType::Message),
+            "t6" => Ok(RedditDataType::Award),
+            "LiveUpdate" => Ok(RedditDataType::LiveUpdate),
+            "LiveUpdateEvent" => Ok(RedditDataType::LiveUpdateEvent),
             "Listing" => Ok(RedditDataType::Listing),
             data => Err(InvalidDataType(data.to_string())),
         }
@@ -89,6 +136,14 @@ impl<'de> Deserialize<'de> for RedditDataType {
         RedditDataType::from_str(s.as_str()).map_err(DeError::custom)
     }
 }
+impl Serialize for RedditDataType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
 /// A RedditResponse the type is dynamically decided based on the kind data
 /// This is primarily used in Listing that return different type of data
 pub type RedditResponse = GenericResponse<RedditTypeResponse>;
@@ -102,6 +157,7 @@ impl From<RedditTypeResponse> for RedditResponse {
             RedditTypeResponse::Message(_) => RedditDataType::Message,
             RedditTypeResponse::Subreddit(_) => RedditDataType::Subreddit,
             RedditTypeResponse::Award => RedditDataType::Award,
+            RedditTypeResponse::LiveUpdate(_) => RedditDataType::LiveUpdate,
             RedditTypeResponse::Listing(_) => RedditDataType::Listing,
         };
         RedditResponse {
@@ -116,35 +172,102 @@ impl<'de> Deserialize<'de> for RedditResponse {
     where
         D: Deserializer<'de>,
     {
-        let value: serde_json::Value = serde::Deserialize::deserialize(deserializer).unwrap();
-        if let Some(kind) = value["kind"].as_str() {
-            return match kind {
-                "t1" => Ok(RedditResponse::from(RedditTypeResponse::Comment(
-                    serde_json::from_value(value["data"].clone()).unwrap(),
-                ))),
-                "t2" => Ok(RedditResponse::from(RedditTypeResponse::Account(
-                    serde_json::from_value(value["data"].clone()).unwrap(),
-                ))),
-                "t3" => Ok(RedditResponse::from(RedditTypeResponse::Link(
-                    serde_json::from_value(value["data"].clone()).unwrap(),
-                ))),
-                "t4" => Ok(RedditResponse::from(RedditTypeResponse::Message(
-                    serde_json::from_value(value["data"].clone()).unwrap(),
-                ))),
-                "t5" => Ok(RedditResponse::from(RedditTypeResponse::Subreddit(
-                    serde_json::from_value(value["data"].clone()).unwrap(),
-                ))),
-                "Listing" => Ok(RedditResponse::from(RedditTypeResponse::Listing(
-                    serde_json::from_value(value["data"].clone()).unwrap(),
-                ))),
-                //"t6" => { Ok(GenericResponse::new(RedditType::Comment(serde_json::from_str(value["data"].as_str().unwrap()).unwrap()))) }
-                _ => Err(DeError::custom("Invalid Reddit Kind")),
-            };
+        deserialize_kind_data(deserializer, |kind| ThingSeed { kind }).map(RedditResponse::from)
+    }
+}
+
+/// Picks the type of `data` from the `kind` of a [RedditResponse]
+struct ThingSeed {
+    kind: String,
+}
+
+impl<'de> DeserializeSeed<'de> for ThingSeed {
+    type Value = RedditTypeResponse;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(match self.kind.as_str() {
+            "t1" => RedditTypeResponse::Comment(Deserialize::deserialize(deserializer)?),
+            "t2" => RedditTypeResponse::Account(Deserialize::deserialize(deserializer)?),
+            "t3" => RedditTypeResponse::Link(Deserialize::deserialize(deserializer)?),
+            "t4" => RedditTypeResponse::Message(Deserialize::deserialize(deserializer)?),
+            "t5" => RedditTypeResponse::Subreddit(Deserialize::deserialize(deserializer)?),
+            "LiveUpdate" => RedditTypeResponse::LiveUpdate(Deserialize::deserialize(deserializer)?),
+            "Listing" => RedditTypeResponse::Listing(Deserialize::deserialize(deserializer)?),
+            _ => return Err(DeError::custom("Invalid Reddit Kind")),
+        })
+    }
+}
+
+/// Reads a `{"kind": .., "data": ..}` map. `data` is read by the seed for the kind through the same deserializer,
+/// so a failure inside it keeps its path and position.
+///
+/// Reddit sends the kind first. If the data comes first it is buffered and the position of a failure is lost
+pub(crate) fn deserialize_kind_data<'de, D, S, F>(
+    deserializer: D,
+    seed: F,
+) -> Result<S::Value, D::Error>
+where
+    D: Deserializer<'de>,
+    S: DeserializeSeed<'de>,
+    F: FnOnce(String) -> S,
+{
+    struct KindDataVisitor<F>(F);
+
+    impl<'de, S, F> Visitor<'de> for KindDataVisitor<F>
+    where
+        S: DeserializeSeed<'de>,
+        F: FnOnce(String) -> S,
+    {
+        type Value = S::Value;
+
+        fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+            formatter.write_str("a map with a kind and data")
+        }
+
+        fn visit_map<M>(self, mut map: M) -> Result<Self::Value, M::Error>
+        where
+            M: MapAccess<'de>,
+        {
+            let mut seed = Some(self.0);
+            let mut kind: Option<String> = None;
+            let mut data = None;
+            let mut buffered: Option<serde_json::Value> = None;
+            while let Some(key) = map.next_key::<String>()? {
+                match key.as_str() {
+                    "kind" => kind = Some(map.next_value()?),
+                    "data" => match (kind.clone(), seed.take()) {
+                        (Some(kind), Some(seed)) => data = Some(map.next_value_seed(seed(kind))?),
+                        (_, unused) => {
+                            seed = unused;
+                            buffered = Some(map.next_value()?)
+                        }
+                    },
+                    _ => {
+                        map.next_value::<IgnoredAny>()?;
+                    }
+                }
+            }
+            if let Some(data) = data {
+                return Ok(data);
+            }
+            let kind =
+                kind.ok_or_else(|| M::Error::custom("Some how we are missing a kind tag"))?;
+            match (buffered, seed) {
+                (Some(value), Some(seed)) => {
+                    seed(kind).deserialize(value).map_err(M::Error::custom)
+                }
+                _ => Err(M::Error::missing_field("data")),
+            }
         }
-        Err(DeError::custom("Some how we are missing a kind tag"))
     }
+
+    deserializer.deserialize_map(KindDataVisitor(seed))
 }
 /// FullNames are the {t1,t2,t3,t4,t5,t6}_{id} you see within Reddit API all the time
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct FullName {
     pub reddit_type: RedditDataType,
     pub id: String,
@@ -160,6 +283,15 @@ impl<'de> Deserialize<'de> for FullName {
     }
 }
 
+impl Serialize for FullName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
 impl FromStr for FullName {
     type Err = crate::Error;
 
@@ -169,10 +301,10 @@ impl FromStr for FullName {
             // Yes, it is always a good time to make a monty python joke.
             return Err(Error::from("Then shalt thou count to two, no more, no less. Two shall be the number thou shalt count, and the number of the counting shall be two."));
         }
-        return Ok(FullName {
+        Ok(FullName {
             reddit_type: RedditDataType::from_str(split.first().unwrap())?,
             id: split.get(1).unwrap().to_string(),
-        });
+        })
     }
 }
 
@@ -187,6 +319,7 @@ impl Debug for FullName {
     }
 }
 /// Reddit Type Response Enum
+#[derive(Clone, PartialEq)]
 pub enum RedditTypeResponse {
     /// The Listing Type
     Listing(Listing<RedditResponse>),
@@ -204,6 +337,40 @@ pub enum RedditTypeResponse {
     Subreddit(Box<AboutSubreddit>),
     /// TODO
     Award,
+    /// Live Thread Update
+    LiveUpdate(Box<LiveUpdate>),
+}
+
+impl RedditTypeResponse {
+    /// The fullname of comments, submissions, messages and subreddits. Such as `t3_15bfi0`
+    pub fn fullname(&self) -> Option<String> {
+        match self {
+            RedditTypeResponse::Comment(comment) => Some(comment.fullname()),
+            RedditTypeResponse::Link(submission) => Some(submission.name.clone()),
+            RedditTypeResponse::Message(message) => Some(message.name.clone()),
+            RedditTypeResponse::Subreddit(subreddit) => Some(subreddit.name.clone()),
+            _ => None,
+        }
+    }
+}
+
+/// Serializes the inner data. The kind is written by the wrapping [RedditResponse]
+impl Serialize for RedditTypeResponse {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            RedditTypeResponse::Listing(data) => data.serialize(serializer),
+            RedditTypeResponse::Comment(data) => data.serialize(serializer),
+            RedditTypeResponse::Account(data) => data.serialize(serializer),
+            RedditTypeResponse::Link(data) => data.serialize(serializer),
+            RedditTypeResponse::Message(data) => data.serialize(serializer),
+            RedditTypeResponse::Subreddit(data) => data.serialize(serializer),
+            RedditTypeResponse::Award => serializer.serialize_unit(),
+            RedditTypeResponse::LiveUpdate(data) => data.serialize(serializer),
+        }
+    }
 }
 
 impl Debug for RedditTypeResponse {
@@ -230,6 +397,9 @@ impl Debug for RedditTypeResponse {
             RedditTypeResponse::Award => {
                 write!(f, "AWARD!")
             }
+            RedditTypeResponse::LiveUpdate(data) => {
+                write!(f, "{:?}", data)
+            }
         }
     }
 }
@@ -0,0 +1,68 @@
+use serde::{Deserialize, Serialize};
+
+/// Reddit replaces the body of deleted content with this
+pub const DELETED: &str = "[deleted]";
+/// The default author of content that arrives without one
+pub(crate) fn deleted_author() -> String {
+    DELETED.to_string()
+}
+
+/// Reddit replaces the body of removed content with this
+pub const REMOVED: &str = "[removed]";
+
+/// Whether a submission or comment is still visible.
+///
+/// Content can be both removed and deleted. The checks are in order
+/// 1. Deleted by the author. `removed_by_category` is `deleted` or `author`, or both the author and body are `[deleted]`.
+///    An author of `[deleted]` with a real body is a deleted account and the content is still live
+/// 2. Spam. `spam` is true or `banned_by` is `true`
+/// 3. Removed by Reddit. `removed_by_category` is one of Reddit's own categories
+/// 4. Removed by a moderator. `removed_by_category` is `moderator` or `automod_filtered`, `banned_by` is a name, or the body is `[removed]`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ContentStatus {
+    Live,
+    /// Removed by a moderator or AutoModerator
+    RemovedByModerator,
+    /// Removed by Reddit's admins. Such as anti evil operations or a copyright takedown
+    RemovedByReddit,
+    /// Deleted by the author
+    DeletedByAuthor,
+    /// Caught by the spam filter or removed as spam. Only moderators can see this
+    Spam,
+}
+
+/// The `banned_by` value. Only sent to moderators
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum BannedBy {
+    /// The moderator that removed it
+    Moderator(String),
+    /// `true` when the spam filter removed it
+    Filter(bool),
+}
+
+/// Decides the [ContentStatus] of a submission or comment
+pub(crate) fn content_status(
+    removed_by_category: Option<&str>,
+    banned_by: Option<&BannedBy>,
+    spam: bool,
+    author: Option<&str>,
+    body: &str,
+) -> ContentStatus {
+    if matches!(removed_by_category, Some("deleted") | Some("author"))
+        || (author == Some(DELETED) && body == DELETED)
+    {
+        return ContentStatus::DeletedByAuthor;
+    }
+    if spam || banned_by == Some(&BannedBy::Filter(true)) {
+        return ContentStatus::Spam;
+    }
+    match removed_by_category {
+        Some("moderator") | Some("automod_filtered") => ContentStatus::RemovedByModerator,
+        Some(_) => ContentStatus::RemovedByReddit,
+        None if matches!(banned_by, Some(BannedBy::Moderator(_))) || body == REMOVED => {
+            ContentStatus::RemovedByModerator
+        }
+        None => ContentStatus::Live,
+    }
+}
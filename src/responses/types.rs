@@ -0,0 +1,101 @@
+//! Typed versions of string fields. Values Reddit adds later are kept as `Unknown`
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// Who distinguished a submission or comment
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(from = "String", into = "String")]
+pub enum Distinguished {
+    Moderator,
+    Admin,
+    /// Such as a Reddit employee posting as themselves
+    Special,
+    Unknown(String),
+}
+
+impl From<String> for Distinguished {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "moderator" => Distinguished::Moderator,
+            "admin" => Distinguished::Admin,
+            "special" => Distinguished::Special,
+            _ => Distinguished::Unknown(value),
+        }
+    }
+}
+
+impl From<Distinguished> for String {
+    fn from(value: Distinguished) -> Self {
+        value.to_string()
+    }
+}
+
+impl Display for Distinguished {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let string = match self {
+            Distinguished::Moderator => "moderator",
+            Distinguished::Admin => "admin",
+            Distinguished::Special => "special",
+            Distinguished::Unknown(value) => value.as_str(),
+        };
+        write!(f, "{}", string)
+    }
+}
+
+/// Who can view and post in a subreddit
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(from = "String", into = "String")]
+pub enum SubredditType {
+    Public,
+    /// Only approved users can view it
+    Private,
+    /// Anyone can view it. Only approved users can post
+    Restricted,
+    /// Nothing new can be posted
+    Archived,
+    EmployeesOnly,
+    GoldRestricted,
+    GoldOnly,
+    /// The profile subreddit of a user. `u_{name}`
+    User,
+    Unknown(String),
+}
+
+impl From<String> for SubredditType {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "public" => SubredditType::Public,
+            "private" => SubredditType::Private,
+            "restricted" => SubredditType::Restricted,
+            "archived" => SubredditType::Archived,
+            "employees_only" => SubredditType::EmployeesOnly,
+            "gold_restricted" => SubredditType::GoldRestricted,
+            "gold_only" => SubredditType::GoldOnly,
+            "user" => SubredditType::User,
+            _ => SubredditType::Unknown(value),
+        }
+    }
+}
+
+impl From<SubredditType> for String {
+    fn from(value: SubredditType) -> Self {
+        value.to_string()
+    }
+}
+
+impl Display for SubredditType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let string = match self {
+            SubredditType::Public => "public",
+            SubredditType::Private => "private",
+            SubredditType::Restricted => "restricted",
+            SubredditType::Archived => "archived",
+            SubredditType::EmployeesOnly => "employees_only",
+            SubredditType::GoldRestricted => "gold_restricted",
+            SubredditType::GoldOnly => "gold_only",
+            SubredditType::User => "user",
+            SubredditType::Unknown(value) => value.as_str(),
+        };
+        write!(f, "{}", string)
+    }
+}
@@ -0,0 +1,56 @@
+//! The `.rss` feeds of subreddits and users. Reddit serves them as Atom without OAuth and with a separate rate limit
+use roxmltree::{Document, Node};
+
+use crate::error::internal_error::InternalError;
+use crate::error::Error;
+
+/// An entry of a feed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RssItem {
+    /// The fullname of the submission or comment. Such as `t3_15bfi0`
+    pub id: String,
+    pub title: String,
+    /// The permalink
+    pub link: String,
+    /// The username without the `/u/`. None if the account was deleted
+    pub author: Option<String>,
+    /// RFC 3339. Such as `2023-07-27T20:31:50+00:00`
+    pub published: Option<String>,
+}
+
+/// Parses the entries of an Atom feed. `url` is only used in the error
+pub fn parse_feed(url: &str, body: &str) -> Result<Vec<RssItem>, Error> {
+    let document = Document::parse(body).map_err(|source| {
+        Error::InternalError(InternalError::FeedError {
+            url: url.to_string(),
+            source,
+        })
+    })?;
+    Ok(document
+        .root_element()
+        .children()
+        .filter(|node| node.has_tag_name("entry"))
+        .map(|entry| RssItem {
+            id: child_text(entry, "id").unwrap_or_default(),
+            title: child_text(entry, "title").unwrap_or_default(),
+            link: child(entry, "link")
+                .and_then(|link| link.attribute("href"))
+                .unwrap_or_default()
+                .to_string(),
+            author: child(entry, "author")
+                .and_then(|author| child_text(author, "name"))
+                .map(|name| name.trim_start_matches("/u/").to_string()),
+            published: child_text(entry, "published"),
+        })
+        .collect())
+}
+
+fn child<'a, 'input>(node: Node<'a, 'input>, name: &str) -> Option<Node<'a, 'input>> {
+    node.children().find(|child| child.has_tag_name(name))
+}
+
+fn child_text(node: Node, name: &str) -> Option<String> {
+    child(node, name)
+        .and_then(|child| child.text())
+        .map(|text| text.trim().to_string())
+}
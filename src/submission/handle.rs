@@ -0,0 +1,67 @@
+use crate::auth::Authorized;
+use crate::error::http_error::HTTPError;
+use crate::error::Error;
+use crate::submission::response::SubmissionResponse;
+use crate::thing::private::Sealed;
+use crate::thing::{Deletable, Replyable, Reportable, Savable, Votable};
+use crate::utils::fullname::Fullname;
+use crate::Client;
+
+/// A submission known only by its fullname. Created with [Me::submission](crate::user::me::Me::submission)
+///
+/// Nothing is requested until an action is called. Use [SubmissionHandle::fetch] to load the submission
+pub struct SubmissionHandle<'a, A: Authorized> {
+    pub(crate) client: &'a Client<A>,
+    pub(crate) fullname: Fullname,
+}
+
+impl<'a, A: Authorized> Sealed for SubmissionHandle<'a, A> {
+    type Auth = A;
+    fn client(&self) -> &Client<A> {
+        self.client
+    }
+    fn thing_fullname(&self) -> String {
+        self.fullname.to_string()
+    }
+}
+
+impl<'a, A: Authorized> Votable for SubmissionHandle<'a, A> {}
+impl<'a, A: Authorized> Savable for SubmissionHandle<'a, A> {}
+impl<'a, A: Authorized> Reportable for SubmissionHandle<'a, A> {}
+impl<'a, A: Authorized> Replyable for SubmissionHandle<'a, A> {}
+impl<'a, A: Authorized> Deletable for SubmissionHandle<'a, A> {}
+
+impl<'a, A: Authorized> SubmissionHandle<'a, A> {
+    pub fn fullname(&self) -> &Fullname {
+        &self.fullname
+    }
+    /// Loads the submission with `/api/info`. A submission that does not exist is [HTTPError::NotFound]
+    pub async fn fetch(&self) -> Result<SubmissionResponse, Error> {
+        self.client
+            .info_single(&self.fullname.to_string())
+            .await?
+            .ok_or_else(|| HTTPError::NotFound.into())
+    }
+    /// Removes the submission as a moderator. `spam` also trains the spam filter
+    pub async fn remove(&self, spam: bool) -> Result<(), Error> {
+        self.client
+            .remove_thing(&self.fullname.to_string(), spam)
+            .await
+    }
+    /// Approves the submission as a moderator. Undoes a removal
+    pub async fn approve(&self) -> Result<(), Error> {
+        self.action("/api/approve").await
+    }
+    /// Prevents new comments. Requires moderator access
+    pub async fn lock(&self) -> Result<(), Error> {
+        self.action("/api/lock").await
+    }
+    pub async fn unlock(&self) -> Result<(), Error> {
+        self.action("/api/unlock").await
+    }
+    async fn action(&self, endpoint: &str) -> Result<(), Error> {
+        self.client
+            .thing_action(endpoint, &self.fullname.to_string())
+            .await
+    }
+}
@@ -1,12 +1,27 @@
+pub mod handle;
 pub mod response;
+pub mod submit;
 
-use crate::auth::Authenticator;
+use crate::access::Access;
+use crate::auth::{Authenticator, Authorized};
+use crate::comments::tree::CommentTree;
 use crate::comments::CommentRetriever;
+use crate::submission::response::SubmissionResponse;
 use crate::submission::response::SubmissionsResponse;
-use crate::utils::options::{CommentOption, FeedOption};
+use crate::subreddit::Subreddit;
+use crate::thing::private::Sealed;
+use crate::thing::{Deletable, Replyable, Reportable, Savable, Votable};
+use crate::user::User;
+use crate::utils::form_body;
+use crate::utils::fullname::{Fullname, FullnameKind};
+use crate::utils::options::{CommentOption, FeedOption, SubmissionSort};
+use crate::utils::reddit_url::RedditUrl;
 use crate::Client;
 use async_trait::async_trait;
+use reqwest::Url;
+use serde::de::IgnoredAny;
 
+use crate::error::http_error::HTTPError;
 use crate::error::Error;
 use crate::responses::listing::{GenericListing, ListingArray};
 
@@ -42,7 +57,161 @@ impl<'a, A: Authenticator, T: SubmissionType<'a>> CommentRetriever for Submissio
         if let Some(options) = sort {
             options.extend(&mut path)
         }
-        return self.me.get_json::<ListingArray>(&path, false, false).await;
+        return self.me.get_json::<ListingArray>(&path, Access::Any).await;
+    }
+}
+
+impl<'a, A: Authenticator, T: SubmissionType<'a>> Submission<'a, A, T> {
+    /// Loads the comments as a [CommentTree]
+    pub async fn comment_tree(&self, sort: Option<CommentOption>) -> Result<CommentTree, Error> {
+        let mut path = self.submission.get_permalink().to_string();
+        if let Some(options) = sort {
+            options.extend(&mut path)
+        }
+        // The first listing is the submission
+        let (_, tree) = self
+            .me
+            .get_json::<(IgnoredAny, CommentTree)>(&path, Access::Any)
+            .await?;
+        Ok(tree)
+    }
+}
+
+impl<'a, A: Authenticator> Submission<'a, A, SubmissionResponse> {
+    /// Loads the author. A deleted author is [HTTPError::NotFound](crate::error::http_error::HTTPError::NotFound) without a request
+    pub async fn author(&self) -> Result<User<'a, A>, Error> {
+        if self.submission.is_deleted() {
+            return Err(HTTPError::NotFound.into());
+        }
+        self.me.user(&self.submission.author).await
+    }
+    /// The author without a request. Only the name and id of the [User] are set until [User::about] is loaded.
+    /// None if the author is deleted
+    pub fn author_user(&self) -> Option<User<'a, A>> {
+        if self.submission.is_deleted() {
+            return None;
+        }
+        let fullname = self.submission.author_fullname.as_deref();
+        Some(User::unloaded(self.me, &self.submission.author, fullname))
+    }
+    /// Loads the Subreddit the submission was posted to
+    pub async fn subreddit(&self) -> Result<Subreddit<'a, A>, Error> {
+        self.me.subreddit(&self.submission.subreddit).await
+    }
+}
+
+impl SubmissionsResponse {
+    /// The submissions bound to the Client. So they can be acted on directly
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() ->anyhow::Result<()>{
+    ///    use std::env;
+    ///    use rraw::auth::PasswordAuthenticator;
+    ///    use rraw::submission::SubmissionRetriever;
+    ///    use rraw::thing::Votable;
+    ///    use rraw::Client;
+    ///    let client = Client::login(PasswordAuthenticator::new(env::var("CLIENT_ID")?,env::var("CLIENT_SECRET")?,env::var("USERNAME")?,env::var("PASSWORD")?), "RRAW Test (by u/KingTuxWH)").await?;
+    ///    let hot = client.subreddit("rraw").await?.hot(None).await?;
+    ///    for submission in hot.bound(&client) {
+    ///        submission.upvote().await?;
+    ///    }
+    ///    Ok(())
+    /// }
+    /// ```
+    pub fn bound<'a, A: Authenticator>(
+        &'a self,
+        client: &'a Client<A>,
+    ) -> impl Iterator<Item = Submission<'a, A, SubmissionResponse>> {
+        self.iter()
+            .map(move |child| child.data.to_submission(client))
+    }
+}
+
+impl<'a, A: Authorized> Sealed for Submission<'a, A, SubmissionResponse> {
+    type Auth = A;
+    fn client(&self) -> &Client<A> {
+        self.me
+    }
+    fn thing_fullname(&self) -> String {
+        Fullname::new(FullnameKind::Link, &self.submission.name).to_string()
+    }
+}
+
+impl<'a, A: Authorized> Votable for Submission<'a, A, SubmissionResponse> {}
+impl<'a, A: Authorized> Savable for Submission<'a, A, SubmissionResponse> {}
+impl<'a, A: Authorized> Reportable for Submission<'a, A, SubmissionResponse> {}
+impl<'a, A: Authorized> Replyable for Submission<'a, A, SubmissionResponse> {}
+impl<'a, A: Authorized> Deletable for Submission<'a, A, SubmissionResponse> {}
+
+impl<'a, A: Authorized> Submission<'a, A, SubmissionResponse> {
+    /// Edits the selftext of a self post. Returns the submission as Reddit now has it
+    pub async fn edit(&self, text: &str) -> Result<SubmissionResponse, Error> {
+        self.me.edit_user_text(&self.submission.name, text).await
+    }
+    /// Hides the submission from your listings
+    pub async fn hide(&self) -> Result<(), Error> {
+        self.me
+            .hide_things("/api/hide", &[&self.submission.name])
+            .await
+    }
+    /// Unhides the submission
+    pub async fn unhide(&self) -> Result<(), Error> {
+        self.me
+            .hide_things("/api/unhide", &[&self.submission.name])
+            .await
+    }
+    /// Subscribes to notifications for new comments on the submission. Used for submissions you did not post
+    pub async fn follow(&self) -> Result<(), Error> {
+        self.follow_post(true).await
+    }
+    /// Stops the notifications enabled with [Submission::follow]
+    pub async fn unfollow(&self) -> Result<(), Error> {
+        self.follow_post(false).await
+    }
+    async fn follow_post(&self, follow: bool) -> Result<(), Error> {
+        // Reddit documents the fullname. The listing name is normalized in case it is only the id
+        let fullname = Fullname::new(FullnameKind::Link, &self.submission.name).to_string();
+        let follow = follow.to_string();
+        let body = form_body(&[("fullname", fullname.as_str()), ("follow", follow.as_str())]);
+        self.me
+            .post_empty("/api/follow_post", Access::OAuthOnly, body)
+            .await
+    }
+    /// Enables or disables inbox notifications for replies to the submission
+    pub async fn set_send_replies(&self, enabled: bool) -> Result<(), Error> {
+        self.me
+            .set_send_replies(&self.submission.name, enabled)
+            .await
+    }
+    /// Sets the flair of the submission. `text` overrides the template text if the template allows it.
+    ///
+    /// Requires the moderator `flair` permission unless the subreddit lets users flair their own posts.
+    /// Without it Reddit responds with [Error::Forbidden](crate::error::Error::Forbidden).
+    /// An unknown template is returned as [Error::Api]
+    pub async fn set_flair(
+        &self,
+        template_id: Option<&str>,
+        text: Option<&str>,
+    ) -> Result<(), Error> {
+        let path = format!("/r/{}/api/selectflair", self.submission.subreddit);
+        let mut form = vec![
+            ("api_type", "json"),
+            ("link", self.submission.name.as_str()),
+        ];
+        if let Some(template_id) = template_id {
+            form.push(("flair_template_id", template_id));
+        }
+        if let Some(text) = text {
+            form.push(("text", text));
+        }
+        self.me
+            .post_json::<IgnoredAny>(&path, Access::OAuthOnly, form_body(&form))
+            .await?;
+        Ok(())
+    }
+    /// Removes the flair of the submission
+    pub async fn clear_flair(&self) -> Result<(), Error> {
+        self.set_flair(None, None).await
     }
 }
 
@@ -50,13 +219,35 @@ pub type Submissions<'a, A, T> = GenericListing<Submission<'a, A, T>>;
 
 #[async_trait(?Send)]
 pub trait SubmissionRetriever {
-    async fn get_submissions<T: Into<String> + std::marker::Send>(
+    async fn get_submissions(
         &self,
-        sort: T,
+        sort: SubmissionSort,
         feed_options: Option<FeedOption>,
     ) -> Result<SubmissionsResponse, Error>;
 
     async fn hot(&self, feed_options: Option<FeedOption>) -> Result<SubmissionsResponse, Error> {
-        return self.get_submissions("hot", feed_options).await;
+        return self
+            .get_submissions(SubmissionSort::Hot, feed_options)
+            .await;
     }
 }
+
+/// The id of the submission a link points to. Without the `t3_` prefix.
+///
+/// Any link [RedditUrl] parses as a submission or a comment. Such as `redd.it/{id}` shortlinks, `/r/{subreddit}/comments/{id}/{title}`,
+/// `/comments/{id}` and `/gallery/{id}`. Media links such as `v.redd.it` do not contain the id and return None
+pub fn submission_id(url: &Url) -> Option<String> {
+    match RedditUrl::parse(url.as_str()).ok()? {
+        RedditUrl::Submission { id, .. } => Some(id),
+        RedditUrl::Comment { submission_id, .. } => Some(submission_id),
+        _ => None,
+    }
+}
+
+/// Hosts for videos and images. They redirect to the submission that posted the media
+pub(crate) fn is_media_host(url: &Url) -> bool {
+    matches!(
+        url.host_str(),
+        Some("v.redd.it") | Some("i.redd.it") | Some("preview.redd.it")
+    )
+}
@@ -1,10 +1,15 @@
 use crate::responses::listing::GenericListing;
-use serde::Deserialize;
+use crate::responses::status::{content_status, BannedBy, ContentStatus, DELETED};
+use crate::responses::types::Distinguished;
+use serde::{Deserialize, Serialize};
 use std::fmt::{Debug, Formatter};
 
+use crate::collection::response::Collection;
+use crate::comments::response::REDDIT_URL;
 use crate::submission::SubmissionType;
+use crate::subreddit::response::SubredditCompact;
 
-#[derive(Deserialize, Clone)]
+#[derive(Deserialize, Serialize, Clone, PartialEq)]
 pub struct SubmissionResponse {
     pub domain: String,
     pub subreddit: String,
@@ -12,23 +17,81 @@ pub struct SubmissionResponse {
     pub selftext: String,
     pub likes: Option<bool>,
     pub id: String,
+    /// `[deleted]` if the account or the submission was deleted. See [SubmissionResponse::is_deleted]
+    #[serde(default = "crate::responses::status::deleted_author")]
     pub author: String,
-    pub score: f64,
-    pub num_comments: i64,
+    /// The fullname of the author. Missing if the author is `[deleted]`
+    #[serde(default)]
+    pub author_fullname: Option<String>,
+    /// When `score_hidden` is true Reddit does not send the real score
+    #[serde(default)]
+    pub score: i64,
+    #[serde(default)]
+    pub score_hidden: bool,
+    #[serde(default)]
+    pub num_comments: u64,
     pub thumbnail: String,
     pub subreddit_id: String,
-    pub downs: f64,
-    pub ups: f64,
+    /// Reddit no longer exposes downvotes. This is usually 0
+    #[serde(default)]
+    pub downs: i64,
+    #[serde(default)]
+    pub ups: i64,
+    #[serde(default)]
+    pub upvote_ratio: f64,
     pub stickied: bool,
     pub locked: bool,
     pub over_18: bool,
     pub name: String,
     pub created: f64,
     pub url: Option<String>,
+    /// Relative to [REDDIT_URL]. Use [SubmissionResponse::permalink_url] for the full URL
     pub permalink: String,
     pub title: String,
     pub created_utc: f64,
-    pub distinguished: Option<String>,
+    pub distinguished: Option<Distinguished>,
+    /// Why the content was removed. Such as `moderator`, `deleted` or `anti_evil_ops`
+    pub removed_by_category: Option<String>,
+    #[serde(default)]
+    pub banned_by: Option<BannedBy>,
+    /// Only sent to moderators
+    #[serde(default)]
+    pub spam: bool,
+    /// When the submission was last edited
+    #[serde(default, with = "crate::responses::edited")]
+    pub edited: Option<f64>,
+    /// The collections the submission is part of
+    #[serde(default)]
+    pub collections: Vec<Collection>,
+    /// The options and votes of a poll. None if the submission is not a poll
+    #[serde(default)]
+    pub poll_data: Option<PollData>,
+    /// The subreddit of the submission. Only sent when the listing was loaded with [sr_detail](crate::utils::options::FeedOption::sr_detail)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sr_detail: Option<SubredditCompact>,
+}
+
+/// The state of a poll submission
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct PollData {
+    #[serde(default)]
+    pub total_vote_count: u64,
+    /// Milliseconds since the Unix Epoch
+    pub voting_end_timestamp: u64,
+    pub options: Vec<PollOption>,
+    /// The id of the option the authenticated user voted for
+    #[serde(default)]
+    pub user_selection: Option<String>,
+}
+
+/// An option of a [PollData]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct PollOption {
+    pub id: String,
+    pub text: String,
+    /// Reddit only sends the votes once the poll has closed or the user has voted
+    #[serde(default)]
+    pub vote_count: Option<u64>,
 }
 
 impl Debug for SubmissionResponse {
@@ -41,6 +104,49 @@ impl Debug for SubmissionResponse {
     }
 }
 
+/// Seconds between the Unix Epoch and Reddit's hot ranking epoch. 2005-12-08T07:46:43Z
+const HOT_EPOCH: f64 = 1134028003.0;
+
+impl SubmissionResponse {
+    /// Whether the submission was removed or deleted. See [ContentStatus] for the precedence
+    pub fn status(&self) -> ContentStatus {
+        content_status(
+            self.removed_by_category.as_deref(),
+            self.banned_by.as_ref(),
+            self.spam,
+            Some(&self.author),
+            &self.selftext,
+        )
+    }
+    /// Whether the author is `[deleted]`. The account or the submission was deleted. [SubmissionResponse::status] tells them apart
+    pub fn is_deleted(&self) -> bool {
+        self.author == DELETED
+    }
+    /// The HTML Reddit rendered for a self post. Unescaped if the Client was not built with
+    /// [raw_json](crate::builder::ClientBuilder::raw_json)
+    pub fn rendered_html(&self) -> Option<String> {
+        crate::utils::rendered_html(self.selftext_html.as_ref())
+    }
+    /// The URL of the comments page. Such as `https://www.reddit.com/r/rust/comments/15bfi0/rraw_release/`
+    pub fn permalink_url(&self) -> String {
+        format!("{REDDIT_URL}{}", self.permalink)
+    }
+    /// Whether the submission is a poll. See [SubmissionResponse::poll_data]
+    pub fn is_poll(&self) -> bool {
+        self.poll_data.is_some()
+    }
+    /// Reddit's classic hot ranking formula. Higher is hotter.
+    ///
+    /// Uses `score`, so posts with `score_hidden` will be ranked on the value Reddit sent
+    pub fn hot_score(&self) -> f64 {
+        let order = (self.score.unsigned_abs().max(1) as f64).log10();
+        let sign = self.score.signum() as f64;
+        let seconds = self.created_utc - HOT_EPOCH;
+        let score = sign * order + seconds / 45000.0;
+        (score * 10_000_000.0).round() / 10_000_000.0
+    }
+}
+
 impl<'a> SubmissionType<'a> for SubmissionResponse {
     fn get_permalink(&self) -> &String {
         &self.permalink
@@ -0,0 +1,259 @@
+use crate::error::Error;
+use crate::utils::fullname::{Fullname, FullnameKind};
+use serde::{Deserialize, Serialize};
+use std::ops::RangeInclusive;
+
+/// The max length of a submission title
+pub const MAX_TITLE_LENGTH: usize = 300;
+/// The number of options a poll can have
+pub const POLL_OPTIONS: RangeInclusive<usize> = 2..=6;
+/// The number of days a poll can run for
+pub const POLL_DURATION_DAYS: RangeInclusive<u8> = 1..=7;
+
+/// The type of submission
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SubmitKind {
+    /// A text post
+    SelfPost { text: String },
+    /// A link post
+    Link { url: String },
+    /// Crossposts the submission with the fullname `parent`
+    Crosspost { parent: String },
+}
+
+/// A validated submission. Created with [SubmitBuilder]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submit {
+    pub subreddit: String,
+    pub title: String,
+    pub kind: SubmitKind,
+    pub flair_id: Option<String>,
+    pub nsfw: bool,
+    pub spoiler: bool,
+    pub send_replies: bool,
+}
+
+impl Submit {
+    /// The form sent to `/api/submit`
+    pub fn form(&self) -> Vec<(&'static str, String)> {
+        let mut form = vec![
+            ("api_type", "json".to_string()),
+            ("sr", self.subreddit.clone()),
+            ("title", self.title.clone()),
+        ];
+        match &self.kind {
+            SubmitKind::SelfPost { text } => {
+                form.push(("kind", "self".to_string()));
+                form.push(("text", text.clone()));
+            }
+            SubmitKind::Link { url } => {
+                form.push(("kind", "link".to_string()));
+                form.push(("url", url.clone()));
+            }
+            SubmitKind::Crosspost { parent } => {
+                form.push(("kind", "crosspost".to_string()));
+                form.push(("crosspost_fullname", parent.clone()));
+            }
+        }
+        if let Some(flair_id) = &self.flair_id {
+            form.push(("flair_id", flair_id.clone()));
+        }
+        form.push(("nsfw", self.nsfw.to_string()));
+        form.push(("spoiler", self.spoiler.to_string()));
+        form.push(("sendreplies", self.send_replies.to_string()));
+        form
+    }
+}
+
+/// Builds a [Submit]
+/// ```
+/// use rraw::submission::submit::{SubmitBuilder, SubmitKind};
+/// let submit = SubmitBuilder::new("rraw", "RRAW 1.3 Released")
+///     .kind(SubmitKind::SelfPost { text: "Changelog".to_string() })
+///     .spoiler(true)
+///     .build()
+///     .unwrap();
+/// assert!(!submit.nsfw);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct SubmitBuilder {
+    subreddit: String,
+    title: String,
+    kind: Option<SubmitKind>,
+    flair_id: Option<String>,
+    nsfw: bool,
+    spoiler: bool,
+    send_replies: Option<bool>,
+}
+
+impl SubmitBuilder {
+    /// The subreddit name without the `r/`
+    pub fn new(subreddit: impl Into<String>, title: impl Into<String>) -> Self {
+        SubmitBuilder {
+            subreddit: subreddit.into(),
+            title: title.into(),
+            ..SubmitBuilder::default()
+        }
+    }
+    pub fn kind(mut self, kind: SubmitKind) -> Self {
+        self.kind = Some(kind);
+        self
+    }
+    pub fn flair_id(mut self, flair_id: impl Into<String>) -> Self {
+        self.flair_id = Some(flair_id.into());
+        self
+    }
+    pub fn nsfw(mut self, nsfw: bool) -> Self {
+        self.nsfw = nsfw;
+        self
+    }
+    pub fn spoiler(mut self, spoiler: bool) -> Self {
+        self.spoiler = spoiler;
+        self
+    }
+    /// Send replies to the inbox. Defaults to true
+    pub fn send_replies(mut self, send_replies: bool) -> Self {
+        self.send_replies = Some(send_replies);
+        self
+    }
+    /// Returns [Error::InvalidOption] if the subreddit, title or kind are missing or the title is too long
+    pub fn build(self) -> Result<Submit, Error> {
+        let subreddit = self.subreddit.trim().trim_start_matches("r/").to_string();
+        if subreddit.is_empty() {
+            return Err(Error::InvalidOption("subreddit is required".to_string()));
+        }
+        check_title(&self.title)?;
+        let kind = self
+            .kind
+            .ok_or_else(|| Error::InvalidOption("kind is required".to_string()))?;
+        match &kind {
+            SubmitKind::Link { url } if url.trim().is_empty() => {
+                return Err(Error::InvalidOption("url is required".to_string()));
+            }
+            SubmitKind::Crosspost { parent } if !FullnameKind::Link.matches(parent) => {
+                return Err(Error::InvalidOption(
+                    "parent must be the fullname of a submission".to_string(),
+                ));
+            }
+            _ => {}
+        }
+        Ok(Submit {
+            subreddit,
+            title: self.title,
+            kind,
+            flair_id: self.flair_id,
+            nsfw: self.nsfw,
+            spoiler: self.spoiler,
+            send_replies: self.send_replies.unwrap_or(true),
+        })
+    }
+}
+
+/// The `data` of the `/api/submit` response
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct SubmitResponse {
+    pub id: String,
+    /// The fullname of the new submission
+    pub name: String,
+    pub url: Option<String>,
+}
+
+fn check_title(title: &str) -> Result<(), Error> {
+    if title.trim().is_empty() {
+        return Err(Error::InvalidOption("title is required".to_string()));
+    }
+    if title.chars().count() > MAX_TITLE_LENGTH {
+        return Err(Error::InvalidOption(format!(
+            "title must be at most {MAX_TITLE_LENGTH} characters"
+        )));
+    }
+    Ok(())
+}
+
+/// A validated poll post. The JSON body sent to `/api/submit_poll_post`
+/// ```
+/// use rraw::submission::submit::SubmitPoll;
+/// let options = vec!["Rust".to_string(), "Also Rust".to_string()];
+/// let poll = SubmitPoll::new("rraw", "Favorite language?", options, 3, None).unwrap();
+/// assert_eq!(poll.duration, 3);
+/// assert!(SubmitPoll::new("rraw", "Favorite language?", vec![], 3, None).is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SubmitPoll {
+    pub api_type: &'static str,
+    #[serde(rename = "sr")]
+    pub subreddit: String,
+    pub submit_type: &'static str,
+    pub title: String,
+    /// The body of the post. Reddit requires the field even if it is empty
+    pub text: String,
+    pub options: Vec<String>,
+    /// Days the poll is open for
+    pub duration: u8,
+    #[serde(rename = "sendreplies")]
+    pub send_replies: bool,
+}
+
+impl SubmitPoll {
+    /// Returns [Error::InvalidOption] if the title is invalid, there are not [POLL_OPTIONS] options
+    /// or the duration is outside [POLL_DURATION_DAYS]
+    pub fn new(
+        subreddit: impl Into<String>,
+        title: impl Into<String>,
+        options: Vec<String>,
+        duration_days: u8,
+        text: Option<String>,
+    ) -> Result<SubmitPoll, Error> {
+        let subreddit = subreddit.into();
+        let subreddit = subreddit.trim().trim_start_matches("r/").to_string();
+        if subreddit.is_empty() {
+            return Err(Error::InvalidOption("subreddit is required".to_string()));
+        }
+        let title = title.into();
+        check_title(&title)?;
+        if !POLL_OPTIONS.contains(&options.len()) {
+            return Err(Error::InvalidOption(format!(
+                "a poll must have {} to {} options",
+                POLL_OPTIONS.start(),
+                POLL_OPTIONS.end()
+            )));
+        }
+        if options.iter().any(|option| option.trim().is_empty()) {
+            return Err(Error::InvalidOption(
+                "poll options can not be empty".to_string(),
+            ));
+        }
+        if !POLL_DURATION_DAYS.contains(&duration_days) {
+            return Err(Error::InvalidOption(format!(
+                "a poll must run for {} to {} days",
+                POLL_DURATION_DAYS.start(),
+                POLL_DURATION_DAYS.end()
+            )));
+        }
+        Ok(SubmitPoll {
+            api_type: "json",
+            subreddit,
+            submit_type: "subreddit",
+            title,
+            text: text.unwrap_or_default(),
+            options,
+            duration: duration_days,
+            send_replies: true,
+        })
+    }
+}
+
+/// The `data` of the `/api/submit_poll_post` response
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct SubmitPollResponse {
+    /// The id of the new submission. Usually the fullname
+    pub id: String,
+    pub url: Option<String>,
+}
+
+impl SubmitPollResponse {
+    /// The fullname of the new submission. `t3_{id}`
+    pub fn fullname(&self) -> String {
+        Fullname::new(FullnameKind::Link, &self.id).to_string()
+    }
+}
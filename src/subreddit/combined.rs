@@ -0,0 +1,287 @@
+//! Several subreddits read as one with Reddit's `r/rust+programming` form. One request instead of one per subreddit
+use std::collections::HashSet;
+
+use async_trait::async_trait;
+
+use crate::access::Access;
+use crate::auth::Authenticator;
+#[cfg(feature = "stream")]
+use crate::comments::response::CommentResponse;
+use crate::comments::response::CommentsResponse;
+use crate::error::Error;
+#[cfg(feature = "stream")]
+use crate::paginator::Paginator;
+#[cfg(feature = "stream")]
+use crate::responses::listing::GenericListing;
+#[cfg(feature = "stream")]
+use crate::submission::response::SubmissionResponse;
+use crate::submission::response::SubmissionsResponse;
+use crate::submission::SubmissionRetriever;
+use crate::utils::options::{FeedOption, SubmissionSort};
+use crate::Client;
+#[cfg(feature = "stream")]
+use futures_util::{stream, Stream};
+#[cfg(feature = "stream")]
+use std::collections::VecDeque;
+#[cfg(feature = "stream")]
+use std::fmt::Debug;
+#[cfg(feature = "stream")]
+use std::future::Future;
+#[cfg(feature = "stream")]
+use std::time::Duration;
+
+/// The longest `+` joined name. Longer URLs are rejected by Reddit
+pub const MAX_COMBINED_LENGTH: usize = 2048;
+/// The fullnames the polling streams remember. Older items are not expected to come back into the newest page
+#[cfg(feature = "stream")]
+const SEEN_LIMIT: usize = 1000;
+
+/// Checks the name of a subreddit without the `r/`.
+///
+/// 2 to 21 letters, numbers or underscores. The first can not be an underscore
+/// ```
+/// use rraw::subreddit::combined::check_subreddit_name;
+/// assert!(check_subreddit_name("rust").is_ok());
+/// assert!(check_subreddit_name("u_KingTuxWH").is_ok());
+/// assert!(check_subreddit_name("r/rust").is_err());
+/// assert!(check_subreddit_name("_rust").is_err());
+/// ```
+pub fn check_subreddit_name(name: &str) -> Result<(), Error> {
+    let valid_characters = name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if !(2..=21).contains(&name.len()) || !valid_characters || name.starts_with('_') {
+        return Err(Error::InvalidOption(format!(
+            "`{name}` is not a valid subreddit name"
+        )));
+    }
+    Ok(())
+}
+
+/// Subreddits read together. Their listings are merged by Reddit.
+///
+/// Created with [Client::subreddits]. No request is made until one of its methods is called
+#[derive(Clone)]
+pub struct CombinedSubreddits<'a, A: Authenticator> {
+    pub(crate) me: &'a Client<A>,
+    names: Vec<String>,
+}
+
+impl<'a, A: Authenticator> CombinedSubreddits<'a, A> {
+    /// Checks every name. Names repeated in a different case are only kept once
+    pub(crate) fn new<S: AsRef<str>>(
+        me: &'a Client<A>,
+        names: &[S],
+    ) -> Result<CombinedSubreddits<'a, A>, Error> {
+        let mut unique = HashSet::new();
+        let mut kept = Vec::with_capacity(names.len());
+        for name in names {
+            let name = name.as_ref().trim();
+            check_subreddit_name(name)?;
+            if unique.insert(name.to_ascii_lowercase()) {
+                kept.push(name.to_string());
+            }
+        }
+        if kept.is_empty() {
+            return Err(Error::InvalidOption(
+                "At least one subreddit is required".to_string(),
+            ));
+        }
+        let combined = CombinedSubreddits { me, names: kept };
+        let length = combined.joined().len();
+        if length > MAX_COMBINED_LENGTH {
+            return Err(Error::InvalidOption(format!(
+                "The joined subreddit names are {length} characters. The most is {MAX_COMBINED_LENGTH}"
+            )));
+        }
+        Ok(combined)
+    }
+    /// The names in the order they were given
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+    /// The names joined with `+`. Such as `rust+programming`
+    pub fn joined(&self) -> String {
+        self.names.join("+")
+    }
+    /// The newest comments across every submission of the subreddits. Newest first
+    pub async fn recent_comments(
+        &self,
+        feed: Option<FeedOption>,
+    ) -> Result<CommentsResponse, Error> {
+        let mut string = format!("/r/{}/comments.json", self.joined());
+        if let Some(options) = feed {
+            options.extend(&mut string);
+        }
+        self.me
+            .get_json::<CommentsResponse>(&string, Access::Any)
+            .await
+    }
+}
+
+#[cfg(feature = "stream")]
+impl<'a, A: Authenticator> CombinedSubreddits<'a, A> {
+    /// A [Paginator] over the submissions of every subreddit sorted together
+    pub fn submissions_stream(
+        &self,
+        sort: SubmissionSort,
+        options: FeedOption,
+    ) -> Paginator<'_, SubmissionResponse> {
+        self.me.paginate(options, move |options| {
+            self.get_submissions(sort, Some(options))
+        })
+    }
+    /// A [Paginator] over the newest comments of every subreddit
+    pub fn comments_stream(&self, options: FeedOption) -> Paginator<'_, CommentResponse> {
+        self.me
+            .paginate(options, move |options| self.recent_comments(Some(options)))
+    }
+    /// Polls the newest submissions every `interval`. Yielding each submission posted after the stream started once. Oldest first.
+    ///
+    /// Submissions are remembered by fullname. Reddit interleaves the subreddits, so their position in the listing changes between polls.
+    /// Errors from polling are yielded and polling continues
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() ->anyhow::Result<()>{
+    ///    use std::time::Duration;
+    ///    use futures_util::StreamExt;
+    ///    use rraw::auth::AnonymousAuthenticator;
+    ///    use rraw::Client;
+    ///    let client = Client::login(AnonymousAuthenticator::new(), "RRAW Test (by u/KingTuxWH)").await?;
+    ///    let subreddits = client.subreddits(&["rust", "programming"])?;
+    ///    let mut submissions = Box::pin(subreddits.stream_submissions(Duration::from_secs(30)));
+    ///    while let Some(submission) = submissions.next().await {
+    ///        println!("{}", submission?.title);
+    ///    }
+    ///    Ok(())
+    /// }
+    /// ```
+    pub fn stream_submissions(
+        &self,
+        interval: Duration,
+    ) -> impl Stream<Item = Result<SubmissionResponse, Error>> + 'a {
+        let subreddits = self.clone();
+        poll_new(
+            interval,
+            move || {
+                let subreddits = subreddits.clone();
+                async move {
+                    subreddits
+                        .get_submissions(SubmissionSort::New, Some(newest_page()))
+                        .await
+                }
+            },
+            |submission: &SubmissionResponse| submission.name.clone(),
+        )
+    }
+    /// Polls the newest comments every `interval`. Yielding each comment posted after the stream started once. Oldest first.
+    ///
+    /// Comments are remembered by fullname like [CombinedSubreddits::stream_submissions]. Errors from polling are yielded and polling continues
+    pub fn stream_comments(
+        &self,
+        interval: Duration,
+    ) -> impl Stream<Item = Result<CommentResponse, Error>> + 'a {
+        let subreddits = self.clone();
+        poll_new(
+            interval,
+            move || {
+                let subreddits = subreddits.clone();
+                async move { subreddits.recent_comments(Some(newest_page())).await }
+            },
+            CommentResponse::fullname,
+        )
+    }
+}
+
+#[async_trait(?Send)]
+impl<'a, A: Authenticator> SubmissionRetriever for CombinedSubreddits<'a, A> {
+    async fn get_submissions(
+        &self,
+        sort: SubmissionSort,
+        feed_options: Option<FeedOption>,
+    ) -> Result<SubmissionsResponse, Error> {
+        let mut path = format!("/r/{}/{}", self.joined(), sort);
+        if let Some(options) = feed_options {
+            options.extend(&mut path)
+        }
+        self.me
+            .get_json::<SubmissionsResponse>(&path, Access::Any)
+            .await
+    }
+}
+
+#[cfg(feature = "stream")]
+fn newest_page() -> FeedOption {
+    FeedOption {
+        limit: Some(FeedOption::MAX_LIMIT),
+        ..FeedOption::default()
+    }
+}
+
+/// Polls `fetch` every `interval` yielding the items whose fullname was not seen before. The first poll only fills the seen fullnames
+#[cfg(feature = "stream")]
+fn poll_new<'a, T, F, Fut>(
+    interval: Duration,
+    fetch: F,
+    fullname: fn(&T) -> String,
+) -> impl Stream<Item = Result<T, Error>> + 'a
+where
+    T: Debug + 'a,
+    F: FnMut() -> Fut + 'a,
+    Fut: Future<Output = Result<GenericListing<T>, Error>> + 'a,
+{
+    let state = PollState {
+        fetch,
+        buffer: VecDeque::new(),
+        seen: HashSet::new(),
+        seen_order: VecDeque::new(),
+        polled: false,
+    };
+    stream::unfold(state, move |mut state| async move {
+        loop {
+            if let Some(item) = state.buffer.pop_front() {
+                return Some((Ok(item), state));
+            }
+            if state.polled {
+                tokio::time::sleep(interval).await;
+            }
+            let first = !state.polled;
+            state.polled = true;
+            match (state.fetch)().await {
+                Ok(listing) => state.push_new(listing, first, fullname),
+                Err(error) => return Some((Err(error), state)),
+            }
+        }
+    })
+}
+
+#[cfg(feature = "stream")]
+struct PollState<T, F> {
+    fetch: F,
+    buffer: VecDeque<T>,
+    /// Fullnames already yielded or present when the stream started
+    seen: HashSet<String>,
+    /// The seen fullnames oldest first. So the oldest are forgotten after [SEEN_LIMIT]
+    seen_order: VecDeque<String>,
+    polled: bool,
+}
+
+#[cfg(feature = "stream")]
+impl<T: Debug, F> PollState<T, F> {
+    fn push_new(&mut self, listing: GenericListing<T>, first: bool, fullname: fn(&T) -> String) {
+        // Reddit returns the newest first
+        for item in listing.into_iter().rev().map(|child| child.data) {
+            let name = fullname(&item);
+            if !self.seen.insert(name.clone()) {
+                continue;
+            }
+            self.seen_order.push_back(name);
+            if self.seen_order.len() > SEEN_LIMIT {
+                if let Some(oldest) = self.seen_order.pop_front() {
+                    self.seen.remove(&oldest);
+                }
+            }
+            if !first {
+                self.buffer.push_back(item);
+            }
+        }
+    }
+}
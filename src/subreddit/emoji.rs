@@ -0,0 +1,189 @@
+//! Subreddit emoji. `/api/v1/{name}/emojis/all` is a map of maps rather than a Listing
+use std::collections::BTreeMap;
+
+use serde::de::Deserializer;
+use serde::ser::Serializer;
+use serde::{Deserialize, Serialize};
+
+use crate::error::Error;
+
+/// The key Reddit uses for the emoji available in every subreddit
+pub const SNOOMOJI_KEY: &str = "snoomojis";
+/// The longest emoji name Reddit accepts
+pub const MAX_EMOJI_NAME_LENGTH: usize = 24;
+
+/// An emoji usable in flair
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Emoji {
+    /// Used as `:name:` in flair text
+    pub name: String,
+    pub url: String,
+    /// The fullname of the user that uploaded it
+    pub created_by: Option<String>,
+    pub mod_flair_only: bool,
+    pub post_flair_allowed: bool,
+    pub user_flair_allowed: bool,
+}
+
+/// The fields of an emoji. The name is the key of the map
+#[derive(Deserialize, Serialize)]
+struct EmojiData {
+    url: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    created_by: Option<String>,
+    #[serde(default)]
+    mod_flair_only: bool,
+    #[serde(default = "allowed")]
+    post_flair_allowed: bool,
+    #[serde(default = "allowed")]
+    user_flair_allowed: bool,
+}
+
+fn allowed() -> bool {
+    true
+}
+
+impl Emoji {
+    fn new(name: String, data: EmojiData) -> Emoji {
+        Emoji {
+            name,
+            url: data.url,
+            created_by: data.created_by,
+            mod_flair_only: data.mod_flair_only,
+            post_flair_allowed: data.post_flair_allowed,
+            user_flair_allowed: data.user_flair_allowed,
+        }
+    }
+    fn data(&self) -> EmojiData {
+        EmojiData {
+            url: self.url.clone(),
+            created_by: self.created_by.clone(),
+            mod_flair_only: self.mod_flair_only,
+            post_flair_allowed: self.post_flair_allowed,
+            user_flair_allowed: self.user_flair_allowed,
+        }
+    }
+}
+
+/// The emoji of a subreddit. Each list is sorted by name
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Emojis {
+    /// The emoji Reddit provides to every subreddit
+    pub snoomojis: Vec<Emoji>,
+    /// The fullname of the subreddit. None if it has no custom emoji
+    pub subreddit_id: Option<String>,
+    /// The emoji uploaded by the moderators
+    pub subreddit: Vec<Emoji>,
+}
+
+impl Emojis {
+    /// Finds an emoji by name. Custom emoji take precedence over snoomoji
+    pub fn get(&self, name: &str) -> Option<&Emoji> {
+        self.subreddit
+            .iter()
+            .chain(self.snoomojis.iter())
+            .find(|emoji| emoji.name == name)
+    }
+}
+
+type EmojiMap = BTreeMap<String, BTreeMap<String, EmojiData>>;
+
+fn into_emoji(emoji: BTreeMap<String, EmojiData>) -> Vec<Emoji> {
+    emoji
+        .into_iter()
+        .map(|(name, data)| Emoji::new(name, data))
+        .collect()
+}
+
+impl<'de> Deserialize<'de> for Emojis {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut emojis = Emojis::default();
+        for (key, emoji) in EmojiMap::deserialize(deserializer)? {
+            if key == SNOOMOJI_KEY {
+                emojis.snoomojis = into_emoji(emoji);
+            } else {
+                emojis.subreddit = into_emoji(emoji);
+                emojis.subreddit_id = Some(key);
+            }
+        }
+        Ok(emojis)
+    }
+}
+
+impl Serialize for Emojis {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let to_map = |emoji: &[Emoji]| -> BTreeMap<String, EmojiData> {
+            emoji
+                .iter()
+                .map(|emoji| (emoji.name.clone(), emoji.data()))
+                .collect()
+        };
+        let mut map = EmojiMap::new();
+        map.insert(SNOOMOJI_KEY.to_string(), to_map(&self.snoomojis));
+        if let Some(subreddit_id) = &self.subreddit_id {
+            map.insert(subreddit_id.clone(), to_map(&self.subreddit));
+        }
+        map.serialize(serializer)
+    }
+}
+
+/// The upload lease for an emoji image. From `/api/v1/{name}/emoji_asset_upload_s3.json`
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
+pub(crate) struct EmojiLeaseResponse {
+    #[serde(rename = "s3UploadLease")]
+    pub lease: UploadLease,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
+pub(crate) struct UploadLease {
+    /// Protocol relative. `//reddit-uploaded-emoji.s3-accelerate.amazonaws.com`
+    pub action: String,
+    pub fields: Vec<LeaseField>,
+}
+
+impl UploadLease {
+    /// The key of the uploaded image. Sent to `/api/v1/{name}/emoji.json`
+    pub fn key(&self) -> Option<&str> {
+        self.fields
+            .iter()
+            .find(|field| field.name == "key")
+            .map(|field| field.value.as_str())
+    }
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
+pub(crate) struct LeaseField {
+    pub name: String,
+    pub value: String,
+}
+
+/// Returns [Error::InvalidOption] unless the name is 1 to [MAX_EMOJI_NAME_LENGTH] letters, digits, `-` or `_`
+pub fn check_emoji_name(name: &str) -> Result<(), Error> {
+    if name.is_empty() || name.chars().count() > MAX_EMOJI_NAME_LENGTH {
+        return Err(Error::InvalidOption(format!(
+            "emoji names must be 1 to {MAX_EMOJI_NAME_LENGTH} characters"
+        )));
+    }
+    if !name
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        return Err(Error::InvalidOption(
+            "emoji names can only contain letters, digits, - and _".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// The file extension and mime type of a PNG or JPEG image. Reddit does not accept other formats for emoji
+pub(crate) fn image_type(image: &[u8]) -> Result<(&'static str, &'static str), Error> {
+    if image.starts_with(b"\x89PNG\r\n\x1a\n") {
+        Ok(("png", "image/png"))
+    } else if image.starts_with(&[0xFF, 0xD8, 0xFF]) {
+        Ok(("jpg", "image/jpeg"))
+    } else {
+        Err(Error::InvalidOption(
+            "emoji must be a PNG or JPEG image".to_string(),
+        ))
+    }
+}
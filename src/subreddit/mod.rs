@@ -1,17 +1,60 @@
+pub mod combined;
+pub mod emoji;
 pub mod response;
+pub mod widget;
+#[cfg(feature = "stream")]
+mod window;
 
 use log::trace;
+use reqwest::multipart::{Form, Part};
 use reqwest::Body;
+use std::fmt::Display;
 
+use crate::access::Access;
+#[cfg(feature = "stream")]
+use crate::paginator::{Paginator, PartialError};
+#[cfg(feature = "stream")]
+use crate::responses::listing::Listing;
+#[cfg(feature = "stream")]
+use crate::responses::{GenericResponse, RedditDataType};
+use crate::submission::response::SubmissionResponse;
 use crate::submission::response::SubmissionsResponse;
+use crate::submission::submit::{SubmitPoll, SubmitPollResponse};
 use crate::submission::SubmissionRetriever;
+#[cfg(feature = "stream")]
+use crate::subreddit::window::{unix_seconds, SearchWindow};
+#[cfg(feature = "stream")]
+use crate::utils::options::TimePeriod;
 use crate::{Authorized, Client};
+#[cfg(feature = "stream")]
+use std::cell::RefCell;
+#[cfg(feature = "stream")]
+use std::rc::Rc;
+#[cfg(feature = "stream")]
+use std::time::SystemTime;
 
 use crate::auth::Authenticator;
-use crate::error::Error;
-use crate::subreddit::response::{AboutSubreddit, Contributors, Friend, Moderators};
-use crate::utils::options::{FeedOption, FriendType};
+use crate::collection::response::Collection;
+use crate::comments::response::CommentsResponse;
+use crate::error::http_error::HTTPError;
+use crate::error::{check_response, Error};
+use crate::responses::api::JsonResponse;
+use crate::responses::listing::MixedListing;
+#[cfg(feature = "rss")]
+use crate::rss::RssItem;
+use crate::subreddit::emoji::{check_emoji_name, image_type, EmojiLeaseResponse, Emojis};
+use crate::subreddit::response::{
+    AboutSubreddit, Contributors, FlairTemplate, Friend, Moderators, PostRequirements,
+    PostingContext, PostingSection, Rules, SubmitRequirements, SubmitText, SubredditResponse,
+};
+use crate::subreddit::widget::Widgets;
+use crate::utils::form_body;
+use crate::utils::options::{
+    FeedOption, FriendType, ModQueue, SearchSort, SubmissionSort, ThingType,
+};
 use async_trait::async_trait;
+use futures_util::{join, try_join};
+use serde::de::IgnoredAny;
 use serde_json::Value;
 
 /// Subreddit Object
@@ -29,30 +72,500 @@ impl<'a, A: Authenticator> PartialEq for Subreddit<'a, A> {
 }
 
 impl<'a, A: Authenticator> Subreddit<'a, A> {
+    /// Searches the submissions of the Subreddit. The query can be a `&str` or a [SearchQuery](crate::utils::search::SearchQuery)
+    pub async fn search(
+        &self,
+        query: impl Display,
+        sort: Option<SearchSort>,
+        feed: Option<FeedOption>,
+    ) -> Result<SubmissionsResponse, Error> {
+        let mut query = vec![("q", query.to_string()), ("restrict_sr", "on".to_string())];
+        if let Some(sort) = sort {
+            query.push(("sort", sort.to_string()));
+        }
+        let mut path = format!(
+            "/r/{}/search.json?{}",
+            &self.subreddit,
+            serde_urlencoded::to_string(query).unwrap_or_default()
+        );
+        if let Some(options) = feed {
+            options.extend(&mut path);
+        }
+        self.me
+            .get_json::<SubmissionsResponse>(&path, Access::Any)
+            .await
+    }
+    /// Loads the about of the Subreddit again. Includes the relationship of the logged in user such as [AboutSubreddit::user_is_banned]
+    pub async fn about(&self) -> Result<AboutSubreddit, Error> {
+        let string = format!("/r/{}/about.json", &self.subreddit);
+        let about = self
+            .me
+            .get_json::<SubredditResponse>(&string, Access::Any)
+            .await?;
+        Ok(about.data)
+    }
+    /// The rules of the Subreddit. Private subreddits return 403 unless the user is approved
+    pub async fn rules(&self) -> Result<Rules, Error> {
+        let string = format!("/r/{}/about/rules.json", &self.subreddit);
+        self.me.get_json::<Rules>(&string, Access::Any).await
+    }
     /// Returns a Listing of "Contributors" to the Subreddit
     /// Returns 403 if the contributors are displayed. Most Subreddits will have this disabled
     pub async fn get_contributors(&self, feed: Option<FeedOption>) -> Result<Contributors, Error> {
         let mut string = format!("/r/{}/about/contributors", &self.subreddit);
         if let Some(options) = feed {
-            string.push('?');
-            string.push_str(options.url().as_str());
+            options.extend(&mut string);
         }
         self.me
-            .get_json::<Contributors>(&*string, true, false)
+            .get_json::<Contributors>(&string, Access::OAuthOnly)
             .await
     }
     /// Returns a Listing of Moderators to the Subreddit
     pub async fn get_moderators(&self, feed: Option<FeedOption>) -> Result<Moderators, Error> {
         let mut string = format!("/r/{}/about/moderators", &self.subreddit);
         if let Some(options) = feed {
-            string.push('?');
-            string.push_str(options.url().as_str());
+            options.extend(&mut string);
         }
-        self.me.get_json::<Moderators>(&string, true, false).await
+        self.me
+            .get_json::<Moderators>(&string, Access::OAuthOnly)
+            .await
+    }
+    /// The collections of the Subreddit. Without their submissions
+    pub async fn collections(&self) -> Result<Vec<Collection>, Error> {
+        let string = format!(
+            "/api/v1/collections/subreddit_collections?sr_fullname={}",
+            self.subreddit.name
+        );
+        self.me
+            .get_json::<Vec<Collection>>(&string, Access::Any)
+            .await
+    }
+    /// The snoomoji and custom emoji usable in the flair of the Subreddit
+    pub async fn emojis(&self) -> Result<Emojis, Error> {
+        let string = format!("/api/v1/{}/emojis/all", &self.subreddit);
+        self.me.get_json::<Emojis>(&string, Access::Any).await
+    }
+    /// The new Reddit widgets of the Subreddit. Such as the sidebar and the moderators
+    pub async fn widgets(&self) -> Result<Widgets, Error> {
+        let string = format!("/r/{}/api/widgets", &self.subreddit);
+        self.me.get_json::<Widgets>(&string, Access::Any).await
+    }
+    /// The newest comments across every submission of the Subreddit. Newest first.
+    ///
+    /// Page with the `after` of the [FeedOption]. Reddit returns at most [FeedOption::MAX_LIMIT] at a time
+    pub async fn recent_comments(
+        &self,
+        feed: Option<FeedOption>,
+    ) -> Result<CommentsResponse, Error> {
+        let mut string = format!("/r/{}/comments.json", &self.subreddit);
+        if let Some(options) = feed {
+            options.extend(&mut string);
+        }
+        self.me
+            .get_json::<CommentsResponse>(&string, Access::Any)
+            .await
+    }
+}
+
+#[cfg(feature = "stream")]
+impl<'a, A: Authenticator> Subreddit<'a, A> {
+    /// A [Paginator] over the top submissions of the Subreddit for the period
+    pub fn top_stream(
+        &self,
+        mut options: FeedOption,
+        period: TimePeriod,
+    ) -> Paginator<'_, SubmissionResponse> {
+        options.period = Some(period);
+        self.me.paginate(options, move |options| {
+            self.get_submissions(SubmissionSort::Top, Some(options))
+        })
+    }
+    /// Loads up to `max` of the top submissions of the Subreddit for the period.
+    ///
+    /// If a request fails part way through the submissions loaded so far are returned inside the [PartialError]
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() ->anyhow::Result<()>{
+    ///    use rraw::auth::AnonymousAuthenticator;
+    ///    use rraw::utils::options::{FeedOption, TimePeriod};
+    ///    use rraw::Client;
+    ///    let client = Client::login(AnonymousAuthenticator::new(), "RRAW Test (by u/KingTuxWH)").await?;
+    ///    let subreddit = client.subreddit("rust").await?;
+    ///    let posts = subreddit.top_all(FeedOption::default(), TimePeriod::AllTime, 500).await?;
+    ///    assert!(posts.len() <= 500);
+    ///    Ok(())
+    /// }
+    /// ```
+    pub async fn top_all(
+        &self,
+        options: FeedOption,
+        period: TimePeriod,
+        max: usize,
+    ) -> Result<Vec<SubmissionResponse>, PartialError<SubmissionResponse>> {
+        self.top_stream(options, period)
+            .limit(max)
+            .collect_partial()
+            .await
+    }
+    /// A [Paginator] over the submissions of the Subreddit created between `start` and `end`. Newest first.
+    ///
+    /// Listings stop at around 1000 submissions. This searches one window of time at a time instead and
+    /// moves the end of the window back whenever a page comes back full, so it can reach further back.
+    /// Submissions on the edge of two windows are only returned once.
+    ///
+    /// This is best effort. Search is indexed with a delay, so the newest submissions can be missing, and
+    /// a second with more submissions than fit on one page is cut short
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() ->anyhow::Result<()>{
+    ///    use std::time::{Duration, SystemTime};
+    ///    use rraw::auth::AnonymousAuthenticator;
+    ///    use rraw::Client;
+    ///    let client = Client::login(AnonymousAuthenticator::new(), "RRAW Test (by u/KingTuxWH)").await?;
+    ///    let subreddit = client.subreddit("rust").await?;
+    ///    let end = SystemTime::now() - Duration::from_secs(60 * 60 * 24 * 365);
+    ///    let start = end - Duration::from_secs(60 * 60 * 24 * 7);
+    ///    let posts = subreddit.submissions_between(start, end).collect_partial().await?;
+    ///    println!("{} submissions that week", posts.len());
+    ///    Ok(())
+    /// }
+    /// ```
+    pub fn submissions_between(
+        &self,
+        start: SystemTime,
+        end: SystemTime,
+    ) -> Paginator<'_, SubmissionResponse> {
+        let window = Rc::new(RefCell::new(SearchWindow::new(
+            unix_seconds(start),
+            unix_seconds(end),
+        )));
+        let options = FeedOption {
+            limit: Some(FeedOption::MAX_LIMIT),
+            ..FeedOption::default()
+        };
+        self.me.paginate(options, move |options| {
+            let window = window.clone();
+            async move { self.search_window(&window, options).await }
+        })
+    }
+    /// The next page of [Subreddit::submissions_between]. Windows with nothing new are skipped
+    async fn search_window(
+        &self,
+        window: &RefCell<SearchWindow>,
+        options: FeedOption,
+    ) -> Result<SubmissionsResponse, Error> {
+        let limit = options
+            .limit
+            .unwrap_or(FeedOption::MAX_LIMIT)
+            .clamp(1, FeedOption::MAX_LIMIT);
+        loop {
+            if window.borrow().is_done() {
+                return Ok(GenericResponse {
+                    kind: RedditDataType::Listing,
+                    data: Listing {
+                        modhash: None,
+                        after: None,
+                        before: None,
+                        children: Vec::new(),
+                    },
+                });
+            }
+            let query = window.borrow().query();
+            let path = format!(
+                "/r/{}/search.json?{}",
+                &self.subreddit,
+                serde_urlencoded::to_string([
+                    ("q", query.as_str()),
+                    ("restrict_sr", "on"),
+                    ("sort", "new"),
+                    ("syntax", "cloudsearch"),
+                    ("limit", &limit.to_string()),
+                ])
+                .unwrap_or_default()
+            );
+            let mut listing = self
+                .me
+                .get_json::<SubmissionsResponse>(&path, Access::Any)
+                .await?;
+            let page = std::mem::take(&mut listing.data.children);
+            let full = page.len() >= limit as usize;
+            let mut window = window.borrow_mut();
+            let kept = window.advance(page, full);
+            if kept.is_empty() && !window.is_done() {
+                continue;
+            }
+            listing.data.after = match kept.last() {
+                Some(last) if !window.is_done() => Some(last.data.name.clone()),
+                _ => None,
+            };
+            listing.data.children = kept;
+            return Ok(listing);
+        }
+    }
+}
+
+#[cfg(feature = "rss")]
+impl<'a, A: Authenticator> Subreddit<'a, A> {
+    /// The newest submissions from `/r/{name}/new.rss`. Does not need OAuth and is not counted against the API rate limit
+    pub async fn new_rss(&self) -> Result<Vec<RssItem>, Error> {
+        let string = format!("/r/{}/new.rss", &self.subreddit);
+        self.me.get_feed(&string).await
     }
 }
 
 impl<'a, A: Authorized> Subreddit<'a, A> {
+    /// Loads the submit text, rules and post requirements of the Subreddit concurrently.
+    ///
+    /// Use [SubmitRequirements::check] to validate a submission before sending it
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() ->anyhow::Result<()>{
+    ///    use std::env;
+    ///    use rraw::auth::PasswordAuthenticator;
+    ///    use rraw::submission::submit::{SubmitBuilder, SubmitKind};
+    ///    use rraw::Client;
+    ///    let client = Client::login(PasswordAuthenticator::new(env::var("CLIENT_ID")?,env::var("CLIENT_SECRET")?,env::var("USERNAME")?,env::var("PASSWORD")?), "RRAW Test (by u/KingTuxWH)").await?;
+    ///    let subreddit = client.subreddit("rraw").await?;
+    ///    let requirements = subreddit.submit_requirements().await?;
+    ///    println!("{}", requirements.submit_text.submit_text);
+    ///    let submit = SubmitBuilder::new("rraw", "RRAW 1.3 Released")
+    ///        .kind(SubmitKind::Link { url: "https://github.com/wherkamp/async_rawr".to_string() })
+    ///        .build()?;
+    ///    requirements.check(&submit)?;
+    ///    Ok(())
+    /// }
+    /// ```
+    pub async fn submit_requirements(&self) -> Result<SubmitRequirements, Error> {
+        let submit_text = format!("/r/{}/api/submit_text.json", &self.subreddit);
+        let (submit_text, rules, requirements) = try_join!(
+            self.me.get_json::<SubmitText>(&submit_text, Access::Any),
+            self.rules(),
+            self.post_requirements()
+        )?;
+        Ok(SubmitRequirements {
+            submit_text,
+            rules: rules.rules,
+            requirements,
+        })
+    }
+    /// The title, body, domain and flair requirements of the Subreddit.
+    ///
+    /// Use [PostRequirements::validate] to list what a submission is missing before sending it
+    pub async fn post_requirements(&self) -> Result<PostRequirements, Error> {
+        let path = format!("/api/v1/{}/post_requirements", &self.subreddit);
+        self.me
+            .get_json::<PostRequirements>(&path, Access::OAuthOnly)
+            .await
+    }
+    /// The link flair the user can pick when submitting. Returns 403 if the Subreddit does not let users pick flair
+    pub async fn link_flair(&self) -> Result<Vec<FlairTemplate>, Error> {
+        let path = format!("/r/{}/api/link_flair_v2", &self.subreddit);
+        self.me
+            .get_json::<Vec<FlairTemplate>>(&path, Access::OAuthOnly)
+            .await
+    }
+    /// A moderation listing. `filter` limits it to only comments or submissions.
+    ///
+    /// Requires the `modposts` scope and moderator permissions. Use the subreddit `mod` for every subreddit you moderate
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() ->anyhow::Result<()>{
+    ///    use std::env;
+    ///    use rraw::auth::PasswordAuthenticator;
+    ///    use rraw::utils::options::ModQueue;
+    ///    use rraw::Client;
+    ///    let client = Client::login(PasswordAuthenticator::new(env::var("CLIENT_ID")?,env::var("CLIENT_SECRET")?,env::var("USERNAME")?,env::var("PASSWORD")?), "RRAW Test (by u/KingTuxWH)").await?;
+    ///    let subreddit = client.subreddit("rraw").await?;
+    ///    let reports = subreddit.mod_queue(ModQueue::Reports, None, None).await?;
+    ///    for comment in reports.comments() {
+    ///        println!("Reported comment {}", comment.fullname());
+    ///    }
+    ///    Ok(())
+    /// }
+    /// ```
+    pub async fn mod_queue(
+        &self,
+        queue: ModQueue,
+        feed: Option<FeedOption>,
+        filter: Option<ThingType>,
+    ) -> Result<MixedListing, Error> {
+        let mut path = format!("/r/{}/about/{queue}", &self.subreddit);
+        if let Some(options) = feed {
+            options.extend(&mut path);
+        }
+        if let Some(filter) = filter {
+            filter.extend(&mut path);
+        }
+        self.me
+            .get_json::<MixedListing>(&path, Access::OAuthOnly)
+            .await
+    }
+    /// Loads the about, rules, post requirements and link flair of the Subreddit concurrently.
+    ///
+    /// A failing section does not fail the others. Check [PostingContext::can_submit] before submitting
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() ->anyhow::Result<()>{
+    ///    use std::env;
+    ///    use rraw::auth::PasswordAuthenticator;
+    ///    use rraw::Client;
+    ///    let client = Client::login(PasswordAuthenticator::new(env::var("CLIENT_ID")?,env::var("CLIENT_SECRET")?,env::var("USERNAME")?,env::var("PASSWORD")?), "RRAW Test (by u/KingTuxWH)").await?;
+    ///    let subreddit = client.subreddit("rraw").await?;
+    ///    let context = subreddit.posting_context().await;
+    ///    if context.can_submit_self() && !context.flair_required() {
+    ///        println!("Ready to post to r/rraw");
+    ///    }
+    ///    Ok(())
+    /// }
+    /// ```
+    pub async fn posting_context(&self) -> PostingContext {
+        let (about, rules, requirements, flair) = join!(
+            self.about(),
+            self.rules(),
+            self.post_requirements(),
+            self.link_flair()
+        );
+        let mut errors = Vec::new();
+        let about = section(&mut errors, PostingSection::About, about);
+        let rules = section(
+            &mut errors,
+            PostingSection::Rules,
+            rules.map(|rules| rules.rules),
+        );
+        let requirements = section(&mut errors, PostingSection::Requirements, requirements);
+        let flair = section(&mut errors, PostingSection::Flair, flair);
+        PostingContext {
+            about,
+            rules,
+            requirements,
+            flair,
+            errors,
+        }
+    }
+    /// Submits a poll post. Polls need 2 to 6 options and run for 1 to 7 days
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() ->anyhow::Result<()>{
+    ///    use std::env;
+    ///    use rraw::auth::PasswordAuthenticator;
+    ///    use rraw::Client;
+    ///    let client = Client::login(PasswordAuthenticator::new(env::var("CLIENT_ID")?,env::var("CLIENT_SECRET")?,env::var("USERNAME")?,env::var("PASSWORD")?), "RRAW Test (by u/KingTuxWH)").await?;
+    ///    let subreddit = client.subreddit("rraw").await?;
+    ///    let options = vec!["Yes".to_string(), "No".to_string()];
+    ///    let poll = subreddit.submit_poll("Weekly poll", options, 7, None).await?;
+    ///    println!("{}", poll.permalink);
+    ///    Ok(())
+    /// }
+    /// ```
+    pub async fn submit_poll(
+        &self,
+        title: impl Into<String>,
+        options: Vec<String>,
+        duration_days: u8,
+        text: Option<String>,
+    ) -> Result<SubmissionResponse, Error> {
+        let poll = SubmitPoll::new(
+            self.subreddit.display_name.clone(),
+            title,
+            options,
+            duration_days,
+            text,
+        )?;
+        let response: JsonResponse<SubmitPollResponse> = self
+            .me
+            .post_json_body("/api/submit_poll_post", Access::OAuthOnly, &poll)
+            .await?;
+        let created = response
+            .into_result()?
+            .ok_or_else(|| Error::from("Reddit did not return the new submission"))?;
+        self.me
+            .info_single(&created.fullname())
+            .await?
+            .ok_or_else(|| HTTPError::NotFound.into())
+    }
+    /// Creates an empty collection. Add submissions with [Collections::add_post](crate::collection::Collections::add_post).
+    ///
+    /// Requires moderator access
+    pub async fn create_collection(
+        &self,
+        title: &str,
+        description: &str,
+    ) -> Result<Collection, Error> {
+        let body = form_body(&[
+            ("sr_fullname", self.subreddit.name.as_str()),
+            ("title", title),
+            ("description", description),
+        ]);
+        self.me
+            .post_json::<Collection>(
+                "/api/v1/collections/create_collection",
+                Access::OAuthOnly,
+                body,
+            )
+            .await
+    }
+    /// Uploads a PNG or JPEG image as a custom emoji. Reddit resizes it to 128x128.
+    ///
+    /// The image is uploaded with a lease from Reddit then added to the Subreddit.
+    /// Usable in post and user flair by default. Requires moderator access
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() ->anyhow::Result<()>{
+    ///    use std::env;
+    ///    use rraw::auth::PasswordAuthenticator;
+    ///    use rraw::Client;
+    ///    let client = Client::login(PasswordAuthenticator::new(env::var("CLIENT_ID")?,env::var("CLIENT_SECRET")?,env::var("USERNAME")?,env::var("PASSWORD")?), "RRAW Test (by u/KingTuxWH)").await?;
+    ///    let subreddit = client.subreddit("rraw").await?;
+    ///    subreddit.upload_emoji("ferris", std::fs::read("ferris.png")?).await?;
+    ///    Ok(())
+    /// }
+    /// ```
+    pub async fn upload_emoji(&self, name: &str, image: Vec<u8>) -> Result<(), Error> {
+        check_emoji_name(name)?;
+        let (extension, mime_type) = image_type(&image)?;
+        let filepath = format!("{name}.{extension}");
+        let string = format!("/api/v1/{}/emoji_asset_upload_s3.json", &self.subreddit);
+        let body = form_body(&[("filepath", filepath.as_str()), ("mimetype", mime_type)]);
+        let response = self.me.post(&string, Access::OAuthOnly, body).await?;
+        let url = response.url().clone();
+        let lease = self
+            .me
+            .parse_api_json::<EmojiLeaseResponse>(response)
+            .await?
+            .lease;
+        let key = lease
+            .key()
+            .ok_or_else(|| Error::from("Reddit did not return the key of the upload lease"))?
+            .to_string();
+        // The action is protocol relative
+        let action = url
+            .join(&lease.action)
+            .map_err(|_| Error::from("Reddit returned an invalid upload lease"))?;
+        trace!("Uploading {} to {}", filepath, action);
+        let mut form = Form::new();
+        for field in lease.fields {
+            form = form.text(field.name, field.value);
+        }
+        let file = Part::bytes(image).file_name(filepath).mime_str(mime_type)?;
+        let response = self
+            .me
+            .client
+            .post(action)
+            .multipart(form.part("file", file))
+            .send()
+            .await?;
+        check_response(response).await?;
+
+        let string = format!("/api/v1/{}/emoji.json", &self.subreddit);
+        let body = form_body(&[("name", name), ("s3_key", &key)]);
+        self.me.post_empty(&string, Access::OAuthOnly, body).await
+    }
+    /// Deletes a custom emoji. Requires moderator access
+    pub async fn delete_emoji(&self, name: &str) -> Result<(), Error> {
+        check_emoji_name(name)?;
+        let string = format!("/api/v1/{}/emoji/{}", &self.subreddit, name);
+        self.me.delete(&string, Access::OAuthOnly).await
+    }
     /// Adds a friend to the subreddit
     pub async fn add_friend(&self, username: String, typ: FriendType) -> Result<Friend, Error> {
         trace!(
@@ -64,14 +577,68 @@ impl<'a, A: Authorized> Subreddit<'a, A> {
         let string = format!("/r/{}/api/friend", &self.subreddit);
 
         let body = Body::from(format!("name={}&type={}", username, typ));
-        self.me.post_json::<Friend>(&string, true, body).await
+        self.me
+            .post_json::<Friend>(&string, Access::OAuthOnly, body)
+            .await
     }
     ///  removes a friend from the Subreddit
     pub async fn remove_friend(&self, username: String, typ: FriendType) -> Result<Friend, Error> {
         let string = format!("/r/{}/api/unfriend", &self.subreddit);
 
         let body = Body::from(format!("name={username}&type={typ}"));
-        self.me.post_json::<Friend>(&string, true, body).await
+        self.me
+            .post_json::<Friend>(&string, Access::OAuthOnly, body)
+            .await
+    }
+
+    /// Adds the user as an approved submitter.
+    ///
+    /// Requires moderator access. Otherwise [Error::Forbidden](crate::error::Error::Forbidden) is returned
+    pub async fn add_contributor(&self, username: &str) -> Result<(), Error> {
+        self.friend_request("friend", username, FriendType::Contributor)
+            .await
+    }
+    /// Removes the user as an approved submitter.
+    ///
+    /// Requires moderator access. Otherwise [Error::Forbidden](crate::error::Error::Forbidden) is returned
+    pub async fn remove_contributor(&self, username: &str) -> Result<(), Error> {
+        self.friend_request("unfriend", username, FriendType::Contributor)
+            .await
+    }
+    /// Allows the user to edit the wiki.
+    ///
+    /// Requires moderator access. Otherwise [Error::Forbidden](crate::error::Error::Forbidden) is returned
+    pub async fn add_wiki_contributor(&self, username: &str) -> Result<(), Error> {
+        self.friend_request("friend", username, FriendType::WikiContributor)
+            .await
+    }
+    /// Removes the users ability to edit the wiki.
+    ///
+    /// Requires moderator access. Otherwise [Error::Forbidden](crate::error::Error::Forbidden) is returned
+    pub async fn remove_wiki_contributor(&self, username: &str) -> Result<(), Error> {
+        self.friend_request("unfriend", username, FriendType::WikiContributor)
+            .await
+    }
+    async fn friend_request(
+        &self,
+        endpoint: &str,
+        username: &str,
+        typ: FriendType,
+    ) -> Result<(), Error> {
+        trace!(
+            "{} {} on r/{} with type {}",
+            endpoint,
+            username,
+            &self.subreddit,
+            &typ
+        );
+        let string = format!("/r/{}/api/{}", &self.subreddit, endpoint);
+        let typ = typ.to_string();
+        let body = form_body(&[("api_type", "json"), ("name", username), ("type", &typ)]);
+        self.me
+            .post_json::<JsonResponse<IgnoredAny>>(&string, Access::OAuthOnly, body)
+            .await?;
+        Ok(())
     }
 
     pub async fn compose(
@@ -85,23 +652,41 @@ impl<'a, A: Authorized> Subreddit<'a, A> {
             self.subreddit.name
         );
         let body = reqwest::Body::from(string);
-        self.me.post_json::<Value>("/api/compose", true, body).await
+        self.me
+            .post_json::<Value>("/api/compose", Access::OAuthOnly, body)
+            .await
+    }
+}
+
+/// The value of a [PostingContext] section. The error is recorded instead
+fn section<T>(
+    errors: &mut Vec<(PostingSection, Error)>,
+    section: PostingSection,
+    result: Result<T, Error>,
+) -> Option<T> {
+    match result {
+        Ok(value) => Some(value),
+        Err(error) => {
+            errors.push((section, error));
+            None
+        }
     }
 }
+
 #[async_trait(?Send)]
 impl<'a, A: Authenticator> SubmissionRetriever for Subreddit<'a, A> {
-    async fn get_submissions<T: Into<String> + std::marker::Send>(
+    async fn get_submissions(
         &self,
-        sort: T,
+        sort: SubmissionSort,
         feed_options: Option<FeedOption>,
     ) -> Result<SubmissionsResponse, Error> {
-        let mut path = format!("/r/{}/{}", &self.subreddit, sort.into());
+        let mut path = format!("/r/{}/{}", &self.subreddit, sort);
         if let Some(options) = feed_options {
             options.extend(&mut path)
         }
         return self
             .me
-            .get_json::<SubmissionsResponse>(&path, false, false)
+            .get_json::<SubmissionsResponse>(&path, Access::Any)
             .await;
     }
 }
@@ -1,19 +1,23 @@
+use crate::error::Error;
+use crate::responses::types::SubredditType;
 use crate::responses::GenericResponse;
+use crate::submission::submit::{Submit, SubmitKind};
 use std::collections::HashMap;
 use std::fmt::{Debug, Display, Formatter};
 
 use crate::responses::listing::GenericListing;
-pub use serde::Deserialize;
+use reqwest::Url;
+pub use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
 /// The response from an add friend request
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 pub struct Friend {
     /// Was the friend request a success
     pub success: bool,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 pub struct Moderator {
     pub name: String,
     pub author_flair_text: Option<String>,
@@ -24,7 +28,7 @@ pub struct Moderator {
 
 pub type Moderators = GenericListing<Moderator>;
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 pub struct Contributor {
     pub name: String,
     pub id: Option<String>,
@@ -34,7 +38,7 @@ pub struct Contributor {
 
 pub type Contributors = GenericListing<Contributor>;
 
-#[derive(Deserialize, Clone)]
+#[derive(Deserialize, Serialize, Clone, PartialEq)]
 pub struct AboutSubreddit {
     pub name: String,
     pub display_name: String,
@@ -46,6 +50,21 @@ pub struct AboutSubreddit {
     pub subscribers: u64,
     #[serde(default)]
     pub over18: bool,
+    #[serde(default)]
+    pub subreddit_type: Option<SubredditType>,
+    /// `any`, `link` or `self`. The kinds of submissions allowed
+    #[serde(default)]
+    pub submission_type: Option<String>,
+    /// The relationship of the logged in user. None for anonymous requests
+    #[serde(default)]
+    pub user_is_banned: Option<bool>,
+    /// Approved to submit to restricted and private subreddits
+    #[serde(default)]
+    pub user_is_contributor: Option<bool>,
+    #[serde(default)]
+    pub user_is_moderator: Option<bool>,
+    #[serde(default)]
+    pub user_is_subscriber: Option<bool>,
     #[serde(flatten)]
     pub other: HashMap<String, Value>,
 }
@@ -62,5 +81,402 @@ impl Debug for AboutSubreddit {
     }
 }
 
+/// The subreddit Reddit embeds in listing items when [sr_detail](crate::utils::options::FeedOption::sr_detail) is set.
+///
+/// Has fewer fields than [AboutSubreddit] and some are named differently. Such as `over_18` instead of `over18`
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct SubredditCompact {
+    /// The fullname. `t5_{id}`
+    pub name: String,
+    pub display_name: String,
+    /// Such as `r/rust`
+    #[serde(default)]
+    pub display_name_prefixed: String,
+    /// Such as `/r/rust/`
+    pub url: String,
+    #[serde(default)]
+    pub title: String,
+    #[serde(default)]
+    pub public_description: String,
+    #[serde(default)]
+    pub subscribers: u64,
+    #[serde(default)]
+    pub over_18: bool,
+    #[serde(default)]
+    pub quarantine: bool,
+    #[serde(default)]
+    pub subreddit_type: Option<SubredditType>,
+    /// Empty if the subreddit has no icon
+    #[serde(default)]
+    pub icon_img: Option<String>,
+    #[serde(default)]
+    pub community_icon: Option<String>,
+    #[serde(default)]
+    pub primary_color: Option<String>,
+    #[serde(default)]
+    pub key_color: Option<String>,
+    /// The relationship of the logged in user. None for anonymous requests
+    #[serde(default)]
+    pub user_is_banned: Option<bool>,
+    #[serde(default)]
+    pub user_is_contributor: Option<bool>,
+    #[serde(default)]
+    pub user_is_moderator: Option<bool>,
+    #[serde(default)]
+    pub user_is_subscriber: Option<bool>,
+    #[serde(flatten)]
+    pub other: HashMap<String, Value>,
+}
+
 pub type SubredditResponse = GenericResponse<AboutSubreddit>;
 pub type Subreddits = GenericListing<AboutSubreddit>;
+
+/// The text shown above the submit form. From `/r/{name}/api/submit_text.json`
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Default)]
+pub struct SubmitText {
+    #[serde(default)]
+    pub submit_text: String,
+    pub submit_text_html: Option<String>,
+}
+
+/// A rule of the subreddit
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct Rule {
+    /// `link`, `comment` or `all`
+    pub kind: String,
+    pub short_name: String,
+    #[serde(default)]
+    pub description: String,
+    pub description_html: Option<String>,
+    /// The reason shown when reporting. Defaults to the short name
+    pub violation_reason: Option<String>,
+    pub created_utc: f64,
+    #[serde(default)]
+    pub priority: u32,
+}
+
+/// The response of `/r/{name}/about/rules`
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Default)]
+pub struct Rules {
+    #[serde(default)]
+    pub rules: Vec<Rule>,
+    /// The Reddit wide rules offered when reporting
+    #[serde(default)]
+    pub site_rules: Vec<String>,
+}
+
+/// The limits checked by Reddit when submitting. From `/api/v1/{name}/post_requirements`
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Default)]
+pub struct PostRequirements {
+    pub title_text_min_length: Option<usize>,
+    pub title_text_max_length: Option<usize>,
+    /// At least one must be in the title
+    #[serde(default)]
+    pub title_required_strings: Vec<String>,
+    #[serde(default)]
+    pub title_blacklisted_strings: Vec<String>,
+    #[serde(default)]
+    pub is_flair_required: bool,
+    /// Link posts must be to one of these domains. Empty if every domain is allowed
+    #[serde(default)]
+    pub domain_whitelist: Vec<String>,
+    #[serde(default)]
+    pub domain_blacklist: Vec<String>,
+    /// `none`, `required` or `notAllowed`
+    pub body_restriction_policy: Option<String>,
+    pub body_text_min_length: Option<usize>,
+    pub body_text_max_length: Option<usize>,
+    pub guidelines_text: Option<String>,
+    #[serde(flatten)]
+    pub other: HashMap<String, Value>,
+}
+
+/// A link flair the user can pick when submitting. From `/r/{name}/api/link_flair_v2`
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct FlairTemplate {
+    /// Sent as the `flair_id` of a submission
+    pub id: String,
+    #[serde(default)]
+    pub text: String,
+    /// The user can change the text
+    #[serde(default)]
+    pub text_editable: bool,
+    /// Only moderators can pick it
+    #[serde(default)]
+    pub mod_only: bool,
+    pub background_color: Option<String>,
+    pub text_color: Option<String>,
+    pub css_class: Option<String>,
+    #[serde(flatten)]
+    pub other: HashMap<String, Value>,
+}
+
+/// A part of a [PostingContext]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PostingSection {
+    About,
+    Rules,
+    Requirements,
+    Flair,
+}
+
+/// The subreddit and the logged in user's relationship to it. Loaded with [Subreddit::posting_context](crate::subreddit::Subreddit::posting_context)
+///
+/// A section that could not be loaded is None and its error is kept in `errors`. Such as the rules of a private subreddit
+#[derive(Debug)]
+pub struct PostingContext {
+    pub about: Option<AboutSubreddit>,
+    pub rules: Option<Vec<Rule>>,
+    pub requirements: Option<PostRequirements>,
+    /// The link flair the user can pick
+    pub flair: Option<Vec<FlairTemplate>>,
+    pub errors: Vec<(PostingSection, Error)>,
+}
+
+impl PostingContext {
+    /// Why the section could not be loaded
+    pub fn error(&self, section: PostingSection) -> Option<&Error> {
+        self.errors
+            .iter()
+            .find(|(failed, _)| *failed == section)
+            .map(|(_, error)| error)
+    }
+    /// Can the user submit at all. Checks the ban, the type of the subreddit and the approval of the user.
+    ///
+    /// False if the about could not be loaded. Karma and account age limits are not exposed by Reddit and not checked
+    pub fn can_submit(&self) -> bool {
+        let about = match &self.about {
+            Some(about) => about,
+            None => return false,
+        };
+        if about.user_is_banned == Some(true) {
+            return false;
+        }
+        let approved =
+            about.user_is_contributor == Some(true) || about.user_is_moderator == Some(true);
+        match &about.subreddit_type {
+            Some(SubredditType::Archived) => false,
+            Some(
+                SubredditType::Private
+                | SubredditType::Restricted
+                | SubredditType::EmployeesOnly
+                | SubredditType::GoldRestricted
+                | SubredditType::User,
+            ) => approved,
+            _ => true,
+        }
+    }
+    /// Can the user submit links
+    pub fn can_submit_link(&self) -> bool {
+        self.can_submit() && self.submission_type() != Some("self")
+    }
+    /// Can the user submit self posts
+    pub fn can_submit_self(&self) -> bool {
+        self.can_submit() && self.submission_type() != Some("link")
+    }
+    /// Submissions must have a flair. False if the requirements could not be loaded
+    pub fn flair_required(&self) -> bool {
+        self.requirements
+            .as_ref()
+            .map_or(false, |requirements| requirements.is_flair_required)
+    }
+    fn submission_type(&self) -> Option<&str> {
+        self.about.as_ref()?.submission_type.as_deref()
+    }
+}
+
+/// Everything a subreddit asks of a submission. Loaded with [Subreddit::submit_requirements](crate::subreddit::Subreddit::submit_requirements)
+#[derive(Debug, Clone, PartialEq)]
+pub struct SubmitRequirements {
+    pub submit_text: SubmitText,
+    pub rules: Vec<Rule>,
+    pub requirements: PostRequirements,
+}
+
+impl SubmitRequirements {
+    /// Checks the submission against the [PostRequirements].
+    ///
+    /// Returns [Error::InvalidOption] describing the first requirement that is not met.
+    /// Reddit may still reject the submission for rules it does not expose
+    pub fn check(&self, submit: &Submit) -> Result<(), Error> {
+        match self.requirements.validate(submit).into_iter().next() {
+            Some(violation) => Err(Error::InvalidOption(violation.to_string())),
+            None => Ok(()),
+        }
+    }
+}
+
+/// A [PostRequirements] that a submission does not meet
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RequirementViolation {
+    TitleTooShort {
+        min: usize,
+    },
+    TitleTooLong {
+        max: usize,
+    },
+    /// The title does not contain any of the required strings
+    TitleMissingRequired {
+        required: Vec<String>,
+    },
+    TitleBlacklisted {
+        blacklisted: String,
+    },
+    FlairRequired,
+    /// The link is not to one of the whitelisted domains
+    DomainNotWhitelisted {
+        host: String,
+        whitelist: Vec<String>,
+    },
+    DomainBlacklisted {
+        host: String,
+    },
+    BodyRequired,
+    BodyNotAllowed,
+    BodyTooShort {
+        min: usize,
+    },
+    BodyTooLong {
+        max: usize,
+    },
+}
+
+impl Display for RequirementViolation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RequirementViolation::TitleTooShort { min } => {
+                write!(f, "the title must be at least {min} characters")
+            }
+            RequirementViolation::TitleTooLong { max } => {
+                write!(f, "the title must be at most {max} characters")
+            }
+            RequirementViolation::TitleMissingRequired { required } => {
+                write!(f, "the title must contain one of {required:?}")
+            }
+            RequirementViolation::TitleBlacklisted { blacklisted } => {
+                write!(f, "the title can not contain {blacklisted:?}")
+            }
+            RequirementViolation::FlairRequired => write!(f, "a flair is required"),
+            RequirementViolation::DomainNotWhitelisted { whitelist, .. } => {
+                write!(f, "links must be to one of {whitelist:?}")
+            }
+            RequirementViolation::DomainBlacklisted { host } => {
+                write!(f, "links to {host} are not allowed")
+            }
+            RequirementViolation::BodyRequired => write!(f, "a body is required"),
+            RequirementViolation::BodyNotAllowed => write!(f, "a body is not allowed"),
+            RequirementViolation::BodyTooShort { min } => {
+                write!(f, "the body must be at least {min} characters")
+            }
+            RequirementViolation::BodyTooLong { max } => {
+                write!(f, "the body must be at most {max} characters")
+            }
+        }
+    }
+}
+
+impl PostRequirements {
+    /// Checks the submission locally. Returns every requirement that is not met, empty if there are none.
+    ///
+    /// Reddit may still reject the submission for rules it does not expose
+    pub fn validate(&self, submit: &Submit) -> Vec<RequirementViolation> {
+        let mut violations = Vec::new();
+        let title = submit.title.to_lowercase();
+        let length = submit.title.chars().count();
+        if let Some(min) = self.title_text_min_length {
+            if length < min {
+                violations.push(RequirementViolation::TitleTooShort { min });
+            }
+        }
+        if let Some(max) = self.title_text_max_length {
+            if length > max {
+                violations.push(RequirementViolation::TitleTooLong { max });
+            }
+        }
+        if !self.title_required_strings.is_empty()
+            && !self
+                .title_required_strings
+                .iter()
+                .any(|required| title.contains(&required.to_lowercase()))
+        {
+            violations.push(RequirementViolation::TitleMissingRequired {
+                required: self.title_required_strings.clone(),
+            });
+        }
+        for blacklisted in &self.title_blacklisted_strings {
+            if title.contains(&blacklisted.to_lowercase()) {
+                violations.push(RequirementViolation::TitleBlacklisted {
+                    blacklisted: blacklisted.clone(),
+                });
+            }
+        }
+        if self.is_flair_required && submit.flair_id.is_none() {
+            violations.push(RequirementViolation::FlairRequired);
+        }
+        match &submit.kind {
+            SubmitKind::Link { url } => self.validate_domain(url, &mut violations),
+            SubmitKind::SelfPost { text } => self.validate_body(text, &mut violations),
+            SubmitKind::Crosspost { .. } => {}
+        }
+        violations
+    }
+    fn validate_domain(&self, url: &str, violations: &mut Vec<RequirementViolation>) {
+        let host = Url::parse(url)
+            .ok()
+            .and_then(|url| url.host_str().map(|host| host.to_lowercase()))
+            .unwrap_or_default();
+        let matches = |domain: &String| {
+            let domain = domain.to_lowercase();
+            host == domain || host.ends_with(&format!(".{domain}"))
+        };
+        if !self.domain_whitelist.is_empty() && !self.domain_whitelist.iter().any(matches) {
+            violations.push(RequirementViolation::DomainNotWhitelisted {
+                host: host.clone(),
+                whitelist: self.domain_whitelist.clone(),
+            });
+        }
+        if self.domain_blacklist.iter().any(matches) {
+            violations.push(RequirementViolation::DomainBlacklisted { host });
+        }
+    }
+    fn validate_body(&self, text: &str, violations: &mut Vec<RequirementViolation>) {
+        match self.body_restriction_policy.as_deref() {
+            Some("required") if text.trim().is_empty() => {
+                violations.push(RequirementViolation::BodyRequired)
+            }
+            Some("notAllowed") if !text.trim().is_empty() => {
+                violations.push(RequirementViolation::BodyNotAllowed)
+            }
+            _ => {}
+        }
+        let length = text.chars().count();
+        if let Some(min) = self.body_text_min_length {
+            if length < min {
+                violations.push(RequirementViolation::BodyTooShort { min });
+            }
+        }
+        if let Some(max) = self.body_text_max_length {
+            if length > max {
+                violations.push(RequirementViolation::BodyTooLong { max });
+            }
+        }
+    }
+}
+
+/// The subreddits trending today. From `/api/trending_subreddits.json`
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq, Default)]
+pub struct TrendingSubreddits {
+    pub subreddit_names: Vec<String>,
+    /// Comments on the daily trending post
+    #[serde(default)]
+    pub comment_count: u64,
+    /// The permalink of the daily trending post
+    #[serde(default)]
+    pub comment_url: String,
+}
+
+/// A subreddit from `/api/recommend/sr/{names}`
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+pub struct RecommendedSubreddit {
+    pub sr_name: String,
+}
@@ -0,0 +1,283 @@
+//! The new Reddit widgets of a subreddit. From `/r/{name}/api/widgets`
+use std::collections::{BTreeMap, HashMap};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Every widget of a subreddit and where they are shown
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct Widgets {
+    /// The widgets by id
+    pub items: BTreeMap<String, Widget>,
+    pub layout: WidgetLayout,
+}
+
+impl Widgets {
+    /// The widget with the id
+    pub fn get(&self, id: &str) -> Option<&Widget> {
+        self.items.get(id)
+    }
+    /// The widgets of the sidebar in the order they are shown
+    pub fn sidebar(&self) -> impl Iterator<Item = &Widget> {
+        self.ordered(&self.layout.sidebar)
+    }
+    /// The widgets of the menu bar in the order they are shown
+    pub fn topbar(&self) -> impl Iterator<Item = &Widget> {
+        self.ordered(&self.layout.topbar)
+    }
+    /// The moderators widget. Shown below the sidebar
+    pub fn moderators(&self) -> Option<&ModeratorsWidget> {
+        match self.get(self.layout.moderator_widget.as_deref()?)? {
+            Widget::Moderators(widget) => Some(widget),
+            _ => None,
+        }
+    }
+    fn ordered<'a>(&'a self, section: &'a WidgetSection) -> impl Iterator<Item = &'a Widget> {
+        section.order.iter().filter_map(move |id| self.get(id))
+    }
+}
+
+/// The ids of the widgets in each part of the page
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct WidgetLayout {
+    #[serde(default)]
+    pub id_card_widget: Option<String>,
+    #[serde(default)]
+    pub moderator_widget: Option<String>,
+    #[serde(default)]
+    pub sidebar: WidgetSection,
+    #[serde(default)]
+    pub topbar: WidgetSection,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Default)]
+pub struct WidgetSection {
+    /// Widget ids. The first is shown first
+    #[serde(default)]
+    pub order: Vec<String>,
+}
+
+/// A widget. Kinds without a variant are kept as [Widget::Other]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(tag = "kind")]
+pub enum Widget {
+    #[serde(rename = "textarea")]
+    TextArea(TextAreaWidget),
+    #[serde(rename = "button")]
+    Button(ButtonWidget),
+    #[serde(rename = "calendar")]
+    Calendar(CalendarWidget),
+    #[serde(rename = "community-list")]
+    CommunityList(CommunityListWidget),
+    #[serde(rename = "subreddit-rules")]
+    Rules(RulesWidget),
+    #[serde(rename = "moderators")]
+    Moderators(ModeratorsWidget),
+    #[serde(rename = "image")]
+    Image(ImageWidget),
+    /// Such as `id-card` or `menu`. The whole widget including the `kind`
+    #[serde(untagged)]
+    Other(Value),
+}
+
+impl Widget {
+    /// The `kind` Reddit sent
+    pub fn kind(&self) -> &str {
+        match self {
+            Widget::TextArea(_) => "textarea",
+            Widget::Button(_) => "button",
+            Widget::Calendar(_) => "calendar",
+            Widget::CommunityList(_) => "community-list",
+            Widget::Rules(_) => "subreddit-rules",
+            Widget::Moderators(_) => "moderators",
+            Widget::Image(_) => "image",
+            Widget::Other(value) => value["kind"].as_str().unwrap_or_default(),
+        }
+    }
+    /// The title shown above the widget. None for widgets without one
+    pub fn short_name(&self) -> Option<&str> {
+        let short_name = match self {
+            Widget::TextArea(widget) => &widget.short_name,
+            Widget::Button(widget) => &widget.short_name,
+            Widget::Calendar(widget) => &widget.short_name,
+            Widget::CommunityList(widget) => &widget.short_name,
+            Widget::Rules(widget) => &widget.short_name,
+            Widget::Image(widget) => &widget.short_name,
+            Widget::Moderators(_) => return None,
+            Widget::Other(value) => return value["shortName"].as_str(),
+        };
+        Some(short_name)
+    }
+}
+
+/// The colors of a widget
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct WidgetStyles {
+    #[serde(default)]
+    pub background_color: Option<String>,
+    #[serde(default)]
+    pub header_color: Option<String>,
+}
+
+/// Markdown text
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TextAreaWidget {
+    pub id: String,
+    pub short_name: String,
+    /// The markdown
+    pub text: String,
+    #[serde(default)]
+    pub text_html: Option<String>,
+    #[serde(default)]
+    pub styles: Option<WidgetStyles>,
+}
+
+/// The rules of the subreddit
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct RulesWidget {
+    pub id: String,
+    pub short_name: String,
+    /// `full` shows the descriptions. `compact` only the short names
+    #[serde(default)]
+    pub display: Option<String>,
+    #[serde(rename = "data", default)]
+    pub rules: Vec<WidgetRule>,
+    #[serde(default)]
+    pub styles: Option<WidgetStyles>,
+}
+
+/// A rule of a [RulesWidget]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct WidgetRule {
+    pub short_name: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub description_html: Option<String>,
+    /// The reason shown when reporting
+    #[serde(default)]
+    pub violation_reason: Option<String>,
+    #[serde(default)]
+    pub created_utc: Option<f64>,
+    #[serde(default)]
+    pub priority: u32,
+}
+
+/// Links shown as buttons
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ButtonWidget {
+    pub id: String,
+    pub short_name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub buttons: Vec<WidgetButton>,
+    #[serde(flatten)]
+    pub other: HashMap<String, Value>,
+}
+
+/// A button of a [ButtonWidget]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct WidgetButton {
+    /// `text` or `image`
+    pub kind: String,
+    #[serde(default)]
+    pub text: Option<String>,
+    #[serde(default)]
+    pub url: Option<String>,
+    #[serde(flatten)]
+    pub other: HashMap<String, Value>,
+}
+
+/// Upcoming events of a Google Calendar
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CalendarWidget {
+    pub id: String,
+    pub short_name: String,
+    #[serde(default)]
+    pub google_calendar_id: Option<String>,
+    /// The events. Only sent when the calendar could be loaded
+    #[serde(rename = "data", default)]
+    pub events: Vec<Value>,
+    #[serde(flatten)]
+    pub other: HashMap<String, Value>,
+}
+
+/// A list of related subreddits
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CommunityListWidget {
+    pub id: String,
+    pub short_name: String,
+    #[serde(rename = "data", default)]
+    pub communities: Vec<WidgetCommunity>,
+    #[serde(flatten)]
+    pub other: HashMap<String, Value>,
+}
+
+/// A subreddit of a [CommunityListWidget]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct WidgetCommunity {
+    /// Without the `r/`
+    pub name: String,
+    #[serde(default)]
+    pub subscribers: u64,
+    #[serde(rename = "isNSFW", default)]
+    pub is_nsfw: bool,
+    #[serde(flatten)]
+    pub other: HashMap<String, Value>,
+}
+
+/// The moderators of the subreddit
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ModeratorsWidget {
+    #[serde(default)]
+    pub id: Option<String>,
+    #[serde(default)]
+    pub mods: Vec<WidgetModerator>,
+    /// Can be more than the moderators listed
+    #[serde(default)]
+    pub total_mods: u64,
+}
+
+/// A moderator of a [ModeratorsWidget]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct WidgetModerator {
+    pub name: String,
+    #[serde(default)]
+    pub author_flair_text: Option<String>,
+}
+
+/// One or more images
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ImageWidget {
+    pub id: String,
+    pub short_name: String,
+    #[serde(rename = "data", default)]
+    pub images: Vec<WidgetImage>,
+}
+
+/// An image of an [ImageWidget]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct WidgetImage {
+    pub url: String,
+    #[serde(default)]
+    pub width: u32,
+    #[serde(default)]
+    pub height: u32,
+    /// Where the image links to
+    #[serde(default)]
+    pub link_url: Option<String>,
+}
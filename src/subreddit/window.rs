@@ -0,0 +1,91 @@
+//! Walks a time range of a subreddit one search window at a time. Listings stop at ~1000 items, searches by time do not
+use std::collections::HashSet;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use log::trace;
+
+use crate::responses::GenericResponse;
+use crate::submission::response::SubmissionResponse;
+
+/// Seconds since the Unix Epoch. Times before it are 0
+pub(crate) fn unix_seconds(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default()
+}
+
+/// The part of `start..=end` that has not been loaded yet. Searches are sorted by new, so `end` moves back after every full page
+pub(crate) struct SearchWindow {
+    start: u64,
+    end: u64,
+    /// The ids created in the second `end` that were already returned. A full page can stop part way through a second
+    seen: HashSet<String>,
+    done: bool,
+}
+
+impl SearchWindow {
+    pub fn new(start: u64, end: u64) -> SearchWindow {
+        SearchWindow {
+            start,
+            end,
+            seen: HashSet::new(),
+            done: start > end,
+        }
+    }
+    pub fn is_done(&self) -> bool {
+        self.done
+    }
+    /// The cloudsearch query matching the window
+    pub fn query(&self) -> String {
+        format!("timestamp:{}..{}", self.start, self.end)
+    }
+    /// Keeps the submissions inside the window that were not returned before and moves the window back.
+    ///
+    /// `full` is true if Reddit returned as many submissions as were asked for. Older submissions may be left
+    pub fn advance(
+        &mut self,
+        page: Vec<GenericResponse<SubmissionResponse>>,
+        full: bool,
+    ) -> Vec<GenericResponse<SubmissionResponse>> {
+        let oldest = page.iter().map(|child| child.data.created_utc as u64).min();
+        let kept: Vec<_> = page
+            .into_iter()
+            .filter(|child| {
+                let created = child.data.created_utc as u64;
+                created >= self.start && created <= self.end && !self.seen.contains(&child.data.id)
+            })
+            .collect();
+        match oldest {
+            Some(oldest) if full && oldest < self.end => {
+                self.seen = kept
+                    .iter()
+                    .filter(|child| child.data.created_utc as u64 == oldest)
+                    .map(|child| child.data.id.clone())
+                    .collect();
+                self.end = oldest;
+            }
+            Some(oldest) if full && oldest == self.end => {
+                // A full page from a single second. The rest of that second can not be reached
+                trace!("Skipping the rest of {} in the search window", oldest);
+                self.seen.clear();
+                match oldest.checked_sub(1) {
+                    Some(end) => self.end = end,
+                    None => self.done = true,
+                }
+            }
+            Some(oldest) if full => {
+                // Newer than the window. The timestamp syntax was ignored and the same page would come back
+                trace!(
+                    "Search ignored the window. Oldest submission was {}",
+                    oldest
+                );
+                self.done = true;
+            }
+            _ => self.done = true,
+        }
+        if self.end < self.start {
+            self.done = true;
+        }
+        kept
+    }
+}
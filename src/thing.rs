@@ -0,0 +1,87 @@
+//! Actions shared by everything with a fullname. Implemented for the bound submissions, comments and inbox items
+//! and for their handles, so utilities can be written once
+//! ```no_run
+//! use rraw::error::Error;
+//! use rraw::thing::{Savable, Votable};
+//!
+//! async fn archive<T: Savable + Votable>(thing: &T) -> Result<(), Error> {
+//!     thing.upvote().await?;
+//!     thing.save().await
+//! }
+//! ```
+//! The traits are sealed. They can not be implemented outside of RRAW, so methods can be added without breaking changes
+use async_trait::async_trait;
+
+use crate::comments::response::CommentResponse;
+use crate::error::Error;
+use crate::utils::options::VoteDirection;
+
+pub(crate) mod private {
+    use crate::auth::Authorized;
+    use crate::Client;
+
+    pub trait Sealed {
+        type Auth: Authorized;
+        fn client(&self) -> &Client<Self::Auth>;
+        /// The fullname sent to Reddit. Such as `t3_15bfi0`
+        fn thing_fullname(&self) -> String;
+    }
+}
+
+/// Submissions and comments
+#[async_trait(?Send)]
+pub trait Votable: private::Sealed {
+    async fn vote(&self, direction: VoteDirection) -> Result<(), Error> {
+        self.client().vote(&self.thing_fullname(), direction).await
+    }
+    async fn upvote(&self) -> Result<(), Error> {
+        self.vote(VoteDirection::Up).await
+    }
+    async fn downvote(&self) -> Result<(), Error> {
+        self.vote(VoteDirection::Down).await
+    }
+}
+
+/// Submissions and comments
+#[async_trait(?Send)]
+pub trait Savable: private::Sealed {
+    async fn save(&self) -> Result<(), Error> {
+        self.client()
+            .thing_action("/api/save", &self.thing_fullname())
+            .await
+    }
+    async fn unsave(&self) -> Result<(), Error> {
+        self.client()
+            .thing_action("/api/unsave", &self.thing_fullname())
+            .await
+    }
+}
+
+/// Submissions, comments and inbox items
+#[async_trait(?Send)]
+pub trait Reportable: private::Sealed {
+    /// Reports it to the moderators of the subreddit. `reason` is shown to them
+    async fn report(&self, reason: &str) -> Result<(), Error> {
+        self.client().report(&self.thing_fullname(), reason).await
+    }
+}
+
+/// Submissions, comments and comment replies in the inbox
+#[async_trait(?Send)]
+pub trait Replyable: private::Sealed {
+    /// Comments on it. Returns the new comment
+    async fn reply(&self, text: &str) -> Result<CommentResponse, Error> {
+        self.client().reply(&self.thing_fullname(), text).await
+    }
+}
+
+/// Your own submissions and comments and private messages in the inbox
+#[async_trait(?Send)]
+pub trait Deletable: private::Sealed {
+    /// Deletes it. Submissions and comments can only be deleted by their author
+    async fn delete(&self) -> Result<(), Error> {
+        self.client()
+            .thing_action("/api/del", &self.thing_fullname())
+            .await
+    }
+}
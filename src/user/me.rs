@@ -1,33 +1,221 @@
-use crate::auth::Authorized;
+use crate::access::Access;
+use crate::auth::{Authorized, ScopeDescriptions};
+use crate::collection::Collections;
+use crate::comments::handle::CommentHandle;
 use crate::comments::response::CommentsResponse;
+use crate::error::http_error::HTTPError;
+use crate::error::url_error::RedditUrlError;
 use crate::error::Error;
+use crate::message::inbox::Inbox;
 use crate::message::response::MessageListing;
 use crate::message::WhereMessage;
+use crate::modmail::Modmail;
+use crate::responses::api::JsonResponse;
 use crate::responses::FullName;
+use crate::utils::form_body;
+use crate::utils::fullname::{Fullname, FullnameKind};
+use crate::utils::reddit_url::{RedditUrl, UrlTarget};
 use crate::Client;
 use serde_json::Value;
 
-use crate::responses::listing::RedditListing;
-use crate::submission::response::SubmissionsResponse;
-use crate::subreddit::response::Friend;
+use crate::responses::listing::MixedListing;
+use crate::submission::handle::SubmissionHandle;
+use crate::submission::response::{SubmissionResponse, SubmissionsResponse};
+use crate::submission::submit::{Submit, SubmitResponse};
+use crate::subreddit::response::{Friend, Subreddits};
 use crate::user::response::MeResponse;
 
-use crate::utils::options::FeedOption;
+use crate::utils::options::{FeedOption, ThingType};
 
 /// The User Object for Reddit
 pub struct Me<'a, A: Authorized> {
     pub(crate) client: &'a Client<A>,
     pub me: MeResponse,
+    pub(crate) default_feed: Option<FeedOption>,
 }
 
 impl<'a, A: Authorized> Me<'a, A> {
+    /// Sets the [FeedOption] used by the listing methods of Me.
+    ///
+    /// Passing `None` to a method uses the default as is.
+    /// Passing an option uses its fields and falls back to the default for the ones not set. See [FeedOption::merge]
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() ->anyhow::Result<()>{
+    ///    use std::env;
+    ///    use rraw::auth::PasswordAuthenticator;
+    ///    use rraw::utils::options::FeedOption;
+    ///    use rraw::Client;
+    ///    let client = Client::login(PasswordAuthenticator::new(env::var("CLIENT_ID")?,env::var("CLIENT_SECRET")?,env::var("USERNAME")?,env::var("PASSWORD")?), "RRAW Test (by u/KingTuxWH)").await?;
+    ///    let me = client
+    ///        .me()
+    ///        .await?
+    ///        .with_default_feed_option(FeedOption::builder().limit(FeedOption::MAX_LIMIT).build()?);
+    ///    let saved = me.saved(None, None).await?;
+    ///    Ok(())
+    /// }
+    /// ```
+    pub fn with_default_feed_option(mut self, feed: FeedOption) -> Self {
+        self.default_feed = Some(feed);
+        self
+    }
+    /// Merges the per call option with the default
+    fn feed(&self, feed: Option<FeedOption>) -> Option<FeedOption> {
+        match (feed, &self.default_feed) {
+            (Some(feed), Some(defaults)) => Some(feed.merge(defaults)),
+            (None, defaults) => defaults.clone(),
+            (feed, None) => feed,
+        }
+    }
     /// For blocking the author of a thing via inbox. - Reddit API
     pub async fn block_author(&self, full_name: FullName) -> Result<Friend, Error> {
         let body = reqwest::Body::from(format!("id={}", full_name));
         self.client
-            .post_json::<Friend>("/api/block", true, body)
+            .post_json::<Friend>("/api/block", Access::OAuthOnly, body)
+            .await
+    }
+    /// Hides multiple submissions at once. Takes the fullnames of the submissions
+    pub async fn hide_all(&self, fullnames: &[&str]) -> Result<(), Error> {
+        self.client.hide_things("/api/hide", fullnames).await
+    }
+    /// Unhides multiple submissions at once. Takes the fullnames of the submissions
+    pub async fn unhide_all(&self, fullnames: &[&str]) -> Result<(), Error> {
+        self.client.hide_things("/api/unhide", fullnames).await
+    }
+    /// Submits a new post. Returns the submission as Reddit now has it
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() ->anyhow::Result<()>{
+    ///    use std::env;
+    ///    use rraw::auth::PasswordAuthenticator;
+    ///    use rraw::submission::submit::{SubmitBuilder, SubmitKind};
+    ///    use rraw::Client;
+    ///    let client = Client::login(PasswordAuthenticator::new(env::var("CLIENT_ID")?,env::var("CLIENT_SECRET")?,env::var("USERNAME")?,env::var("PASSWORD")?), "RRAW Test (by u/KingTuxWH)").await?;
+    ///    let me = client.me().await?;
+    ///    let submit = SubmitBuilder::new("rraw", "Hello")
+    ///        .kind(SubmitKind::Link { url: "https://github.com/wyatt-herkamp/rraw".to_string() })
+    ///        .build()?;
+    ///    let submission = me.submit(submit).await?;
+    ///    println!("{}", submission.permalink);
+    ///    Ok(())
+    /// }
+    /// ```
+    pub async fn submit(&self, submit: Submit) -> Result<SubmissionResponse, Error> {
+        let response: JsonResponse<SubmitResponse> = self
+            .client
+            .post_json("/api/submit", Access::OAuthOnly, form_body(&submit.form()))
+            .await?;
+        let created = response
+            .into_result()?
+            .ok_or_else(|| Error::from("Reddit did not return the new submission"))?;
+        self.client
+            .info_single(&created.name)
+            .await?
+            .ok_or_else(|| HTTPError::NotFound.into())
+    }
+    /// Does Reddit require a captcha for this account to submit or compose. Usually true for low karma accounts
+    pub async fn needs_captcha(&self) -> Result<bool, Error> {
+        self.client
+            .get_json::<bool>("/api/needs_captcha", Access::OAuthOnly)
+            .await
+    }
+    /// Every OAuth scope with the name and description Reddit shows when authorizing an app
+    pub async fn oauth_scopes(&self) -> Result<ScopeDescriptions, Error> {
+        self.client
+            .get_json::<ScopeDescriptions>("/api/v1/scopes", Access::Any)
             .await
     }
+    /// A submission from its id or fullname. Such as `15bfi0` or `t3_15bfi0`.
+    ///
+    /// No request is made. Anything that is not a submission id is [Error::InvalidOption]
+    pub fn submission(&self, id: &str) -> Result<SubmissionHandle<'a, A>, Error> {
+        Ok(SubmissionHandle {
+            client: self.client,
+            fullname: Self::fullname(FullnameKind::Link, id)?,
+        })
+    }
+    /// A comment from its id or fullname. Such as `jtq1a2b` or `t1_jtq1a2b`.
+    ///
+    /// No request is made. Anything that is not a comment id is [Error::InvalidOption]
+    pub fn comment(&self, id: &str) -> Result<CommentHandle<'a, A>, Error> {
+        Ok(CommentHandle {
+            client: self.client,
+            fullname: Self::fullname(FullnameKind::Comment, id)?,
+        })
+    }
+    fn fullname(kind: FullnameKind, id: &str) -> Result<Fullname, Error> {
+        Fullname::parse_as(kind, id)
+            .ok_or_else(|| Error::InvalidOption(format!("{id:?} is not a {kind} id")))
+    }
+    /// The handle for a link pasted by a user. See [RedditUrl] for the links understood.
+    ///
+    /// Share links are requested to find where they redirect to. Subreddits and users are loaded.
+    /// Links that are not to Reddit or are not understood are [Error::Url] without a request
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() ->anyhow::Result<()>{
+    ///    use std::env;
+    ///    use rraw::auth::PasswordAuthenticator;
+    ///    use rraw::thing::Savable;
+    ///    use rraw::utils::reddit_url::UrlTarget;
+    ///    use rraw::Client;
+    ///    let client = Client::login(PasswordAuthenticator::new(env::var("CLIENT_ID")?,env::var("CLIENT_SECRET")?,env::var("USERNAME")?,env::var("PASSWORD")?), "RRAW Test (by u/KingTuxWH)").await?;
+    ///    let me = client.me().await?;
+    ///    if let UrlTarget::Submission(submission) = me.from_url("https://redd.it/15bfi0").await? {
+    ///        submission.save().await?;
+    ///    }
+    ///    Ok(())
+    /// }
+    /// ```
+    pub async fn from_url(&self, url: &str) -> Result<UrlTarget<'a, A>, Error> {
+        self.resolve_url(RedditUrl::parse(url)?).await
+    }
+    /// The handle for an already parsed link. See [Me::from_url]
+    pub async fn resolve_url(&self, url: RedditUrl) -> Result<UrlTarget<'a, A>, Error> {
+        let url = match url {
+            RedditUrl::Share(share) => {
+                let target = self.client.redirect_target(share).await?;
+                RedditUrl::parse(target.as_str())?
+            }
+            url => url,
+        };
+        let target = match url {
+            RedditUrl::Subreddit(name) => {
+                UrlTarget::Subreddit(Box::new(self.client.subreddit(name).await?))
+            }
+            RedditUrl::User(name) => UrlTarget::User(Box::new(self.client.user(name).await?)),
+            RedditUrl::Submission { id, .. } => UrlTarget::Submission(self.submission(&id)?),
+            RedditUrl::Comment { id, .. } => UrlTarget::Comment(self.comment(&id)?),
+            // A share link that redirects to another share link
+            RedditUrl::Share(share) => {
+                return Err(RedditUrlError::Unrecognized(share.to_string()).into())
+            }
+        };
+        Ok(target)
+    }
+    /// Forgets the token of the Client without revoking it. See [Client::invalidate_token]
+    #[cfg(feature = "shared_authentication")]
+    pub async fn invalidate_token(&self) {
+        self.client.invalidate_token().await;
+    }
+    /// Collections of the subreddits you moderate
+    pub fn collections(&self) -> Collections<'a, A> {
+        Collections {
+            client: self.client,
+        }
+    }
+    /// New Modmail for the subreddits you moderate
+    pub fn modmail(&self) -> Modmail<'a, A> {
+        Modmail {
+            client: self.client,
+        }
+    }
+    /// The Inbox of the user
+    pub fn inbox(&self) -> Inbox<'a, A> {
+        Inbox {
+            client: self.client,
+        }
+    }
     /// Gets the Messages. Default for where_message is Inbox
     pub async fn get_messages(
         &self,
@@ -35,11 +223,11 @@ impl<'a, A: Authorized> Me<'a, A> {
         feed: Option<FeedOption>,
     ) -> Result<MessageListing, Error> {
         let mut string = format!("/message/{}", where_message.unwrap_or(WhereMessage::Inbox));
-        if let Some(f) = feed {
+        if let Some(f) = self.feed(feed) {
             f.extend(&mut string);
         }
         self.client
-            .get_json::<MessageListing>(&string, true, false)
+            .get_json::<MessageListing>(&string, Access::OAuthOnly)
             .await
     }
     /// Composes a message.
@@ -52,19 +240,18 @@ impl<'a, A: Authorized> Me<'a, A> {
         let string = format!("api_type=json&subject={subject}&text={body}&to={recipient}");
         let body = reqwest::Body::from(string);
         self.client
-            .post_json::<Value>("/api/compose", true, body)
+            .post_json::<Value>("/api/compose", Access::OAuthOnly, body)
             .await
     }
 
     /// Comments
     pub async fn comments(&self, feed: Option<FeedOption>) -> Result<CommentsResponse, Error> {
         let mut string = format!("/user/{}/comments", &self.me.about.name);
-        if let Some(options) = feed {
-            string.push('?');
-            string.push_str(options.url().as_str());
+        if let Some(options) = self.feed(feed) {
+            options.extend(&mut string);
         }
         self.client
-            .get_json::<CommentsResponse>(&*string, false, false)
+            .get_json::<CommentsResponse>(&string, Access::Any)
             .await
     }
     /// user Submissions
@@ -73,54 +260,129 @@ impl<'a, A: Authorized> Me<'a, A> {
         feed: Option<FeedOption>,
     ) -> Result<SubmissionsResponse, Error> {
         let mut string = format!("/user/{}/submitted", &self.me.about.name);
-        if let Some(options) = feed {
-            string.push('?');
-            string.push_str(options.url().as_str());
+        if let Some(options) = self.feed(feed) {
+            options.extend(&mut string);
         }
         self.client
-            .get_json::<SubmissionsResponse>(&*string, false, false)
+            .get_json::<SubmissionsResponse>(&string, Access::Any)
             .await
     }
 
-    /// User Overview
-    pub async fn overview(&self, feed: Option<FeedOption>) -> Result<RedditListing, Error> {
-        let mut string = format!("/user/{}/overview", &self.me.about.name);
-        if let Some(options) = feed {
-            string.push('?');
-            string.push_str(options.url().as_str());
-        }
+    /// User Overview. `filter` limits it to only comments or submissions
+    pub async fn overview(
+        &self,
+        feed: Option<FeedOption>,
+        filter: Option<ThingType>,
+    ) -> Result<MixedListing, Error> {
+        let string = self.listing_path("overview", feed, filter);
         self.client
-            .get_json::<RedditListing>(&*string, false, false)
+            .get_json::<MixedListing>(&string, Access::Any)
             .await
     }
-    pub async fn saved(&self, feed: Option<FeedOption>) -> Result<RedditListing, Error> {
-        let mut string = format!("/user/{}/saved", &self.me.about.name);
-        if let Some(options) = feed {
-            string.push('?');
-            string.push_str(options.url().as_str());
-        }
+    /// Saved comments and submissions. `filter` limits it to only comments or submissions
+    pub async fn saved(
+        &self,
+        feed: Option<FeedOption>,
+        filter: Option<ThingType>,
+    ) -> Result<MixedListing, Error> {
+        let string = self.listing_path("saved", feed, filter);
+        self.client
+            .get_json::<MixedListing>(&string, Access::Any)
+            .await
+    }
+    /// Saved comments
+    pub async fn saved_comments(
+        &self,
+        feed: Option<FeedOption>,
+    ) -> Result<CommentsResponse, Error> {
+        let string = self.listing_path("saved", feed, Some(ThingType::Comments));
+        self.client
+            .get_json::<CommentsResponse>(&string, Access::Any)
+            .await
+    }
+    /// Saved submissions
+    pub async fn saved_submissions(
+        &self,
+        feed: Option<FeedOption>,
+    ) -> Result<SubmissionsResponse, Error> {
+        let string = self.listing_path("saved", feed, Some(ThingType::Links));
         self.client
-            .get_json::<RedditListing>(&*string, false, false)
+            .get_json::<SubmissionsResponse>(&string, Access::Any)
             .await
     }
-    pub async fn up_voted(&self, feed: Option<FeedOption>) -> Result<RedditListing, Error> {
+    fn listing_path(
+        &self,
+        listing: &str,
+        feed: Option<FeedOption>,
+        filter: Option<ThingType>,
+    ) -> String {
+        let mut string = format!("/user/{}/{listing}", &self.me.about.name);
+        if let Some(options) = self.feed(feed) {
+            options.extend(&mut string);
+        }
+        if let Some(filter) = filter {
+            filter.extend(&mut string);
+        }
+        string
+    }
+    pub async fn up_voted(&self, feed: Option<FeedOption>) -> Result<MixedListing, Error> {
         let mut string = format!("/user/{}/upvoted", &self.me.about.name);
-        if let Some(options) = feed {
-            string.push('?');
-            string.push_str(options.url().as_str());
+        if let Some(options) = self.feed(feed) {
+            options.extend(&mut string);
         }
         self.client
-            .get_json::<RedditListing>(&string, false, false)
+            .get_json::<MixedListing>(&string, Access::Any)
             .await
     }
-    pub async fn down_voted(&self, feed: Option<FeedOption>) -> Result<RedditListing, Error> {
+    pub async fn down_voted(&self, feed: Option<FeedOption>) -> Result<MixedListing, Error> {
         let mut string = format!("/user/{}/downvoted", &self.me.about.name);
-        if let Some(options) = feed {
-            string.push('?');
-            string.push_str(options.url().as_str());
+        if let Some(options) = self.feed(feed) {
+            options.extend(&mut string);
+        }
+        self.client
+            .get_json::<MixedListing>(&string, Access::Any)
+            .await
+    }
+
+    /// Subreddits you are subscribed to.
+    ///
+    /// Requires the `mysubreddits` scope. Reddit will respond with a 403([Error::Forbidden](crate::error::Error::Forbidden)) without it
+    pub async fn subscribed_subreddits(
+        &self,
+        feed: Option<FeedOption>,
+    ) -> Result<Subreddits, Error> {
+        self.mine("subscriber", feed).await
+    }
+    /// Subreddits you are a moderator of.
+    ///
+    /// Requires the `mysubreddits` scope. Reddit will respond with a 403([Error::Forbidden](crate::error::Error::Forbidden)) without it
+    pub async fn moderated_subreddits(
+        &self,
+        feed: Option<FeedOption>,
+    ) -> Result<Subreddits, Error> {
+        self.mine("moderator", feed).await
+    }
+    /// Subreddits you are an approved user of.
+    ///
+    /// Requires the `mysubreddits` scope. Reddit will respond with a 403([Error::Forbidden](crate::error::Error::Forbidden)) without it
+    pub async fn contributor_subreddits(
+        &self,
+        feed: Option<FeedOption>,
+    ) -> Result<Subreddits, Error> {
+        self.mine("contributor", feed).await
+    }
+
+    async fn mine(
+        &self,
+        relationship: &str,
+        feed: Option<FeedOption>,
+    ) -> Result<Subreddits, Error> {
+        let mut string = format!("/subreddits/mine/{relationship}.json");
+        if let Some(options) = self.feed(feed) {
+            options.extend(&mut string);
         }
         self.client
-            .get_json::<RedditListing>(&string, false, false)
+            .get_json::<Subreddits>(&string, Access::OAuthOnly)
             .await
     }
 }
@@ -1,21 +1,37 @@
 pub mod me;
 pub mod response;
 
+use crate::access::Access;
 use crate::auth::Authenticator;
 use crate::comments::response::CommentsResponse;
+use crate::error::http_error::HTTPError;
 use crate::error::Error;
-use crate::responses::listing::RedditListing;
+use crate::responses::listing::MixedListing;
+#[cfg(feature = "rss")]
+use crate::rss::RssItem;
 use crate::Client;
 
+#[cfg(feature = "stream")]
+use crate::paginator::Paginator;
+#[cfg(feature = "stream")]
+use crate::submission::response::SubmissionResponse;
 use crate::submission::response::SubmissionsResponse;
-use crate::user::response::AboutUser;
+use crate::user::response::{
+    AboutUser, ModeratedList, Multireddit, MultiredditResponse, TrophyList, UserProfile,
+    UserResponse,
+};
 
-use crate::utils::options::FeedOption;
+use crate::utils::fullname::FullnameKind;
+use crate::utils::options::{extend_query, FeedOption, ThingType, UserSort};
+use futures_util::join;
+use log::debug;
 
 /// The User Object for Reddit
 pub struct User<'a, A: Authenticator> {
     pub(crate) me: &'a Client<A>,
+    /// Only the name and id are set if the User is not loaded. See [User::is_loaded]
     pub user: AboutUser,
+    loaded: bool,
 }
 
 impl<'a, A: Authenticator> PartialEq for User<'a, A> {
@@ -25,43 +41,184 @@ impl<'a, A: Authenticator> PartialEq for User<'a, A> {
 }
 
 impl<'a, A: Authenticator> User<'a, A> {
-    /// Comments
-    pub async fn comments(&self, feed: Option<FeedOption>) -> Result<CommentsResponse, Error> {
-        let mut string = format!("/user/{}/comments.json", &self.user);
-        if let Some(options) = feed {
-            string.push('?');
-            string.push_str(options.url().as_str());
+    /// A User that has not been loaded. Only the name and the id(if the fullname is known) are set
+    pub(crate) fn unloaded(me: &'a Client<A>, name: &str, fullname: Option<&str>) -> User<'a, A> {
+        let user = AboutUser {
+            name: name.to_string(),
+            id: fullname
+                .map(|fullname| FullnameKind::Account.strip(fullname).to_string())
+                .unwrap_or_default(),
+            ..AboutUser::default()
+        };
+        User {
+            me,
+            user,
+            loaded: false,
         }
+    }
+    /// A User with the about Reddit returned
+    pub(crate) fn loaded(me: &'a Client<A>, user: AboutUser) -> User<'a, A> {
+        User {
+            me,
+            user,
+            loaded: true,
+        }
+    }
+    /// False if only the name and id are known. [User::about] loads the rest
+    pub fn is_loaded(&self) -> bool {
+        self.loaded
+    }
+    /// Loads the about of the user. Use it to fill in a User from [Submission::author_user](crate::submission::Submission::author_user)
+    /// or [Comment::author_user](crate::comments::Comment::author_user)
+    pub async fn about(&self) -> Result<AboutUser, Error> {
+        Ok(self.me.about_user(&self.user.name).await?.data)
+    }
+    /// Comments. Reddit sorts them by new if `sort` is None.
+    ///
+    /// The period of `feed` is only sent for [UserSort::Top] and [UserSort::Controversial]
+    pub async fn comments(
+        &self,
+        feed: Option<FeedOption>,
+        sort: Option<UserSort>,
+    ) -> Result<CommentsResponse, Error> {
+        let string = self.listing_path("comments", feed, sort);
         return self
             .me
-            .get_json::<CommentsResponse>(&*string, false, false)
+            .get_json::<CommentsResponse>(&string, Access::Any)
             .await;
     }
-    /// user Submissions
+    /// user Submissions. Reddit sorts them by new if `sort` is None.
+    ///
+    /// The period of `feed` is only sent for [UserSort::Top] and [UserSort::Controversial]
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() ->anyhow::Result<()>{
+    ///    use rraw::auth::AnonymousAuthenticator;
+    ///    use rraw::Client;
+    ///    use rraw::utils::options::{FeedOption, TimePeriod, UserSort};
+    ///    let client = Client::login(AnonymousAuthenticator::new(), "RRAW Test (by u/KingTuxWH)").await?;
+    ///    let user = client.user("KingTuxWH").await?;
+    ///    // The top submissions of the year. /user/KingTuxWH/submitted.json?sort=top&t=year
+    ///    let feed = FeedOption::builder().time(TimePeriod::Year).build()?;
+    ///    let top = user.submissions(Some(feed), Some(UserSort::Top)).await?;
+    ///    Ok(())
+    /// }
+    /// ```
     pub async fn submissions(
         &self,
         feed: Option<FeedOption>,
+        sort: Option<UserSort>,
     ) -> Result<SubmissionsResponse, Error> {
-        let mut string = format!("/user/{}/submitted.json", &self.user);
-        if let Some(options) = feed {
-            string.push('?');
-            string.push_str(options.url().as_str());
-        }
+        let string = self.listing_path("submitted", feed, sort);
         return self
             .me
-            .get_json::<SubmissionsResponse>(&*string, false, false)
+            .get_json::<SubmissionsResponse>(&string, Access::Any)
             .await;
     }
-    /// User Overview
-    pub async fn overview(&self, feed: Option<FeedOption>) -> Result<RedditListing, Error> {
-        let mut string = format!("/user/{}/overview.json", &self.user);
-        if let Some(options) = feed {
-            string.push('?');
-            string.push_str(options.url().as_str());
+    /// A [Paginator] over all of the users submissions
+    #[cfg(feature = "stream")]
+    pub fn submissions_stream(&self, options: FeedOption) -> Paginator<'_, SubmissionResponse> {
+        self.me.paginate(options, move |options| {
+            self.submissions(Some(options), None)
+        })
+    }
+    /// User Overview. `filter` limits it to only comments or submissions. Reddit sorts it by new if `sort` is None.
+    ///
+    /// The period of `feed` is only sent for [UserSort::Top] and [UserSort::Controversial]
+    pub async fn overview(
+        &self,
+        feed: Option<FeedOption>,
+        sort: Option<UserSort>,
+        filter: Option<ThingType>,
+    ) -> Result<MixedListing, Error> {
+        let mut string = self.listing_path("overview", feed, sort);
+        if let Some(filter) = filter {
+            filter.extend(&mut string);
         }
-        return self
+        return self.me.get_json::<MixedListing>(&string, Access::Any).await;
+    }
+    /// The path of a listing of the user. Such as `/user/{name}/comments.json?sort=top&t=year`
+    fn listing_path(
+        &self,
+        listing: &str,
+        feed: Option<FeedOption>,
+        sort: Option<UserSort>,
+    ) -> String {
+        let mut string = format!("/user/{}/{listing}.json", &self.user);
+        if let Some(sort) = sort {
+            extend_query(&mut string, &format!("sort={sort}"));
+        }
+        if let Some(mut options) = feed {
+            if options.period.is_some() && !sort.map_or(false, |sort| sort.uses_period()) {
+                debug!("Not sending the period. Only the top and controversial sorts use it");
+                options.period = None;
+            }
+            options.extend(&mut string);
+        }
+        string
+    }
+    /// The public multireddits of the user. Empty if the user has none or Reddit hides them
+    pub async fn multireddits(&self) -> Result<Vec<Multireddit>, Error> {
+        let string = format!("/api/multi/user/{}", &self.user);
+        match self
             .me
-            .get_json::<RedditListing>(&*string, false, false)
-            .await;
+            .get_json::<Vec<MultiredditResponse>>(&string, Access::Any)
+            .await
+        {
+            Ok(multis) => Ok(multis.into_iter().map(|multi| multi.data).collect()),
+            Err(Error::HTTPError {
+                error: HTTPError::NotFound,
+                ..
+            })
+            | Err(Error::Forbidden { .. }) => Ok(Vec::new()),
+            Err(error) => Err(error),
+        }
+    }
+    /// Loads the about, trophies and moderated subreddits of the user concurrently.
+    ///
+    /// Only the about is required. If the trophies or moderated subreddits fail to load they are `None`
+    pub async fn profile(&self) -> Result<UserProfile, Error> {
+        let about = format!("/user/{}/about.json", &self.user);
+        let trophies = format!("/api/v1/user/{}/trophies", &self.user);
+        let moderated = format!("/user/{}/moderated_subreddits.json", &self.user);
+        let (about, trophies, moderated) = join!(
+            self.me.get_json::<UserResponse>(&about, Access::Any),
+            self.me.get_json::<TrophyList>(&trophies, Access::Any),
+            self.me.get_json::<ModeratedList>(&moderated, Access::Any)
+        );
+        let trophies = trophies
+            .map(|trophies| {
+                trophies
+                    .data
+                    .trophies
+                    .into_iter()
+                    .map(|trophy| trophy.data)
+                    .collect()
+            })
+            .map_err(|error| debug!("Unable to load the trophies of {}: {}", &self.user, error))
+            .ok();
+        let moderated_subreddits = moderated
+            .map(|moderated| moderated.data)
+            .map_err(|error| {
+                debug!(
+                    "Unable to load the moderated subreddits of {}: {}",
+                    &self.user, error
+                )
+            })
+            .ok();
+        Ok(UserProfile {
+            about: about?.data,
+            trophies,
+            moderated_subreddits,
+        })
+    }
+}
+
+#[cfg(feature = "rss")]
+impl<'a, A: Authenticator> User<'a, A> {
+    /// The newest submissions from `/user/{name}/submitted.rss`. Does not need OAuth and is not counted against the API rate limit
+    pub async fn submissions_rss(&self) -> Result<Vec<RssItem>, Error> {
+        let string = format!("/user/{}/submitted.rss", &self.user);
+        self.me.get_feed(&string).await
     }
 }
@@ -1,25 +1,34 @@
+use crate::responses::types::SubredditType;
 use crate::responses::GenericResponse;
 use std::fmt::{Debug, Display, Formatter};
 
-pub use serde::Deserialize;
+pub use serde::{Deserialize, Serialize};
 
 use crate::responses::listing::GenericListing;
+use crate::utils::unescape_html;
 use serde_json::Value;
+use std::collections::HashMap;
 
 ///About Data for the User
-#[derive(Deserialize, Clone)]
+#[derive(Deserialize, Serialize, Clone, PartialEq)]
 pub struct MeResponse {
     #[serde(flatten)]
     pub about: AboutUser,
     /// If you know what this data is. Please Tell me.
     pub features: Value,
+    /// The number of unread items in the Inbox
+    #[serde(default)]
+    pub inbox_count: u64,
+    /// True if there are unread messages
+    #[serde(default)]
+    pub has_mail: Option<bool>,
 }
 impl Debug for MeResponse {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "[Me Response] User: {}", self.about.name)
     }
 }
-#[derive(Deserialize, Clone)]
+#[derive(Deserialize, Serialize, Clone, PartialEq)]
 pub struct PersonalInformation {
     pub pref_no_profanity: bool,
     pub has_external_account: bool,
@@ -42,28 +51,37 @@ impl Debug for PersonalInformation {
         write!(f, "[Personal Information]")
     }
 }
-#[derive(Deserialize, Clone)]
+#[derive(Deserialize, Serialize, Clone, PartialEq, Default)]
 pub struct AboutUser {
     #[serde(default)]
     pub is_employee: bool,
     #[serde(default)]
     pub is_friend: bool,
-    //TODO expand upon later
-    pub subreddit: Value,
+    /// The `u_{name}` profile subreddit. None for suspended accounts and some old accounts
+    #[serde(default)]
+    pub subreddit: Option<ProfileSubreddit>,
     pub snoovatar_size: Option<Vec<i64>>,
     #[serde(default)]
     pub awardee_karma: i64,
+    #[serde(default)]
     pub id: String,
+    #[serde(default)]
     pub verified: bool,
+    #[serde(default)]
     pub is_gold: bool,
+    /// Suspended accounts only have the name and this flag. Every other field is the default
     #[serde(default)]
     pub is_suspended: bool,
     #[serde(default)]
     pub is_mod: bool,
     #[serde(default)]
     pub awarder_karma: i64,
+    #[serde(default)]
     pub has_verified_email: bool,
+    /// HTML escaped unless the Client uses [raw_json](crate::builder::ClientBuilder::raw_json). See [AboutUser::icon_url]
+    #[serde(default)]
     pub icon_img: String,
+    #[serde(default)]
     pub hide_from_robots: bool,
     #[serde(default)]
     pub link_karma: i64,
@@ -71,20 +89,36 @@ pub struct AboutUser {
     pub is_blocked: bool,
     #[serde(default)]
     pub total_karma: i64,
+    #[serde(default)]
     pub pref_show_snoovatar: bool,
+    #[serde(default)]
     pub name: String,
     #[serde(default)]
     pub created: f64,
     #[serde(default)]
     pub created_utc: f64,
+    /// Empty if the user has no avatar. See [AboutUser::snoovatar_url]
+    #[serde(default)]
     pub snoovatar_img: String,
     #[serde(default)]
     pub comment_karma: i64,
+    #[serde(default)]
     pub accept_followers: bool,
+    #[serde(default)]
     pub has_subscribed: bool,
     #[serde(flatten)]
     pub personal_details: Option<PersonalInformation>,
 }
+impl AboutUser {
+    /// The profile icon with the `&amp;` escapes decoded
+    pub fn icon_url(&self) -> String {
+        unescape_html(&self.icon_img)
+    }
+    /// The avatar with the `&amp;` escapes decoded. None if the user has no avatar
+    pub fn snoovatar_url(&self) -> Option<String> {
+        non_empty_url(&self.snoovatar_img)
+    }
+}
 impl Display for AboutUser {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.name)
@@ -101,7 +135,156 @@ impl Debug for AboutUser {
     }
 }
 
+/// The profile of a user. Reddit models it as a subreddit named `u_{name}`
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct ProfileSubreddit {
+    /// `u_{name}`
+    pub display_name: String,
+    /// `u/{name}`
+    #[serde(default)]
+    pub display_name_prefixed: String,
+    /// The fullname of the profile subreddit
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub title: String,
+    /// The description shown on the profile
+    #[serde(default)]
+    pub public_description: String,
+    #[serde(default)]
+    pub icon_img: String,
+    #[serde(default)]
+    pub banner_img: String,
+    #[serde(default)]
+    pub url: String,
+    pub subreddit_type: Option<SubredditType>,
+    #[serde(default)]
+    pub over_18: bool,
+    #[serde(flatten)]
+    pub other: HashMap<String, Value>,
+}
+
+impl ProfileSubreddit {
+    /// The profile icon with the `&amp;` escapes decoded. None if there is no icon
+    pub fn icon_url(&self) -> Option<String> {
+        non_empty_url(&self.icon_img)
+    }
+    /// The banner with the `&amp;` escapes decoded. None if there is no banner
+    pub fn banner_url(&self) -> Option<String> {
+        non_empty_url(&self.banner_img)
+    }
+}
+
+fn non_empty_url(url: &str) -> Option<String> {
+    (!url.is_empty()).then(|| unescape_html(url))
+}
+
 /// About with a GenericResponse Wrap
 pub type UserResponse = GenericResponse<AboutUser>;
 /// A listing of user abouts
 pub type Users = GenericListing<AboutUser>;
+
+/// A Trophy displayed on a users profile
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct Trophy {
+    pub name: String,
+    pub description: Option<String>,
+    pub icon_70: String,
+    pub icon_40: String,
+    pub award_id: Option<String>,
+    pub id: Option<String>,
+    pub url: Option<String>,
+    /// Unix Timestamp. Not all trophies have one
+    pub granted_at: Option<i64>,
+}
+
+/// The `data` of the `TrophyList` response
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct Trophies {
+    pub trophies: Vec<GenericResponse<Trophy>>,
+}
+
+/// Response from `/api/v1/user/{name}/trophies`
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct TrophyList {
+    pub kind: String,
+    pub data: Trophies,
+}
+
+/// A Subreddit the user moderates
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct ModeratedSubreddit {
+    /// The subreddit name without the `r/`
+    pub sr: String,
+    /// The fullname of the subreddit
+    pub name: String,
+    pub sr_display_name_prefixed: String,
+    pub title: String,
+    pub url: String,
+    #[serde(default)]
+    pub subscribers: u64,
+    #[serde(default)]
+    pub over_18: bool,
+    pub subreddit_type: SubredditType,
+    #[serde(default)]
+    pub mod_permissions: Vec<String>,
+    #[serde(default)]
+    pub icon_img: Option<String>,
+    #[serde(default)]
+    pub community_icon: Option<String>,
+}
+
+/// Response from `/user/{name}/moderated_subreddits`
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct ModeratedList {
+    pub kind: String,
+    pub data: Vec<ModeratedSubreddit>,
+}
+
+/// The combined response of [User::profile](crate::user::User::profile)
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct UserProfile {
+    pub about: AboutUser,
+    /// None if the trophies could not be loaded
+    pub trophies: Option<Vec<Trophy>>,
+    /// None if the moderated subreddits could not be loaded. Reddit hides these for some users
+    pub moderated_subreddits: Option<Vec<ModeratedSubreddit>>,
+}
+
+/// A subreddit of a [Multireddit]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct MultiredditSubreddit {
+    pub name: String,
+}
+
+/// A multireddit. A named feed combining several subreddits
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct Multireddit {
+    pub name: String,
+    pub display_name: String,
+    /// Such as `/user/KingTuxWH/m/rust`
+    pub path: String,
+    pub owner: String,
+    #[serde(default)]
+    pub description_md: String,
+    #[serde(default)]
+    pub subreddits: Vec<MultiredditSubreddit>,
+    /// `public`, `private` or `hidden`
+    pub visibility: String,
+    #[serde(default)]
+    pub over_18: bool,
+    #[serde(default)]
+    pub num_subscribers: u64,
+    pub icon_url: Option<String>,
+    /// The path of the multireddit this was copied from
+    pub copied_from: Option<String>,
+    #[serde(default)]
+    pub created_utc: f64,
+}
+
+/// A multireddit from `/api/multi/user/{name}`. Reddit's kind is `LabeledMulti`
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct MultiredditResponse {
+    pub kind: String,
+    pub data: Multireddit,
+}
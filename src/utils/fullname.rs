@@ -0,0 +1,116 @@
+//! Fullnames are the type prefix and the id of a thing. Such as `t3_15bfi0`
+use std::fmt::{Display, Formatter};
+
+/// The type prefix of a [Fullname]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum FullnameKind {
+    /// `t1`
+    Comment,
+    /// `t2`
+    Account,
+    /// `t3` A submission
+    Link,
+    /// `t4`
+    Message,
+    /// `t5`
+    Subreddit,
+    /// `t6`
+    Award,
+}
+
+impl FullnameKind {
+    /// The prefix without the `_`
+    pub fn prefix(&self) -> &'static str {
+        match self {
+            FullnameKind::Comment => "t1",
+            FullnameKind::Account => "t2",
+            FullnameKind::Link => "t3",
+            FullnameKind::Message => "t4",
+            FullnameKind::Subreddit => "t5",
+            FullnameKind::Award => "t6",
+        }
+    }
+    pub fn from_prefix(prefix: &str) -> Option<FullnameKind> {
+        let kind = match prefix {
+            "t1" => FullnameKind::Comment,
+            "t2" => FullnameKind::Account,
+            "t3" => FullnameKind::Link,
+            "t4" => FullnameKind::Message,
+            "t5" => FullnameKind::Subreddit,
+            "t6" => FullnameKind::Award,
+            _ => return None,
+        };
+        Some(kind)
+    }
+    /// Is `value` a valid fullname of this kind
+    pub fn matches(&self, value: &str) -> bool {
+        Fullname::parse(value).map(|fullname| fullname.kind) == Some(*self)
+    }
+    /// The id of `value` whether or not it starts with this prefix
+    pub fn strip<'a>(&self, value: &'a str) -> &'a str {
+        value
+            .strip_prefix(self.prefix())
+            .and_then(|id| id.strip_prefix('_'))
+            .unwrap_or(value)
+    }
+}
+
+impl Display for FullnameKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.prefix())
+    }
+}
+
+/// A fullname. Displays as `{kind}_{id}`
+/// ```
+/// use rraw::utils::fullname::{Fullname, FullnameKind};
+/// let fullname = Fullname::parse("t3_15bfi0").unwrap();
+/// assert_eq!(fullname.kind(), FullnameKind::Link);
+/// assert_eq!(fullname.id(), "15bfi0");
+/// assert_eq!(Fullname::new(FullnameKind::Comment, "jtq1a2b").to_string(), "t1_jtq1a2b");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Fullname {
+    kind: FullnameKind,
+    id: String,
+}
+
+impl Fullname {
+    /// The id may already have the prefix of the kind. `Fullname::new(FullnameKind::Link, "t3_15bfi0")` is `t3_15bfi0`
+    pub fn new(kind: FullnameKind, id: impl AsRef<str>) -> Fullname {
+        Fullname {
+            kind,
+            id: kind.strip(id.as_ref()).to_string(),
+        }
+    }
+    /// None if the prefix is unknown or the id is not base 36
+    pub fn parse(value: &str) -> Option<Fullname> {
+        let (prefix, id) = value.split_once('_')?;
+        let kind = FullnameKind::from_prefix(prefix)?;
+        let valid = !id.is_empty()
+            && id
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit());
+        valid.then(|| Fullname {
+            kind,
+            id: id.to_string(),
+        })
+    }
+    /// Accepts the id or the fullname of a thing of `kind`. None if it is another kind or the id is not base 36
+    pub fn parse_as(kind: FullnameKind, value: &str) -> Option<Fullname> {
+        Fullname::parse(&format!("{kind}_{}", kind.strip(value)))
+    }
+    pub fn kind(&self) -> FullnameKind {
+        self.kind
+    }
+    /// The id without the prefix
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+}
+
+impl Display for Fullname {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}_{}", self.kind, self.id)
+    }
+}
@@ -1,14 +1,53 @@
 use base64::Engine;
+use reqwest::Body;
+use serde::Serialize;
 
+pub mod fullname;
 pub mod options;
+pub mod redact;
+pub mod reddit_url;
+pub mod search;
 
+pub fn basic_header(username: &str, password: &str) -> String {
+    base64::engine::general_purpose::STANDARD.encode(format!("{}:{}", username, password))
+}
 
-pub fn basic_header(username: &str, password: &str)->String{
-    base64::engine::general_purpose::STANDARD.encode(
-        format!(
-            "{}:{}",
-            username,
-            password
-        )
-    )
-}
\ No newline at end of file
+/// Creates a `application/x-www-form-urlencoded` body. Values are percent encoded
+pub fn form_body<T: Serialize + ?Sized>(values: &T) -> Body {
+    Body::from(serde_urlencoded::to_string(values).unwrap_or_default())
+}
+
+/// Appends a value to a form body. Streaming bodies are returned unchanged
+pub(crate) fn append_form(body: Body, key: &str, value: &str) -> Body {
+    let bytes = match body.as_bytes() {
+        Some(bytes) => bytes,
+        None => return body,
+    };
+    let mut form = String::from_utf8_lossy(bytes).into_owned();
+    if !form.is_empty() {
+        form.push('&');
+    }
+    form.push_str(&serde_urlencoded::to_string([(key, value)]).unwrap_or_default());
+    Body::from(form)
+}
+
+/// Decodes the HTML entities Reddit uses to escape text. Such as `&lt;` to `<`
+pub fn unescape_html(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&#x27;", "'")
+        .replace("&amp;", "&")
+}
+
+/// Reddit's HTML fields always start with a tag. If it is escaped the HTML is decoded
+pub(crate) fn rendered_html(html: Option<&String>) -> Option<String> {
+    html.map(|html| {
+        if html.starts_with('<') {
+            html.clone()
+        } else {
+            unescape_html(html)
+        }
+    })
+}
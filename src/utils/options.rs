@@ -1,74 +1,275 @@
+use crate::error::Error;
 use core::fmt;
+use log::debug;
 use std::fmt::{Display, Formatter};
 
 pub use serde::Serialize;
-#[derive(Clone, Debug, Serialize)]
+/// The options for the comments of a submission
+/// ```
+/// use rraw::utils::options::{CommentOption, CommentSort};
+/// let options = CommentOption {
+///     sort: Some(CommentSort::New),
+///     truncate: Some(10),
+///     show_more: Some(false),
+///     ..CommentOption::default()
+/// };
+/// assert_eq!(options.url(), "sort=new&truncate=10&showmore=false");
+/// ```
+#[derive(Clone, Debug, Default, Serialize)]
 pub struct CommentOption {
-    pub sort: Option<String>,
+    pub sort: Option<CommentSort>,
+    /// The maximum depth of the replies
     pub depth: Option<u32>,
+    /// The maximum number of comments
     pub limit: Option<u32>,
+    /// Only the first `truncate` top level comments. Clamped to 0..=50
+    pub truncate: Option<u32>,
+    /// Include [MoreComments](crate::comments::tree::MoreComments) placeholders for the comments that were left out. Reddit defaults to true
+    pub show_more: Option<bool>,
 }
 
 impl CommentOption {
-    ///Returns the URL extension for the request
+    /// The largest truncate Reddit accepts
+    pub const MAX_TRUNCATE: u32 = 50;
+    ///Returns the percent encoded query for the request. Without a leading `?` or `&`
     pub fn url(&self) -> String {
-        let mut url = String::new();
+        let mut query: Vec<(&str, String)> = Vec::new();
         if let Some(sort) = &self.sort {
-            url.push_str(&format!("&sort={sort}"));
+            query.push(("sort", sort.to_string()));
         }
         if let Some(depth) = &self.depth {
-            url.push_str(&format!("&depth={depth}"));
+            query.push(("depth", depth.to_string()));
         }
-
         if let Some(limit) = &self.limit {
-            url.push_str(&format!("&limit={limit}"));
+            query.push(("limit", limit.to_string()));
+        }
+        if let Some(truncate) = self.truncate {
+            let clamped = truncate.min(Self::MAX_TRUNCATE);
+            if clamped != truncate {
+                debug!(
+                    "CommentOption truncate {truncate} is outside of 0..={}. Using {clamped}",
+                    Self::MAX_TRUNCATE
+                );
+            }
+            query.push(("truncate", clamped.to_string()));
         }
-        url
+        if let Some(show_more) = &self.show_more {
+            query.push(("showmore", show_more.to_string()));
+        }
+        serde_urlencoded::to_string(query).unwrap_or_default()
     }
+    /// Appends the query to the path. Nothing is appended if there are no options set
     pub fn extend(&self, value: &mut String) {
-        value.push('?');
-        value.push_str(self.url().as_str());
+        extend_query(value, &self.url());
     }
 }
 ///A simple object to let you set informationons about the listing you are getting
-#[derive(Clone, Debug, Serialize)]
+///
+/// Use [FeedOption::builder] to create one
+/// ```
+/// use rraw::utils::options::{FeedOption, TimePeriod};
+/// let options = FeedOption::builder()
+///     .limit(100)
+///     .after("t3_15bfi0")
+///     .time(TimePeriod::Week)
+///     .count(25)
+///     .sr_detail(true)
+///     .build()
+///     .unwrap();
+/// assert_eq!(options.url(), "after=t3_15bfi0&count=25&t=week&limit=100&sr_detail=true");
+/// ```
+#[derive(Clone, Debug, Default, Serialize)]
 pub struct FeedOption {
     pub after: Option<String>,
     pub before: Option<String>,
     pub count: Option<u32>,
     pub limit: Option<u32>,
     pub period: Option<TimePeriod>,
+    /// Expand the subreddits of the returned things
+    pub sr_detail: Option<bool>,
 }
 
 impl FeedOption {
-    ///Returns the URL extension for the request
+    /// The largest limit Reddit accepts
+    pub const MAX_LIMIT: u32 = 100;
+    /// Creates a [FeedOptionBuilder]
+    pub fn builder() -> FeedOptionBuilder {
+        FeedOptionBuilder::default()
+    }
+    ///Returns the percent encoded query for the request. Without a leading `?` or `&`
+    ///
+    /// Reddit caps the limit at 100 so it is clamped to 1..=100
     pub fn url(&self) -> String {
-        let mut url = String::new();
+        let mut query: Vec<(&str, String)> = Vec::new();
         if let Some(after) = &self.after {
-            url.push_str(&format!("&after={after}"));
+            query.push(("after", after.clone()));
         }
         if let Some(before) = &self.before {
-            url.push_str(&format!("&before={before}"));
+            query.push(("before", before.clone()));
         }
-
         if let Some(count) = &self.count {
-            url.push_str(&format!("&count={count}"));
+            query.push(("count", count.to_string()));
         }
-
         if let Some(period) = &self.period {
-            url.push_str(&format!("&t={period}"));
+            query.push(("t", period.to_string()));
         }
-        if let Some(limit) = &self.limit {
-            url.push_str(&format!("&limit={limit}"));
+        if let Some(limit) = self.limit {
+            let clamped = limit.clamp(1, Self::MAX_LIMIT);
+            if clamped != limit {
+                debug!(
+                    "FeedOption limit {limit} is outside of 1..={}. Using {clamped}",
+                    Self::MAX_LIMIT
+                );
+            }
+            query.push(("limit", clamped.to_string()));
         }
-        url
+        if let Some(sr_detail) = &self.sr_detail {
+            query.push(("sr_detail", sr_detail.to_string()));
+        }
+        serde_urlencoded::to_string(query).unwrap_or_default()
     }
+    /// Appends the query to the path. Using `&` if the path already has a query.
+    ///
+    /// Nothing is appended if there are no options set
     pub fn extend(&self, value: &mut String) {
-        value.push('?');
-        value.push_str(self.url().as_str());
+        extend_query(value, &self.url());
+    }
+    /// Fills every field that is not set with the one from `defaults`.
+    ///
+    /// `after` and `before` are treated as one cursor. They only come from `defaults` if neither is set
+    pub fn merge(mut self, defaults: &FeedOption) -> FeedOption {
+        if self.after.is_none() && self.before.is_none() {
+            self.after = defaults.after.clone();
+            self.before = defaults.before.clone();
+        }
+        self.count = self.count.or(defaults.count);
+        self.limit = self.limit.or(defaults.limit);
+        self.period = self.period.or(defaults.period);
+        self.sr_detail = self.sr_detail.or(defaults.sr_detail);
+        self
     }
 }
 
+/// Builds a [FeedOption]
+#[derive(Clone, Debug, Default)]
+pub struct FeedOptionBuilder {
+    options: FeedOption,
+}
+
+impl FeedOptionBuilder {
+    /// The number of items to return. Must be within 1..=100
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.options.limit = Some(limit);
+        self
+    }
+    /// The fullname to load items after.
+    ///
+    /// `after` and `before` are mutually exclusive. The last one set wins
+    pub fn after(mut self, after: impl Into<String>) -> Self {
+        self.options.after = Some(after.into());
+        self.options.before = None;
+        self
+    }
+    /// The fullname to load items before.
+    ///
+    /// `after` and `before` are mutually exclusive. The last one set wins
+    pub fn before(mut self, before: impl Into<String>) -> Self {
+        self.options.before = Some(before.into());
+        self.options.after = None;
+        self
+    }
+    /// The number of items already seen in the listing
+    pub fn count(mut self, count: u32) -> Self {
+        self.options.count = Some(count);
+        self
+    }
+    /// The time period for sorts such as top and controversial
+    pub fn time(mut self, period: TimePeriod) -> Self {
+        self.options.period = Some(period);
+        self
+    }
+    /// Expand the subreddits of the returned things
+    pub fn sr_detail(mut self, sr_detail: bool) -> Self {
+        self.options.sr_detail = Some(sr_detail);
+        self
+    }
+    /// Returns [Error::InvalidOption] if the limit is not within 1..=100
+    pub fn build(self) -> Result<FeedOption, Error> {
+        if let Some(limit) = self.options.limit {
+            if !(1..=FeedOption::MAX_LIMIT).contains(&limit) {
+                return Err(Error::InvalidOption(format!(
+                    "limit must be between 1 and {} got {limit}",
+                    FeedOption::MAX_LIMIT
+                )));
+            }
+        }
+        Ok(self.options)
+    }
+}
+
+/// Filters a mixed listing such as saved or overview with Reddit's `type` parameter
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize)]
+pub enum ThingType {
+    /// Only Submissions
+    Links,
+    /// Only Comments
+    Comments,
+}
+
+impl ThingType {
+    /// Appends `type={self}` to the path
+    pub fn extend(&self, value: &mut String) {
+        extend_query(value, &format!("type={self}"));
+    }
+}
+
+impl Display for ThingType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let string = match self {
+            ThingType::Links => "links",
+            ThingType::Comments => "comments",
+        };
+        write!(f, "{}", string)
+    }
+}
+
+/// The moderation listings of a subreddit. Each is a mix of comments and submissions
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize)]
+pub enum ModQueue {
+    /// Reported, filtered or marked as spam. Waiting for a moderator
+    ModQueue,
+    /// Reported by users
+    Reports,
+    /// Removed as spam
+    Spam,
+    /// Recently edited
+    Edited,
+    /// Not approved or removed by a moderator yet. Only submissions
+    Unmoderated,
+}
+
+impl Display for ModQueue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let string = match self {
+            ModQueue::ModQueue => "modqueue",
+            ModQueue::Reports => "reports",
+            ModQueue::Spam => "spam",
+            ModQueue::Edited => "edited",
+            ModQueue::Unmoderated => "unmoderated",
+        };
+        write!(f, "{}", string)
+    }
+}
+
+/// Appends the query to the path. Nothing is appended if the query is empty
+pub(crate) fn extend_query(path: &mut String, query: &str) {
+    if query.is_empty() {
+        return;
+    }
+    path.push(if path.contains('?') { '&' } else { '?' });
+    path.push_str(query);
+}
+
 ///Time Period for the request
 #[derive(Copy, Clone, Debug, Serialize)]
 pub enum TimePeriod {
@@ -108,10 +309,134 @@ impl Display for TimePeriod {
     }
 }
 
+/// The sort of a comment tree
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CommentSort {
+    /// Reddit's default. Sent as `confidence`
+    #[serde(rename = "confidence")]
+    Best,
+    Top,
+    New,
+    Controversial,
+    Old,
+    /// Answers from the author of the post first
+    #[serde(rename = "qa")]
+    QandA,
+    Live,
+}
+
+impl Display for CommentSort {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let string = match self {
+            CommentSort::Best => "confidence",
+            CommentSort::Top => "top",
+            CommentSort::New => "new",
+            CommentSort::Controversial => "controversial",
+            CommentSort::Old => "old",
+            CommentSort::QandA => "qa",
+            CommentSort::Live => "live",
+        };
+        write!(f, "{}", string)
+    }
+}
+
+/// The sort of a submission listing. Top and Controversial use the [FeedOption] period
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SubmissionSort {
+    Hot,
+    New,
+    Rising,
+    Top,
+    Controversial,
+}
+
+impl SubmissionSort {
+    /// True for the sorts that use the [FeedOption] period. Reddit ignores `t` for the others
+    /// ```
+    /// use rraw::utils::options::SubmissionSort;
+    /// assert!(SubmissionSort::Top.uses_period());
+    /// assert!(!SubmissionSort::New.uses_period());
+    /// ```
+    pub fn uses_period(&self) -> bool {
+        matches!(self, SubmissionSort::Top | SubmissionSort::Controversial)
+    }
+}
+
+impl Display for SubmissionSort {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let string = match self {
+            SubmissionSort::Hot => "hot",
+            SubmissionSort::New => "new",
+            SubmissionSort::Rising => "rising",
+            SubmissionSort::Top => "top",
+            SubmissionSort::Controversial => "controversial",
+        };
+        write!(f, "{}", string)
+    }
+}
+
+/// The sort of a user listing. Reddit does not support rising for them. Top and Controversial use the [FeedOption] period
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum UserSort {
+    Hot,
+    New,
+    Top,
+    Controversial,
+}
+
+impl UserSort {
+    /// True for the sorts that use the [FeedOption] period. Reddit ignores `t` for the others
+    pub fn uses_period(&self) -> bool {
+        matches!(self, UserSort::Top | UserSort::Controversial)
+    }
+}
+
+impl Display for UserSort {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let string = match self {
+            UserSort::Hot => "hot",
+            UserSort::New => "new",
+            UserSort::Top => "top",
+            UserSort::Controversial => "controversial",
+        };
+        write!(f, "{}", string)
+    }
+}
+
+/// The sort of search results. All but New use the [FeedOption] period
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SearchSort {
+    Relevance,
+    Hot,
+    Top,
+    New,
+    /// Most comments first
+    Comments,
+}
+
+impl Display for SearchSort {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let string = match self {
+            SearchSort::Relevance => "relevance",
+            SearchSort::Hot => "hot",
+            SearchSort::Top => "top",
+            SearchSort::New => "new",
+            SearchSort::Comments => "comments",
+        };
+        write!(f, "{}", string)
+    }
+}
+
 /// FriendType
 pub enum FriendType {
-    /// Contributor
+    /// Contributor. Also known as an approved user
     Contributor,
+    /// Wiki Contributor
+    WikiContributor,
     /// Moderator
     Moderator,
     /// This exist if the reddit api changes in the future or I am missing features
@@ -122,9 +447,30 @@ impl Display for FriendType {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         let string = match self {
             FriendType::Contributor => "contributor",
+            FriendType::WikiContributor => "wikicontributor",
             FriendType::Moderator => "moderator",
             FriendType::Custom(str) => str.as_str(),
         };
         write!(f, "{}", string)
     }
 }
+
+/// The `dir` of a vote
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum VoteDirection {
+    Up,
+    Down,
+    /// Removes the vote
+    Clear,
+}
+
+impl Display for VoteDirection {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let string = match self {
+            VoteDirection::Up => "1",
+            VoteDirection::Down => "-1",
+            VoteDirection::Clear => "0",
+        };
+        write!(f, "{}", string)
+    }
+}
@@ -0,0 +1,56 @@
+use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION, COOKIE};
+
+/// Replaces secrets before they are logged
+pub const REDACTED: &str = "[REDACTED]";
+
+/// Query parameters whose values are never logged
+pub const SECRET_PARAMS: [&str; 8] = [
+    "access_token",
+    "refresh_token",
+    "token",
+    "password",
+    "passwd",
+    "client_secret",
+    "code",
+    "uh",
+];
+
+/// Replaces the values of [SECRET_PARAMS] in the query of the url
+pub fn redact_url(url: &str) -> String {
+    let (path, query) = match url.split_once('?') {
+        Some(value) => value,
+        None => return url.to_string(),
+    };
+    let query = query
+        .split('&')
+        .map(|pair| match pair.split_once('=') {
+            Some((key, _)) if SECRET_PARAMS.contains(&key.to_ascii_lowercase().as_str()) => {
+                format!("{key}={REDACTED}")
+            }
+            _ => pair.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join("&");
+    format!("{path}?{query}")
+}
+
+/// A copy of the headers with the `Authorization` credentials, cookies and modhash masked. The auth scheme is kept
+pub fn redact_headers(headers: &HeaderMap) -> HeaderMap {
+    let mut headers = headers.clone();
+    if let Some(value) = headers.get_mut(AUTHORIZATION) {
+        let scheme = value
+            .to_str()
+            .ok()
+            .and_then(|value| value.split_whitespace().next())
+            .unwrap_or_default();
+        let masked = format!("{scheme} {REDACTED}");
+        *value = HeaderValue::from_str(masked.trim())
+            .unwrap_or_else(|_| HeaderValue::from_static(REDACTED));
+    }
+    for name in [COOKIE.as_str(), "x-modhash"] {
+        if let Some(value) = headers.get_mut(name) {
+            *value = HeaderValue::from_static(REDACTED);
+        }
+    }
+    headers
+}
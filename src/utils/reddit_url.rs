@@ -0,0 +1,126 @@
+//! Classifies links pasted by users. Such as `https://www.reddit.com/r/rust/comments/15bfi0/rraw_release/`
+use reqwest::Url;
+
+use crate::auth::Authorized;
+use crate::comments::handle::CommentHandle;
+use crate::error::url_error::RedditUrlError;
+use crate::submission::handle::SubmissionHandle;
+use crate::subreddit::Subreddit;
+use crate::user::User;
+use crate::utils::fullname::{Fullname, FullnameKind};
+
+/// What a Reddit link points to.
+///
+/// Accepts `reddit.com` on any subdomain, such as `old.` or `new.`, and `redd.it` shortlinks
+/// ```
+/// use rraw::utils::reddit_url::RedditUrl;
+/// let url = RedditUrl::parse("https://old.reddit.com/r/rust/comments/15bfi0/rraw_release/jtq1a2b/?context=3").unwrap();
+/// assert_eq!(url, RedditUrl::Comment {
+///     subreddit: Some("rust".to_string()),
+///     submission_id: "15bfi0".to_string(),
+///     id: "jtq1a2b".to_string(),
+/// });
+/// assert_eq!(RedditUrl::parse("https://redd.it/15bfi0").unwrap(), RedditUrl::Submission {
+///     subreddit: None,
+///     id: "15bfi0".to_string(),
+/// });
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RedditUrl {
+    /// `/r/{name}`. The name is without the `r/`
+    Subreddit(String),
+    /// `/r/{subreddit}/comments/{id}`, `/comments/{id}` or `redd.it/{id}`. The id is without the `t3_`
+    Submission {
+        subreddit: Option<String>,
+        id: String,
+    },
+    /// `/r/{subreddit}/comments/{submission_id}/{title}/{id}`. The ids are without the prefix
+    Comment {
+        subreddit: Option<String>,
+        submission_id: String,
+        id: String,
+    },
+    /// `/u/{name}` or `/user/{name}`
+    User(String),
+    /// `/r/{subreddit}/s/{code}` share links. Reddit redirects them to the real link
+    Share(Url),
+}
+
+impl RedditUrl {
+    pub fn parse(url: &str) -> Result<RedditUrl, RedditUrlError> {
+        let parsed = Url::parse(url).map_err(|_| RedditUrlError::Malformed(url.to_string()))?;
+        let unrecognized = || RedditUrlError::Unrecognized(url.to_string());
+        let host = parsed
+            .host_str()
+            .ok_or_else(|| RedditUrlError::NotReddit(url.to_string()))?
+            .to_lowercase();
+        let segments: Vec<&str> = parsed
+            .path_segments()
+            .map(|segments| segments.filter(|segment| !segment.is_empty()).collect())
+            .unwrap_or_default();
+        if host == "redd.it" || host == "www.redd.it" {
+            return match segments.as_slice() {
+                [id] => submission(None, id).ok_or_else(unrecognized),
+                _ => Err(unrecognized()),
+            };
+        }
+        if host != "reddit.com" && !host.ends_with(".reddit.com") {
+            return Err(RedditUrlError::NotReddit(url.to_string()));
+        }
+        let target = match segments.as_slice() {
+            ["r", name, "s", _, ..] if is_name(name) => Some(RedditUrl::Share(parsed.clone())),
+            ["r", name, "comments", rest @ ..] if is_name(name) => post(Some(name), rest),
+            ["r", name, ..] if is_name(name) => Some(RedditUrl::Subreddit(name.to_string())),
+            ["u" | "user", name, "comments", rest @ ..] if is_name(name) => {
+                post(Some(&format!("u_{name}")), rest)
+            }
+            ["u" | "user", name, ..] if is_name(name) => Some(RedditUrl::User(name.to_string())),
+            ["comments", rest @ ..] => post(None, rest),
+            ["gallery", id] => submission(None, id),
+            _ => None,
+        };
+        target.ok_or_else(unrecognized)
+    }
+}
+
+/// `{id}/{title}/{comment}` after `comments`
+fn post(subreddit: Option<&str>, rest: &[&str]) -> Option<RedditUrl> {
+    match rest {
+        [id] | [id, _] => submission(subreddit, id),
+        [submission_id, _, id, ..] => Some(RedditUrl::Comment {
+            subreddit: subreddit.map(str::to_string),
+            submission_id: Fullname::parse_as(FullnameKind::Link, submission_id)?
+                .id()
+                .to_string(),
+            id: Fullname::parse_as(FullnameKind::Comment, id)?
+                .id()
+                .to_string(),
+        }),
+        _ => None,
+    }
+}
+
+fn submission(subreddit: Option<&str>, id: &str) -> Option<RedditUrl> {
+    Some(RedditUrl::Submission {
+        subreddit: subreddit.map(str::to_string),
+        id: Fullname::parse_as(FullnameKind::Link, id)?.id().to_string(),
+    })
+}
+
+/// Subreddit and user names are letters, digits, `_` and `-`
+fn is_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
+/// The handle for a link. Returned by [Me::from_url](crate::user::me::Me::from_url).
+///
+/// Subreddits and users are boxed as they include the loaded about
+pub enum UrlTarget<'a, A: Authorized> {
+    Subreddit(Box<Subreddit<'a, A>>),
+    Submission(SubmissionHandle<'a, A>),
+    Comment(CommentHandle<'a, A>),
+    User(Box<User<'a, A>>),
+}
@@ -0,0 +1,183 @@
+//! Builds the `q` of a search with Reddit's field operators
+use std::fmt::{Display, Formatter};
+
+/// A search query. Values with spaces or quotes are quoted and escaped.
+///
+/// Terms are matched together unless joined with [SearchQuery::or]. Either side of an operator is put in
+/// parentheses if it has more than one term
+/// ```
+/// use rraw::utils::search::{raw, SearchQuery};
+/// let query = SearchQuery::new()
+///     .flair("Help Wanted")
+///     .author("spez")
+///     .self_posts(true)
+///     .and(raw("\"exact phrase\""));
+/// assert_eq!(
+///     query.to_string(),
+///     r#"(flair:"Help Wanted" author:spez self:yes) AND "exact phrase""#
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SearchQuery {
+    parts: Vec<String>,
+}
+
+/// A query used as is. For syntax the builder does not cover
+pub fn raw(query: impl Into<String>) -> SearchQuery {
+    SearchQuery::raw(query)
+}
+
+impl SearchQuery {
+    pub fn new() -> SearchQuery {
+        SearchQuery::default()
+    }
+    /// A query used as is. For syntax the builder does not cover
+    pub fn raw(query: impl Into<String>) -> SearchQuery {
+        let query = query.into();
+        let mut search = SearchQuery::new();
+        if !query.trim().is_empty() {
+            search.parts.push(query);
+        }
+        search
+    }
+    /// Words to match anywhere. Quoted if they contain a quote or `:`, or are one of `AND`, `OR` and `NOT`
+    pub fn text(mut self, text: &str) -> SearchQuery {
+        for word in text.split_whitespace() {
+            if matches!(word, "AND" | "OR" | "NOT") {
+                self.parts.push(quote(word));
+            } else {
+                self.parts.push(quote_if_needed(word));
+            }
+        }
+        self
+    }
+    /// The words in this order. Always quoted
+    pub fn phrase(mut self, phrase: &str) -> SearchQuery {
+        self.parts.push(quote(phrase));
+        self
+    }
+    /// Submitted by the user. Without the `u/`
+    pub fn author(self, author: &str) -> SearchQuery {
+        self.field("author", author)
+    }
+    /// Submitted to the subreddit. Without the `r/`
+    pub fn subreddit(self, subreddit: &str) -> SearchQuery {
+        self.field("subreddit", subreddit)
+    }
+    /// The text of the link flair
+    pub fn flair(self, flair: &str) -> SearchQuery {
+        self.field("flair", flair)
+    }
+    /// Words in the title
+    pub fn title(self, title: &str) -> SearchQuery {
+        self.field("title", title)
+    }
+    /// Words in the text of self posts
+    pub fn selftext(self, selftext: &str) -> SearchQuery {
+        self.field("selftext", selftext)
+    }
+    /// Links whose URL contains the text
+    pub fn url(self, url: &str) -> SearchQuery {
+        self.field("url", url)
+    }
+    /// Links to the domain. Such as `github.com`
+    pub fn site(self, site: &str) -> SearchQuery {
+        self.field("site", site)
+    }
+    /// Only self posts if true, only links if false
+    pub fn self_posts(self, self_posts: bool) -> SearchQuery {
+        self.field("self", yes_no(self_posts))
+    }
+    /// Only NSFW submissions if true, none if false
+    pub fn nsfw(self, nsfw: bool) -> SearchQuery {
+        self.field("nsfw", yes_no(nsfw))
+    }
+    /// Both this and `other` must match
+    pub fn and(self, other: SearchQuery) -> SearchQuery {
+        self.join("AND", other)
+    }
+    /// Either this or `other` must match
+    pub fn or(self, other: SearchQuery) -> SearchQuery {
+        self.join("OR", other)
+    }
+    /// This must match and `other` must not
+    pub fn not(self, other: SearchQuery) -> SearchQuery {
+        if other.is_empty() {
+            return self;
+        }
+        let mut query = self.into_group();
+        query.parts.push("NOT".to_string());
+        query.parts.push(other.grouped());
+        query
+    }
+    pub fn is_empty(&self) -> bool {
+        self.parts.is_empty()
+    }
+    fn field(mut self, field: &str, value: &str) -> SearchQuery {
+        self.parts
+            .push(format!("{field}:{}", quote_if_needed(value.trim())));
+        self
+    }
+    fn join(self, operator: &str, other: SearchQuery) -> SearchQuery {
+        if other.is_empty() {
+            return self;
+        }
+        let mut query = self.into_group();
+        if !query.is_empty() {
+            query.parts.push(operator.to_string());
+        }
+        query.parts.push(other.grouped());
+        query
+    }
+    /// One part in parentheses if it has more than one. So an operator after it applies to all of it
+    fn into_group(self) -> SearchQuery {
+        if self.parts.len() > 1 {
+            SearchQuery {
+                parts: vec![self.grouped()],
+            }
+        } else {
+            self
+        }
+    }
+    /// In parentheses if it has more than one part. So operators apply to all of it
+    fn grouped(&self) -> String {
+        if self.parts.len() > 1 {
+            format!("({self})")
+        } else {
+            self.to_string()
+        }
+    }
+}
+
+fn yes_no(value: bool) -> &'static str {
+    if value {
+        "yes"
+    } else {
+        "no"
+    }
+}
+
+fn quote(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+fn quote_if_needed(value: &str) -> String {
+    let special = |c: char| c.is_whitespace() || matches!(c, '"' | ':' | '(' | ')');
+    if value.is_empty() || value.contains(special) {
+        quote(value)
+    } else {
+        value.to_string()
+    }
+}
+
+impl Display for SearchQuery {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.parts.join(" "))
+    }
+}
+
+impl From<SearchQuery> for String {
+    fn from(query: SearchQuery) -> Self {
+        query.to_string()
+    }
+}
@@ -0,0 +1,117 @@
+mod common;
+
+use common::{mock_client, MockRequest, MockResponse, MockServer};
+use rraw::access::{Access, OAUTH_HOST, PRIVATE_HOST, WEB_HOST};
+use rraw::auth::AnonymousAuthenticator;
+use rraw::error::Error;
+use rraw::Client;
+
+fn about_server(request: &MockRequest) -> Option<MockResponse> {
+    let body = match request.path.as_str() {
+        "/u/KingTuxWH/about" | "/u/KingTuxWH/about.json" => {
+            include_str!("fixtures/user_about.json")
+        }
+        _ => "{}",
+    };
+    Some(MockResponse::json(body))
+}
+
+async fn anonymous_client(server: &MockServer) -> Client<AnonymousAuthenticator> {
+    Client::builder(AnonymousAuthenticator::new(), "RRAW Test (by u/KingTuxWH)")
+        .base_url(server.url.clone())
+        .login()
+        .await
+        .unwrap()
+}
+
+#[test]
+fn hosts() {
+    assert_eq!(Access::Any.host(true).unwrap(), OAUTH_HOST);
+    assert_eq!(Access::Any.host(false).unwrap(), WEB_HOST);
+    assert_eq!(Access::OAuthOnly.host(true).unwrap(), OAUTH_HOST);
+    assert!(matches!(
+        Access::OAuthOnly.host(false),
+        Err(Error::NotLoggedIn)
+    ));
+    assert_eq!(Access::WebOnly.host(true).unwrap(), PRIVATE_HOST);
+    assert_eq!(Access::WebOnly.host(false).unwrap(), PRIVATE_HOST);
+}
+
+#[test]
+fn paths() {
+    let cases = [
+        (
+            Access::Any,
+            "/user/KingTuxWH/submitted?limit=5",
+            false,
+            "/user/KingTuxWH/submitted.json?limit=5",
+        ),
+        (
+            Access::Any,
+            "/user/KingTuxWH/submitted?limit=5",
+            true,
+            "/user/KingTuxWH/submitted?limit=5",
+        ),
+        (
+            Access::Any,
+            "/r/rust/about.json",
+            false,
+            "/r/rust/about.json",
+        ),
+        (Access::OAuthOnly, "/api/v1/me", true, "/api/v1/me"),
+        (
+            Access::WebOnly,
+            "/domain/github.com.json",
+            true,
+            "/domain/github.com.json",
+        ),
+        (
+            Access::WebOnly,
+            "/api/trending_subreddits.json",
+            true,
+            "/api/trending_subreddits.json",
+        ),
+    ];
+    for (access, path, oauth, expected) in cases {
+        assert_eq!(
+            access.path(path, oauth),
+            expected,
+            "{access:?} {path} {oauth}"
+        );
+    }
+}
+
+#[tokio::test]
+async fn same_endpoint_both_authenticators() {
+    let server = MockServer::start(about_server).await;
+    let oauth = mock_client(&server).await;
+    let anonymous = anonymous_client(&server).await;
+    let from_oauth = oauth.user("KingTuxWH").await.unwrap();
+    let from_anonymous = anonymous.user("KingTuxWH").await.unwrap();
+    assert_eq!(from_oauth.user.name, from_anonymous.user.name);
+    assert!(from_oauth.is_loaded());
+
+    let requests = server.requests();
+    assert_eq!(requests[0].path, "/u/KingTuxWH/about");
+    assert_eq!(requests[0].header("Authorization"), Some("Bearer test"));
+    assert_eq!(requests[1].path, "/u/KingTuxWH/about.json");
+    assert_eq!(requests[1].header("Authorization"), None);
+}
+
+#[tokio::test]
+async fn oauth_only_fails_before_sending() {
+    let server = MockServer::start(about_server).await;
+    let anonymous = anonymous_client(&server).await;
+    let error = anonymous
+        .get_raw("/api/v1/me", Access::OAuthOnly)
+        .await
+        .unwrap_err();
+    assert!(matches!(error, Error::NotLoggedIn), "{error:?}");
+    assert!(server.requests().is_empty());
+
+    anonymous
+        .get_raw("/api/v1/scopes", Access::Any)
+        .await
+        .unwrap();
+    assert_eq!(server.requests()[0].path, "/api/v1/scopes");
+}
@@ -0,0 +1,255 @@
+mod common;
+
+use common::{mock_client, MockResponse, MockServer};
+use reqwest::header::{HeaderMap, HeaderValue};
+use reqwest::StatusCode;
+use rraw::access::Access;
+use rraw::error::http_error::HTTPError;
+use rraw::error::reddit_error::{parse_retry_after, RedditApiError};
+use rraw::error::{retry_after, Error, RateLimitSource};
+use rraw::ratelimit::{RateLimit, MAX_WAIT};
+use rraw::responses::api::JsonResponse;
+use serde_json::{json, Value};
+use std::time::Duration;
+
+fn errors(fixture: &str) -> Vec<RedditApiError> {
+    let response: JsonResponse<Value> =
+        serde_json::from_str(fixture).expect("Unable to parse the fixture");
+    match response.into_result() {
+        Err(Error::Api(errors)) => errors,
+        other => panic!("Expected Error::Api got {:?}", other),
+    }
+}
+
+#[test]
+fn ratelimit() {
+    let response: JsonResponse<Value> =
+        serde_json::from_str(include_str!("fixtures/api_errors/ratelimit.json")).unwrap();
+    let error = response.into_result().unwrap_err();
+    assert!(error.is_retryable());
+    match error {
+        Error::RateLimited {
+            retry_after,
+            source: RateLimitSource::Api(error),
+        } => {
+            assert_eq!(retry_after, Some(Duration::from_secs_f64(371.2)));
+            assert_eq!(error.code, "RATELIMIT");
+            assert_eq!(error.field.as_deref(), Some("ratelimit"));
+        }
+        other => panic!("Expected Error::RateLimited got {:?}", other),
+    }
+}
+
+#[test]
+fn subreddit_noexist() {
+    let errors = errors(include_str!("fixtures/api_errors/subreddit_noexist.json"));
+    assert_eq!(errors[0].code, "SUBREDDIT_NOEXIST");
+    assert_eq!(errors[0].message, "that subreddit doesn't exist");
+    assert_eq!(errors[0].field.as_deref(), Some("sr"));
+    assert_eq!(errors[0].retry_after, None);
+}
+
+#[test]
+fn user_required() {
+    let errors = errors(include_str!("fixtures/api_errors/user_required.json"));
+    assert_eq!(errors[0].code, "USER_REQUIRED");
+    assert_eq!(errors[0].field, None);
+}
+
+#[test]
+fn bad_captcha() {
+    let response: JsonResponse<Value> =
+        serde_json::from_str(include_str!("fixtures/api_errors/bad_captcha.json")).unwrap();
+    let error = response.into_result().unwrap_err();
+    assert!(!error.is_retryable());
+    match error {
+        Error::CaptchaRequired { captcha, error } => {
+            assert_eq!(captcha.as_deref(), Some("Xj2ZMmXyb8q9TQvnLxBUCq8EMU4eN1dA"));
+            assert_eq!(error.code, "BAD_CAPTCHA");
+            assert_eq!(error.field.as_deref(), Some("captcha"));
+        }
+        other => panic!("Expected Error::CaptchaRequired got {:?}", other),
+    }
+}
+
+#[test]
+fn success() {
+    let response: JsonResponse<Value> =
+        serde_json::from_str(include_str!("fixtures/api_errors/success.json")).unwrap();
+    let data = response.into_result().unwrap();
+    assert!(data.is_some());
+}
+
+#[test]
+fn retry_after_message() {
+    assert_eq!(
+        parse_retry_after("you are doing that too much. try again in 10 seconds."),
+        Some(Duration::from_secs(10))
+    );
+    assert_eq!(
+        parse_retry_after("You are doing that too much. Try again in 1 minute."),
+        Some(Duration::from_secs(60))
+    );
+    assert_eq!(parse_retry_after("Take a break for 300 milliseconds"), None);
+    assert_eq!(
+        parse_retry_after(&format!("try again in {} hours.", u64::MAX / 60)),
+        None
+    );
+    assert_eq!(
+        parse_retry_after(&format!("try again in {} minutes.", u64::MAX)),
+        None
+    );
+}
+
+#[test]
+fn retry_after_headers() {
+    let mut headers = HeaderMap::new();
+    assert_eq!(retry_after(&headers), None);
+    headers.insert("x-ratelimit-reset", HeaderValue::from_static("42"));
+    assert_eq!(retry_after(&headers), Some(Duration::from_secs(42)));
+    headers.insert("retry-after", HeaderValue::from_static("7"));
+    assert_eq!(retry_after(&headers), Some(Duration::from_secs(7)));
+}
+
+#[test]
+fn retry_after_odd_headers() {
+    let mut headers = HeaderMap::new();
+    headers.insert("retry-after", HeaderValue::from_static("1e30"));
+    assert_eq!(retry_after(&headers), Some(MAX_WAIT));
+    headers.insert("retry-after", HeaderValue::from_static("inf"));
+    assert_eq!(retry_after(&headers), None);
+    headers.insert("x-ratelimit-reset", HeaderValue::from_static("42"));
+    assert_eq!(retry_after(&headers), Some(Duration::from_secs(42)));
+}
+
+#[test]
+fn retryable() {
+    assert!(Error::from(HTTPError::Other(StatusCode::SERVICE_UNAVAILABLE)).is_retryable());
+    assert!(!Error::from(HTTPError::NotFound).is_retryable());
+    assert!(!Error::Api(vec![]).is_retryable());
+    assert!(Error::RateLimited {
+        retry_after: None,
+        source: RateLimitSource::TooManyRequests
+    }
+    .is_retryable());
+}
+
+#[test]
+fn rate_limit_headers() {
+    let mut headers = HeaderMap::new();
+    assert_eq!(RateLimit::from_headers(&headers), None);
+    headers.insert("x-ratelimit-used", HeaderValue::from_static("40"));
+    headers.insert("x-ratelimit-remaining", HeaderValue::from_static("60.0"));
+    headers.insert("x-ratelimit-reset", HeaderValue::from_static("300"));
+    let rate_limit = RateLimit::from_headers(&headers).unwrap();
+    assert_eq!(rate_limit.used, 40);
+    assert_eq!(rate_limit.reset, Duration::from_secs(300));
+    assert!(rate_limit.delay() <= Duration::from_secs(5));
+    assert!(rate_limit.delay() > Duration::from_secs(4));
+
+    headers.insert("x-ratelimit-remaining", HeaderValue::from_static("0"));
+    let rate_limit = RateLimit::from_headers(&headers).unwrap();
+    assert!(rate_limit.delay() > Duration::from_secs(299));
+}
+
+/// Headers that are not finite are ignored and huge ones are cut down to MAX_WAIT. None of them panic
+#[test]
+fn rate_limit_odd_headers() {
+    let rate_limit = |remaining: &'static str, reset: &'static str| {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-ratelimit-remaining", HeaderValue::from_static(remaining));
+        headers.insert("x-ratelimit-reset", HeaderValue::from_static(reset));
+        RateLimit::from_headers(&headers)
+    };
+    assert_eq!(rate_limit("60", "inf"), None);
+    assert_eq!(rate_limit("60", "NaN"), None);
+    assert_eq!(rate_limit("NaN", "300"), None);
+    assert_eq!(rate_limit("inf", "300"), None);
+    let huge = rate_limit("60", "1e30").unwrap();
+    assert_eq!(huge.reset, MAX_WAIT);
+    assert!(huge.delay() <= MAX_WAIT);
+    let negative = rate_limit("60", "-5").unwrap();
+    assert_eq!(negative.delay(), Duration::ZERO);
+
+    let exhausted = rate_limit("0.0", "300").unwrap();
+    assert!(exhausted.delay() > Duration::from_secs(299));
+    let nan = RateLimit {
+        remaining: f64::NAN,
+        ..exhausted
+    };
+    assert!(nan.is_exhausted());
+    assert!(nan.delay() > Duration::from_secs(299));
+}
+
+/// Without the `ratelimit` field the wait is parsed from the message
+#[test]
+fn ratelimit_message() {
+    let response: JsonResponse<Value> = serde_json::from_value(json!({"json": {"errors": [
+        ["RATELIMIT", "you are doing that too much. try again in 6 minutes.", "ratelimit"]
+    ]}}))
+    .unwrap();
+    let error = response.into_result().unwrap_err();
+    assert_eq!(error.retry_after(), Some(Duration::from_secs(6 * 60)));
+}
+
+#[test]
+fn ratelimit_field_too_large() {
+    let response: JsonResponse<Value> =
+        serde_json::from_value(json!({"json": {"ratelimit": 1e30, "errors": [
+            ["RATELIMIT", "you are doing that too much. try again in 6 minutes.", "ratelimit"]
+        ]}}))
+        .unwrap();
+    let error = response.into_result().unwrap_err();
+    assert_eq!(error.retry_after(), Some(MAX_WAIT));
+}
+
+/// Every POST checks the envelope even when the caller ignores the body
+#[tokio::test]
+async fn post_envelopes() {
+    let server = MockServer::start(|request| {
+        let body = match request.path.as_str() {
+            "/api/ratelimited" => include_str!("fixtures/api_errors/ratelimit.json"),
+            "/api/noexist" => include_str!("fixtures/api_errors/subreddit_noexist.json"),
+            _ => include_str!("fixtures/api_errors/success.json"),
+        };
+        Some(MockResponse::json(body))
+    })
+    .await;
+    let client = mock_client(&server).await;
+
+    let error = client
+        .post_raw(
+            "/api/ratelimited",
+            &[("api_type", "json")],
+            Access::OAuthOnly,
+        )
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(
+            error,
+            Error::RateLimited {
+                source: RateLimitSource::Api(_),
+                ..
+            }
+        ),
+        "{:?}",
+        error
+    );
+    assert_eq!(error.retry_after(), Some(Duration::from_secs_f64(371.2)));
+
+    let error = client
+        .post_raw("/api/noexist", &[("api_type", "json")], Access::OAuthOnly)
+        .await
+        .unwrap_err();
+    match error {
+        Error::Api(errors) => assert_eq!(errors[0].code, "SUBREDDIT_NOEXIST"),
+        other => panic!("Expected Error::Api got {:?}", other),
+    }
+
+    let value = client
+        .post_raw("/api/success", &[("api_type", "json")], Access::OAuthOnly)
+        .await
+        .unwrap();
+    assert!(value["json"]["errors"].as_array().unwrap().is_empty());
+}
@@ -0,0 +1,101 @@
+use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION, WWW_AUTHENTICATE};
+use rraw::access::Access;
+use rraw::auth::AnonymousAuthenticator;
+use rraw::error::{insufficient_scope, Error};
+use rraw::Client;
+use serde_json::Value;
+
+/// `Me::saved` requires an Authorized authenticator. So the anonymous equivalent is a raw OAuth request
+#[tokio::test]
+async fn anonymous_saved() -> anyhow::Result<()> {
+    let client = Client::login(AnonymousAuthenticator::new(), "RRAW Test (by u/KingTuxWH)").await?;
+    let error = client
+        .get_json_with_raw::<Value>("/user/KingTuxWH/saved", Access::OAuthOnly)
+        .await
+        .unwrap_err();
+    assert!(matches!(error, Error::NotLoggedIn), "{:?}", error);
+    assert!(!error.is_retryable());
+    Ok(())
+}
+
+#[tokio::test]
+async fn anonymous_raw() -> anyhow::Result<()> {
+    let client = Client::login(AnonymousAuthenticator::new(), "RRAW Test (by u/KingTuxWH)").await?;
+    let error = client
+        .post_raw("/api/v1/unwrapped", &[("name", "value")], Access::OAuthOnly)
+        .await
+        .unwrap_err();
+    assert!(matches!(error, Error::NotLoggedIn), "{:?}", error);
+    Ok(())
+}
+
+#[test]
+fn scope_header() {
+    let mut headers = HeaderMap::new();
+    assert_eq!(insufficient_scope(&headers), None);
+    headers.insert(
+        WWW_AUTHENTICATE,
+        HeaderValue::from_static("Bearer realm=\"reddit\", error=\"insufficient_scope\""),
+    );
+    assert_eq!(insufficient_scope(&headers), Some(None));
+    headers.insert(
+        WWW_AUTHENTICATE,
+        HeaderValue::from_static(
+            "Bearer realm=\"reddit\", error=\"insufficient_scope\", scope=\"history\"",
+        ),
+    );
+    assert_eq!(
+        insufficient_scope(&headers),
+        Some(Some("history".to_string()))
+    );
+    headers.insert(
+        WWW_AUTHENTICATE,
+        HeaderValue::from_static("Bearer realm=\"reddit\", error=\"invalid_token\""),
+    );
+    assert_eq!(insufficient_scope(&headers), None);
+}
+
+/// The cached bearer header must follow a token set directly on the authenticator
+#[test]
+fn bearer_follows_token() {
+    use rraw::auth::{Authenticator, TokenAuthenticator};
+
+    let mut auth = TokenAuthenticator::new("id", "secret", "refresh");
+    let mut headers = HeaderMap::new();
+    auth.headers(&mut headers);
+    assert!(headers.get(AUTHORIZATION).is_none());
+
+    auth.token = Some("first".to_string());
+    auth.headers(&mut headers);
+    assert_eq!(headers[AUTHORIZATION], "Bearer first");
+    assert!(headers[AUTHORIZATION].is_sensitive());
+
+    auth.token = Some("second".to_string());
+    auth.headers(&mut headers);
+    assert_eq!(headers[AUTHORIZATION], "Bearer second");
+    assert!(headers[AUTHORIZATION].is_sensitive());
+}
+
+#[test]
+fn invalidate_token() {
+    use rraw::auth::{Authenticator, PasswordAuthenticator, TokenAuthenticator};
+
+    let mut auth = PasswordAuthenticator::new("id", "secret", "user", "password");
+    auth.token = Some("token".to_string());
+    auth.expiration_time = Some(u128::MAX);
+    assert!(!auth.needs_token_refresh());
+    auth.invalidate_token();
+    assert_eq!(auth.token, None);
+    assert!(auth.needs_token_refresh());
+    let mut headers = HeaderMap::new();
+    auth.headers(&mut headers);
+    assert!(headers.get(AUTHORIZATION).is_none());
+
+    // The refresh token is kept so it can be refreshed
+    let mut auth = TokenAuthenticator::new("id", "secret", "refresh");
+    auth.token = Some("token".to_string());
+    auth.expiration_time = Some(u128::MAX);
+    auth.invalidate_token();
+    assert!(auth.needs_token_refresh());
+    assert_eq!(auth.get_refresh_token().as_deref(), Some("refresh"));
+}
@@ -0,0 +1,124 @@
+mod common;
+
+use std::time::Duration;
+
+use common::{mock_client, MockResponse, MockServer};
+use futures_util::future::join_all;
+use rraw::error::Error;
+use rraw::responses::RedditTypeResponse;
+use serde_json::Value;
+
+/// Answers `/api/info` with the submissions of the fixture that were asked for
+fn info(path: &str) -> MockResponse {
+    let ids = path.split("id=").nth(1).unwrap_or_default();
+    let mut listing: Value =
+        serde_json::from_str(include_str!("fixtures/submissions.json")).unwrap();
+    listing["data"]["children"]
+        .as_array_mut()
+        .unwrap()
+        .retain(|child| {
+            let name = child["data"]["name"].as_str().unwrap();
+            ids.split(',').any(|id| id == name)
+        });
+    MockResponse::json(listing)
+}
+
+#[tokio::test]
+async fn concurrent_gets_share_a_request() {
+    let server = MockServer::start(|request| Some(info(&request.path))).await;
+    let client = mock_client(&server).await;
+    let batcher = client.info_batcher(Duration::from_millis(20));
+
+    let fullnames: Vec<String> = (0..50)
+        .map(|i| match i % 3 {
+            0 => "t3_15bfi0".to_string(),
+            1 => "t3_15bfi1".to_string(),
+            _ => format!("t3_missing{i}"),
+        })
+        .collect();
+    let things = join_all(fullnames.iter().map(|fullname| batcher.get(fullname))).await;
+    assert_eq!(server.requests().len(), 1);
+    for (fullname, thing) in fullnames.iter().zip(things) {
+        match thing.unwrap() {
+            Some(RedditTypeResponse::Link(submission)) => assert_eq!(&submission.name, fullname),
+            Some(thing) => panic!("Expected a submission got {:?}", thing),
+            None => assert!(fullname.starts_with("t3_missing")),
+        }
+    }
+
+    // Over 100 fullnames are split
+    let fullnames: Vec<String> = (0..150).map(|i| format!("t3_missing{i}")).collect();
+    let things = join_all(fullnames.iter().map(|fullname| batcher.get(fullname))).await;
+    assert!(things.iter().all(|thing| matches!(thing, Ok(None))));
+    let requests = server.requests();
+    assert_eq!(requests.len(), 3);
+    assert_eq!(requests[1].path.matches("t3_").count(), 100);
+    assert_eq!(requests[2].path.matches("t3_").count(), 50);
+}
+
+#[tokio::test]
+async fn failure_reaches_every_caller() {
+    let server = MockServer::start(|_| Some(MockResponse::json("{}").status(500))).await;
+    let client = mock_client(&server).await;
+    let batcher = client.info_batcher(Duration::from_millis(20));
+
+    let things = join_all((0..10).map(|_| batcher.get("t3_15bfi0"))).await;
+    assert_eq!(server.requests().len(), 1);
+    for thing in things {
+        assert!(matches!(thing, Err(Error::Batch(_))), "{:?}", thing);
+    }
+}
+
+#[tokio::test]
+async fn dropped_leader_hands_over() {
+    let server = MockServer::start(|request| Some(info(&request.path))).await;
+    let client = mock_client(&server).await;
+    let batcher = client.info_batcher(Duration::from_millis(100));
+
+    let mut leader = Box::pin(batcher.get("t3_15bfi0"));
+    let mut follower = Box::pin(batcher.get("t3_15bfi1"));
+    // Both join the batch. Then the leader is dropped while waiting out the window
+    assert!(futures_util::poll!(&mut leader).is_pending());
+    assert!(futures_util::poll!(&mut follower).is_pending());
+    drop(leader);
+
+    let thing = tokio::time::timeout(Duration::from_secs(5), follower)
+        .await
+        .expect("The follower should send the batch")
+        .unwrap();
+    assert!(matches!(thing, Some(RedditTypeResponse::Link(_))));
+    assert_eq!(server.requests().len(), 1);
+}
+
+/// The leader hands over once its own fullname is sent. Even while callers arrive faster than one batch a request
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn leader_returns_under_steady_traffic() {
+    use futures_util::future::{select, Either};
+    use futures_util::StreamExt;
+
+    let server = MockServer::start(|request| {
+        std::thread::sleep(Duration::from_millis(10));
+        Some(info(&request.path))
+    })
+    .await;
+    let client = mock_client(&server).await;
+    let batcher = client.info_batcher(Duration::from_millis(20));
+
+    let first = tokio::time::timeout(Duration::from_secs(2), batcher.get("t3_15bfi0"));
+    let traffic = futures_util::stream::repeat(())
+        .then(|_| tokio::time::sleep(Duration::from_millis(2)))
+        .flat_map(|_| futures_util::stream::iter(0..30))
+        .map(|_| batcher.get("t3_15bfi1"))
+        .buffer_unordered(usize::MAX)
+        .for_each(|thing| async move {
+            assert!(matches!(thing, Ok(Some(RedditTypeResponse::Link(_)))));
+        });
+    futures_util::pin_mut!(first, traffic);
+    match select(first, traffic).await {
+        Either::Left((thing, _)) => {
+            let thing = thing.expect("The first get should return while callers keep arriving");
+            assert!(matches!(thing, Ok(Some(RedditTypeResponse::Link(_)))));
+        }
+        Either::Right(_) => unreachable!("The traffic never ends"),
+    }
+}
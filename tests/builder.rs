@@ -0,0 +1,240 @@
+mod common;
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use common::{MockResponse, MockServer, TestAuthenticator};
+use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION, USER_AGENT};
+use rraw::access::Access;
+use rraw::error::http_error::HTTPError;
+use rraw::error::Error;
+use rraw::Client;
+use serde_json::json;
+
+#[tokio::test]
+async fn connection_options() {
+    let server = MockServer::start(|_| Some(MockResponse::json(json!({"ok": true})))).await;
+    let client = Client::builder(TestAuthenticator, "RRAW Test (by u/KingTuxWH)")
+        .base_url(server.url.clone())
+        .pool_max_idle_per_host(4)
+        .tcp_keepalive(Duration::from_secs(30))
+        .login()
+        .await
+        .unwrap();
+    for _ in 0..3 {
+        client
+            .get_raw("/api/v1/me", Access::OAuthOnly)
+            .await
+            .unwrap();
+    }
+    assert_eq!(server.requests().len(), 3);
+}
+
+/// The mock server only speaks HTTP/1 so a client that skips negotiation can not talk to it
+#[tokio::test]
+async fn http2_prior_knowledge() {
+    let server = MockServer::start(|_| Some(MockResponse::json(json!({"ok": true})))).await;
+    let client = Client::builder(TestAuthenticator, "RRAW Test (by u/KingTuxWH)")
+        .base_url(server.url.clone())
+        .http2_prior_knowledge(true)
+        .login()
+        .await
+        .unwrap();
+    assert!(client
+        .get_raw("/api/v1/me", Access::OAuthOnly)
+        .await
+        .is_err());
+}
+
+/// Keeps every log message. Set as the logger once for the test binary
+struct CaptureLogger(Arc<Mutex<Vec<String>>>);
+
+impl log::Log for CaptureLogger {
+    fn enabled(&self, _metadata: &log::Metadata) -> bool {
+        true
+    }
+    fn log(&self, record: &log::Record) {
+        self.0.lock().unwrap().push(record.args().to_string());
+    }
+    fn flush(&self) {}
+}
+
+#[tokio::test]
+async fn log_bodies() {
+    let logs = Arc::new(Mutex::new(Vec::new()));
+    log::set_boxed_logger(Box::new(CaptureLogger(logs.clone()))).unwrap();
+    log::set_max_level(log::LevelFilter::Trace);
+
+    for (log_bodies, marker) in [(true, "logged body"), (false, "hidden body")] {
+        let server =
+            MockServer::start(move |_| Some(MockResponse::json(json!({ "marker": marker })))).await;
+        let client = Client::builder(TestAuthenticator, "RRAW Test (by u/KingTuxWH)")
+            .base_url(server.url.clone())
+            .log_bodies(log_bodies)
+            .login()
+            .await
+            .unwrap();
+        let value = client
+            .get_raw("/api/v1/me", Access::OAuthOnly)
+            .await
+            .unwrap();
+        assert_eq!(value["marker"], marker);
+    }
+
+    let logs = logs.lock().unwrap();
+    assert!(logs.iter().any(|message| message.contains("logged body")));
+    assert!(!logs.iter().any(|message| message.contains("hidden body")));
+}
+
+/// Gzips the body if the request accepts it
+fn gzip_server(request: &common::MockRequest) -> Option<MockResponse> {
+    use flate2::write::GzEncoder;
+    use flate2::Compression;
+    use std::io::Write;
+
+    let body = json!({"ok": true}).to_string();
+    if request.header("Accept-Encoding") != Some("gzip") {
+        return Some(MockResponse::json(body));
+    }
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(body.as_bytes()).unwrap();
+    let body = encoder.finish().unwrap();
+    Some(
+        MockResponse::bytes(body)
+            .header("Content-Type", "application/json")
+            .header("Content-Encoding", "gzip"),
+    )
+}
+
+#[tokio::test]
+async fn decompress() {
+    let server = MockServer::start(gzip_server).await;
+    let client = Client::builder(TestAuthenticator, "RRAW Test (by u/KingTuxWH)")
+        .base_url(server.url.clone())
+        .login()
+        .await
+        .unwrap();
+    let value = client
+        .get_raw("/api/v1/me", Access::OAuthOnly)
+        .await
+        .unwrap();
+    assert_eq!(value["ok"], true);
+    let bytes = client
+        .get_bytes("/api/v1/me", Access::OAuthOnly)
+        .await
+        .unwrap();
+    assert_eq!(&bytes[..], br#"{"ok":true}"#);
+    for request in server.requests() {
+        assert_eq!(request.header("Accept-Encoding"), Some("gzip"));
+    }
+}
+
+#[tokio::test]
+async fn raw_bytes() {
+    let server = MockServer::start(gzip_server).await;
+    let client = Client::builder(TestAuthenticator, "RRAW Test (by u/KingTuxWH)")
+        .base_url(server.url.clone())
+        .decompress(false)
+        .login()
+        .await
+        .unwrap();
+    // Parsed requests do not ask for gzip
+    let value = client
+        .get_raw("/api/v1/me", Access::OAuthOnly)
+        .await
+        .unwrap();
+    assert_eq!(value["ok"], true);
+    assert_eq!(server.requests()[0].header("Accept-Encoding"), None);
+
+    let bytes = client
+        .get_bytes("/api/v1/me", Access::OAuthOnly)
+        .await
+        .unwrap();
+    let expected = gzip_server(&server.requests()[1]).unwrap().body;
+    assert_eq!(&bytes[..], &expected[..]);
+    assert_eq!(&bytes[..2], &[0x1f, 0x8b]);
+}
+
+#[tokio::test]
+async fn raw_bytes_error() {
+    use flate2::write::GzEncoder;
+    use flate2::Compression;
+    use std::io::Write;
+
+    let server = MockServer::start(|_| {
+        let body = json!({"reason": "private", "message": "Forbidden", "error": 403});
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(body.to_string().as_bytes()).unwrap();
+        Some(
+            MockResponse::bytes(encoder.finish().unwrap())
+                .status(403)
+                .header("Content-Type", "application/json")
+                .header("Content-Encoding", "gzip"),
+        )
+    })
+    .await;
+    let client = Client::builder(TestAuthenticator, "RRAW Test (by u/KingTuxWH)")
+        .base_url(server.url.clone())
+        .decompress(false)
+        .login()
+        .await
+        .unwrap();
+    // The error body is decoded to find the reason
+    let error = client
+        .get_bytes("/r/secret/about.json", Access::OAuthOnly)
+        .await
+        .unwrap_err();
+    match error {
+        Error::HTTPError {
+            error: HTTPError::Private,
+            response,
+        } => assert_eq!(response.unwrap().message.as_deref(), Some("Forbidden")),
+        error => panic!("Expected Private got {:?}", error),
+    }
+}
+
+#[tokio::test]
+async fn default_headers() {
+    let server = MockServer::start(|_| Some(MockResponse::json(json!({"ok": true})))).await;
+    let mut headers = HeaderMap::new();
+    headers.insert("x-forwarded-for", HeaderValue::from_static("203.0.113.7"));
+    // The crate's own headers take precedence
+    headers.insert(AUTHORIZATION, HeaderValue::from_static("Bearer override"));
+    headers.insert(USER_AGENT, HeaderValue::from_static("override"));
+    let client = Client::builder(TestAuthenticator, "RRAW Test (by u/KingTuxWH)")
+        .base_url(server.url.clone())
+        .default_headers(headers)
+        .login()
+        .await
+        .unwrap();
+    client
+        .get_raw("/api/v1/me", Access::OAuthOnly)
+        .await
+        .unwrap();
+    client
+        .post_raw("/api/save", &[("id", "t3_15bfi0")], Access::OAuthOnly)
+        .await
+        .unwrap();
+
+    let mut traced = HeaderMap::new();
+    traced.insert("x-request-id", HeaderValue::from_static("export-42"));
+    client
+        .clone()
+        .with_default_headers(traced)
+        .get_raw("/api/v1/me", Access::OAuthOnly)
+        .await
+        .unwrap();
+
+    let requests = server.requests();
+    for request in &requests[..2] {
+        assert_eq!(request.header("x-forwarded-for"), Some("203.0.113.7"));
+        assert_eq!(request.header("authorization"), Some("Bearer test"));
+        assert_eq!(
+            request.header("user-agent"),
+            Some("RRAW Test (by u/KingTuxWH)")
+        );
+    }
+    // Headers on a clone replace the defaults
+    assert_eq!(requests[2].header("x-request-id"), Some("export-42"));
+    assert_eq!(requests[2].header("x-forwarded-for"), None);
+}
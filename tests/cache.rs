@@ -0,0 +1,111 @@
+mod common;
+
+use common::{mock_client, MockResponse, MockServer, TestAuthenticator};
+use rraw::Client;
+
+const USER_AGENT: &str = "RRAW Test (by u/KingTuxWH)";
+
+/// Answers with an `ETag` for the path and `304` if the request already has it
+fn etag_server(request: &common::MockRequest) -> Option<MockResponse> {
+    let etag = format!("\"{}\"", request.path);
+    if request.header("If-None-Match") == Some(etag.as_str()) {
+        return Some(MockResponse::json("").status(304));
+    }
+    Some(MockResponse::json(include_str!("fixtures/user_about.json")).header("ETag", &etag))
+}
+
+async fn cached_client(server: &MockServer, entries: usize) -> Client<TestAuthenticator> {
+    Client::builder(TestAuthenticator, USER_AGENT)
+        .base_url(server.url.clone())
+        .response_cache(entries)
+        .login()
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn not_modified_uses_cached_value() {
+    let server = MockServer::start(etag_server).await;
+    let client = cached_client(&server, 8).await;
+    let first = client.user("KingTuxWH").await.unwrap();
+    let second = client.user("KingTuxWH").await.unwrap();
+    assert_eq!(first.user.name, second.user.name);
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[0].header("If-None-Match"), None);
+    assert_eq!(
+        requests[1].header("If-None-Match"),
+        Some("\"/u/KingTuxWH/about\"")
+    );
+}
+
+#[tokio::test]
+async fn last_modified() {
+    let date = "Wed, 21 Oct 2015 07:28:00 GMT";
+    let server = MockServer::start(move |request| {
+        if request.header("If-Modified-Since") == Some(date) {
+            return Some(MockResponse::json("").status(304));
+        }
+        Some(
+            MockResponse::json(include_str!("fixtures/user_about.json"))
+                .header("Last-Modified", date),
+        )
+    })
+    .await;
+    let client = cached_client(&server, 8).await;
+    client.user("KingTuxWH").await.unwrap();
+    client.user("KingTuxWH").await.unwrap();
+    let requests = server.requests();
+    assert_eq!(requests[1].header("If-Modified-Since"), Some(date));
+    assert_eq!(requests[1].header("If-None-Match"), None);
+}
+
+#[tokio::test]
+async fn without_validators_nothing_is_kept() {
+    let server =
+        MockServer::start(|_| Some(MockResponse::json(include_str!("fixtures/user_about.json"))))
+            .await;
+    let client = cached_client(&server, 8).await;
+    client.user("KingTuxWH").await.unwrap();
+    client.user("KingTuxWH").await.unwrap();
+    for request in server.requests() {
+        assert_eq!(request.header("If-None-Match"), None);
+        assert_eq!(request.header("If-Modified-Since"), None);
+    }
+}
+
+#[tokio::test]
+async fn least_recently_used_is_dropped() {
+    let server = MockServer::start(etag_server).await;
+    let client = cached_client(&server, 1).await;
+    client.user("KingTuxWH").await.unwrap();
+    client.user("TheSmartKing").await.unwrap();
+    client.user("KingTuxWH").await.unwrap();
+    let requests = server.requests();
+    assert_eq!(requests.len(), 3);
+    assert_eq!(requests[2].header("If-None-Match"), None);
+}
+
+#[tokio::test]
+async fn disabled_by_default() {
+    let server = MockServer::start(etag_server).await;
+    let client = mock_client(&server).await;
+    client.user("KingTuxWH").await.unwrap();
+    client.user("KingTuxWH").await.unwrap();
+    assert_eq!(server.requests()[1].header("If-None-Match"), None);
+}
+
+#[tokio::test]
+async fn shared_between_clones() {
+    let server = MockServer::start(etag_server).await;
+    let client = cached_client(&server, 8).await;
+    client.user("KingTuxWH").await.unwrap();
+    let clone = client.clone();
+    clone.user("KingTuxWH").await.unwrap();
+    let requests = server.requests();
+    assert_eq!(
+        requests[1].header("If-None-Match"),
+        Some("\"/u/KingTuxWH/about\"")
+    );
+}
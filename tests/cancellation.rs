@@ -0,0 +1,44 @@
+mod common;
+
+use rraw::access::Access;
+use std::time::Duration;
+
+use common::{mock_client, MockResponse, MockServer};
+use serde_json::json;
+
+#[tokio::test]
+async fn dropped_request_leaves_client_usable() {
+    let server = MockServer::start(|request| {
+        if request.path.starts_with("/slow") {
+            None
+        } else {
+            Some(
+                MockResponse::json(json!({"ok": true}))
+                    .header("x-ratelimit-remaining", "99")
+                    .header("x-ratelimit-reset", "60"),
+            )
+        }
+    })
+    .await;
+    let client = mock_client(&server).await;
+
+    let slow = client.get_raw("/slow.json", Access::Any);
+    assert!(tokio::time::timeout(Duration::from_millis(200), slow)
+        .await
+        .is_err());
+
+    tokio::select! {
+        _ = client.get_raw("/slow.json", Access::OAuthOnly) => panic!("The slow request should never finish"),
+        _ = tokio::time::sleep(Duration::from_millis(100)) => {}
+    }
+
+    let value = client
+        .get_raw("/fast.json", Access::OAuthOnly)
+        .await
+        .unwrap();
+    assert_eq!(value["ok"], true);
+    assert_eq!(client.rate_limit().unwrap().remaining, 99.0);
+    let requests = server.requests();
+    assert_eq!(requests.len(), 3);
+    assert_eq!(requests[2].header("authorization"), Some("Bearer test"));
+}
@@ -0,0 +1,40 @@
+mod common;
+
+use common::{mock_client, MockResponse, MockServer};
+use rraw::error::Error;
+use rraw::submission::submit::{SubmitBuilder, SubmitKind};
+
+#[tokio::test]
+async fn captcha_required() {
+    let server = MockServer::start(|request| {
+        let body = match request.path.as_str() {
+            "/api/v1/me" => include_str!("fixtures/me.json"),
+            "/api/needs_captcha" => "true",
+            _ => include_str!("fixtures/api_errors/bad_captcha.json"),
+        };
+        Some(MockResponse::json(body))
+    })
+    .await;
+    let client = mock_client(&server).await;
+    let me = client.me().await.unwrap();
+    assert!(me.needs_captcha().await.unwrap());
+
+    let submit = SubmitBuilder::new("rraw", "RRAW 1.3 Released")
+        .kind(SubmitKind::SelfPost {
+            text: "Changelog".to_string(),
+        })
+        .build()
+        .unwrap();
+    let error = me.submit(submit).await.unwrap_err();
+    assert!(
+        matches!(
+            error,
+            Error::CaptchaRequired {
+                captcha: Some(_),
+                ..
+            }
+        ),
+        "{:?}",
+        error
+    );
+}
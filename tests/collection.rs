@@ -0,0 +1,130 @@
+mod common;
+
+use common::{mock_client, MockResponse, MockServer};
+use rraw::collection::response::Collection;
+use rraw::submission::response::SubmissionsResponse;
+use serde_json::{json, Value};
+
+const ID: &str = "a8c1c0b4-5b5e-4a4b-9f6d-0e5f0c1e2d3f";
+
+fn collection_server() -> impl Fn(&common::MockRequest) -> Option<MockResponse> {
+    |request| {
+        let path = request.path.as_str();
+        let body = if path.starts_with("/api/v1/collections/collection?") {
+            include_str!("fixtures/collection.json").to_string()
+        } else if path.starts_with("/api/v1/collections/subreddit_collections") {
+            let mut collection: Value =
+                serde_json::from_str(include_str!("fixtures/collection.json")).unwrap();
+            collection.as_object_mut().unwrap().remove("sorted_links");
+            json!([collection]).to_string()
+        } else if path == "/api/v1/collections/create_collection" {
+            let mut collection: Value =
+                serde_json::from_str(include_str!("fixtures/collection.json")).unwrap();
+            collection.as_object_mut().unwrap().remove("sorted_links");
+            collection["link_ids"] = json!([]);
+            collection.to_string()
+        } else if path == "/r/rraw/about.json" {
+            include_str!("fixtures/subreddit_about.json").to_string()
+        } else if path == "/api/v1/me" {
+            include_str!("fixtures/me.json").to_string()
+        } else {
+            "{}".to_string()
+        };
+        Some(MockResponse::json(body))
+    }
+}
+
+#[tokio::test]
+async fn collection() {
+    let server = MockServer::start(collection_server()).await;
+    let client = mock_client(&server).await;
+    let collection = client.collection(ID).await.unwrap();
+    assert_eq!(collection.title, "Releases");
+    assert_eq!(collection.link_ids, vec!["t3_15bfi0", "t3_15bfi1"]);
+    let names: Vec<&str> = collection
+        .submissions()
+        .map(|submission| submission.name.as_str())
+        .collect();
+    assert_eq!(names, collection.link_ids);
+    assert_eq!(
+        server.requests()[0].path,
+        format!("/api/v1/collections/collection?collection_id={ID}&include_links=true")
+    );
+
+    let subreddit = client.subreddit("rraw").await.unwrap();
+    let collections = subreddit.collections().await.unwrap();
+    assert_eq!(collections.len(), 1);
+    assert_eq!(collections[0].submissions().count(), 0);
+    assert_eq!(
+        server.requests()[2].path,
+        "/api/v1/collections/subreddit_collections?sr_fullname=t5_2s4x6"
+    );
+}
+
+#[tokio::test]
+async fn manage() {
+    let server = MockServer::start(collection_server()).await;
+    let client = mock_client(&server).await;
+    let subreddit = client.subreddit("rraw").await.unwrap();
+    let created = subreddit
+        .create_collection("Releases", "Every RRAW release")
+        .await
+        .unwrap();
+    assert_eq!(created.collection_id, ID);
+
+    let me = client.me().await.unwrap();
+    let collections = me.collections();
+    collections.add_post(ID, "t3_15bfi0").await.unwrap();
+    collections.remove_post(ID, "15bfi1").await.unwrap();
+    collections
+        .reorder(ID, &["t3_15bfi1", "15bfi0"])
+        .await
+        .unwrap();
+    collections.delete_collection(ID).await.unwrap();
+
+    let requests = server.requests();
+    let bodies: Vec<(&str, &str)> = requests
+        .iter()
+        .filter(|request| request.method == "POST")
+        .map(|request| (request.path.as_str(), request.body.as_str()))
+        .collect();
+    assert_eq!(
+        bodies,
+        vec![
+            (
+                "/api/v1/collections/create_collection",
+                "sr_fullname=t5_2s4x6&title=Releases&description=Every+RRAW+release"
+            ),
+            (
+                "/api/v1/collections/add_post_to_collection",
+                "collection_id=a8c1c0b4-5b5e-4a4b-9f6d-0e5f0c1e2d3f&link_fullname=t3_15bfi0"
+            ),
+            (
+                "/api/v1/collections/remove_post_in_collection",
+                "collection_id=a8c1c0b4-5b5e-4a4b-9f6d-0e5f0c1e2d3f&link_fullname=t3_15bfi1"
+            ),
+            (
+                "/api/v1/collections/reorder_collection",
+                "collection_id=a8c1c0b4-5b5e-4a4b-9f6d-0e5f0c1e2d3f&link_ids=t3_15bfi1%2Ct3_15bfi0"
+            ),
+            (
+                "/api/v1/collections/delete_collection",
+                "collection_id=a8c1c0b4-5b5e-4a4b-9f6d-0e5f0c1e2d3f"
+            ),
+        ]
+    );
+}
+
+#[test]
+fn submission_collections() {
+    let mut listing: Value =
+        serde_json::from_str(include_str!("fixtures/submissions.json")).unwrap();
+    let mut collection: Value =
+        serde_json::from_str(include_str!("fixtures/collection.json")).unwrap();
+    collection.as_object_mut().unwrap().remove("sorted_links");
+    listing["data"]["children"][0]["data"]["collections"] = json!([collection]);
+    let listing: SubmissionsResponse = serde_json::from_value(listing).unwrap();
+    let collections: &Vec<Collection> = &listing.data.children[0].data.collections;
+    assert_eq!(collections[0].collection_id, ID);
+    assert!(listing.data.children[1].data.collections.is_empty());
+}
@@ -0,0 +1,117 @@
+mod common;
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use common::{mock_client, MockResponse, MockServer};
+use futures_util::StreamExt;
+use rraw::error::Error;
+use rraw::submission::SubmissionRetriever;
+use rraw::subreddit::combined::MAX_COMBINED_LENGTH;
+use rraw::utils::options::SubmissionSort;
+use serde_json::{json, Value};
+
+fn listing(ids: &[&str]) -> String {
+    let fixture: Value = serde_json::from_str(include_str!("fixtures/submissions.json")).unwrap();
+    let children: Vec<Value> = ids
+        .iter()
+        .map(|id| {
+            let mut child = fixture["data"]["children"][0].clone();
+            child["data"]["id"] = json!(id);
+            child["data"]["name"] = json!(format!("t3_{id}"));
+            child
+        })
+        .collect();
+    json!({
+        "kind": "Listing",
+        "data": {"modhash": null, "after": null, "before": null, "children": children}
+    })
+    .to_string()
+}
+
+#[tokio::test]
+async fn names() {
+    let server = MockServer::start(|_| None).await;
+    let client = mock_client(&server).await;
+    let subreddits = client
+        .subreddits(&["rust", "programming", "Rust", "cpp"])
+        .unwrap();
+    assert_eq!(subreddits.joined(), "rust+programming+cpp");
+    assert_eq!(subreddits.names().len(), 3);
+
+    for invalid in [
+        vec!["rust", "r/programming"],
+        vec!["rust+cpp"],
+        vec!["_rust"],
+        vec!["a"],
+        vec!["this_name_is_too_long_for_reddit"],
+    ] {
+        let error = client.subreddits(&invalid).err().unwrap();
+        assert!(matches!(error, Error::InvalidOption(_)), "{:?}", invalid);
+    }
+    let empty: [&str; 0] = [];
+    assert!(client.subreddits(&empty).is_err());
+    let many: Vec<String> = (0..MAX_COMBINED_LENGTH / 10)
+        .map(|index| format!("subreddit{index}"))
+        .collect();
+    assert!(client.subreddits(&many).is_err());
+    assert!(server.requests().is_empty());
+}
+
+#[tokio::test]
+async fn listings() {
+    let server = MockServer::start(|request| {
+        let body = match request.path.split('?').next().unwrap() {
+            "/r/rust+programming/comments.json" => {
+                include_str!("fixtures/comments.json").to_string()
+            }
+            _ => listing(&["a", "b"]),
+        };
+        Some(MockResponse::json(body))
+    })
+    .await;
+    let client = mock_client(&server).await;
+    let subreddits = client.subreddits(&["rust", "programming"]).unwrap();
+    let submissions = subreddits
+        .get_submissions(SubmissionSort::New, None)
+        .await
+        .unwrap();
+    assert_eq!(submissions.len(), 2);
+    let comments = subreddits.recent_comments(None).await.unwrap();
+    assert!(!comments.is_empty());
+    let requests = server.requests();
+    assert_eq!(requests[0].path, "/r/rust+programming/new");
+    assert_eq!(requests[1].path, "/r/rust+programming/comments.json");
+}
+
+#[tokio::test]
+async fn stream_by_fullname() {
+    // Each poll interleaves the subreddits differently. Only unseen fullnames are new
+    let polls = [
+        vec!["c", "b", "a"],
+        vec!["d", "b", "c", "a"],
+        vec!["e", "c", "f", "d", "b"],
+    ];
+    let count = Arc::new(AtomicUsize::new(0));
+    let server_count = count.clone();
+    let server = MockServer::start(move |_| {
+        let poll = server_count
+            .fetch_add(1, Ordering::SeqCst)
+            .min(polls.len() - 1);
+        Some(MockResponse::json(listing(&polls[poll])))
+    })
+    .await;
+    let client = mock_client(&server).await;
+    let subreddits = client.subreddits(&["rust", "programming"]).unwrap();
+    let ids: Vec<String> = subreddits
+        .stream_submissions(Duration::from_millis(10))
+        .take(3)
+        .map(|submission| submission.unwrap().id)
+        .collect()
+        .await;
+    assert_eq!(ids, vec!["d", "f", "e"]);
+    assert!(server.requests()[0]
+        .path
+        .starts_with("/r/rust+programming/new?limit=100"));
+}
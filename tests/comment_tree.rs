@@ -0,0 +1,405 @@
+mod common;
+
+use common::{mock_client, MockResponse, MockServer};
+use rraw::comments::response::CommentResponse;
+use rraw::comments::tree::{CommentNode, CommentTree, MoreComments};
+use rraw::submission::SubmissionType;
+use rraw::utils::options::{CommentOption, CommentSort};
+use serde::de::IgnoredAny;
+use serde_json::json;
+
+const TREE: &str = include_str!("fixtures/comment_tree.json");
+
+fn tree() -> CommentTree {
+    let (_, tree): (IgnoredAny, CommentTree) = serde_json::from_str(TREE).unwrap();
+    tree
+}
+
+#[test]
+fn pre_order() {
+    let tree = tree();
+    let bodies: Vec<&str> = tree.iter().map(|comment| comment.body.as_str()).collect();
+    assert_eq!(bodies, vec!["A", "B", "C", "D", "E"]);
+    assert_eq!(tree.len(), 5);
+    let more: Vec<&str> = tree.more().map(|more| more.id.as_str()).collect();
+    assert_eq!(more, vec!["m1", "_"]);
+    assert!(matches!(tree.nodes[2], CommentNode::More(_)));
+}
+
+#[test]
+fn flatten() {
+    let flattened = tree().flatten();
+    let ids: Vec<&str> = flattened
+        .iter()
+        .map(|comment| comment.id.as_str())
+        .collect();
+    assert_eq!(ids, vec!["a", "b", "c3", "d", "e"]);
+    assert!(flattened.iter().all(|comment| comment.replies.is_empty()));
+}
+
+#[test]
+fn round_trip() {
+    let tree = tree();
+    let value = serde_json::to_value(&tree).unwrap();
+    assert_eq!(value["kind"], "Listing");
+    assert_eq!(value["data"]["children"][1]["data"]["replies"], "");
+    let parsed: CommentTree = serde_json::from_value(value).unwrap();
+    assert_eq!(parsed, tree);
+}
+
+#[tokio::test]
+async fn comment_tree() {
+    let server = MockServer::start(|_| Some(MockResponse::json(TREE))).await;
+    let client = mock_client(&server).await;
+    let permalink = "/r/rust/comments/15bfi0/rraw_release/".to_string();
+    let tree = permalink
+        .to_submission(&client)
+        .comment_tree(None)
+        .await
+        .unwrap();
+    assert_eq!(tree.len(), 5);
+}
+
+#[test]
+fn completeness() {
+    let tree = tree();
+    assert!(!tree.is_complete());
+    assert_eq!(tree.missing_count(), 12);
+    assert!(CommentTree::default().is_complete());
+}
+
+#[tokio::test]
+async fn comment_tree_options() {
+    let server = MockServer::start(|_| Some(MockResponse::json(TREE))).await;
+    let client = mock_client(&server).await;
+    let options = CommentOption {
+        sort: Some(CommentSort::Top),
+        depth: Some(3),
+        limit: Some(100),
+        truncate: Some(5),
+        show_more: Some(false),
+    };
+    "/r/rust/comments/15bfi0/rraw_release/"
+        .to_string()
+        .to_submission(&client)
+        .comment_tree(Some(options))
+        .await
+        .unwrap();
+    assert_eq!(
+        server.requests()[0].path,
+        "/r/rust/comments/15bfi0/rraw_release/?sort=top&depth=3&limit=100&truncate=5&showmore=false"
+    );
+}
+
+#[test]
+fn depth_and_parents() {
+    let tree = tree();
+    let depths: Vec<(&str, u32)> = tree
+        .iter()
+        .map(|comment| (comment.id.as_str(), comment.depth))
+        .collect();
+    assert_eq!(
+        depths,
+        vec![("a", 0), ("b", 1), ("c3", 2), ("d", 1), ("e", 0)]
+    );
+    for comment in tree.iter() {
+        assert_eq!(comment.link_id, "t3_15bfi0");
+        assert_eq!(comment.is_top_level(), comment.depth == 0);
+        if comment.depth == 0 {
+            assert_eq!(comment.parent_id, "t3_15bfi0");
+        }
+    }
+    // The structure can be rebuilt after flattening
+    let flattened = tree.flatten();
+    let b = flattened.iter().find(|comment| comment.id == "b").unwrap();
+    let c = flattened.iter().find(|comment| comment.id == "c3").unwrap();
+    assert_eq!(c.parent_id, b.fullname());
+    assert_eq!(c.depth, b.depth + 1);
+}
+
+#[test]
+fn walk_orders() {
+    let tree = tree();
+    let mut depth_first = tree.iter_depth_first();
+    let walked: Vec<(&str, usize)> = depth_first
+        .by_ref()
+        .map(|(comment, depth)| (comment.id.as_str(), depth))
+        .collect();
+    assert_eq!(
+        walked,
+        vec![("a", 0), ("b", 1), ("c3", 2), ("d", 1), ("e", 0)]
+    );
+    assert_eq!(depth_first.more().len(), 2);
+
+    let mut breadth_first = tree.iter_breadth_first();
+    let order: Vec<&str> = breadth_first
+        .by_ref()
+        .map(|(comment, _)| comment.id.as_str())
+        .collect();
+    assert_eq!(order, vec!["a", "e", "b", "d", "c3"]);
+    assert_eq!(breadth_first.more().len(), 2);
+    assert!(tree.find(|comment| comment.body == "Z").is_none());
+}
+
+fn comment(id: &str, parent_id: &str, replies: Vec<CommentNode>) -> CommentNode {
+    let mut comment: CommentResponse = serde_json::from_value(json!({
+        "link_id": "t3_root",
+        "id": id,
+        "name": format!("t1_{id}"),
+        "parent_id": parent_id,
+        "score": 1.0,
+        "subreddit": "rust",
+        "body": id,
+    }))
+    .unwrap();
+    comment.replies = replies;
+    CommentNode::Comment(Box::new(comment))
+}
+
+fn more(id: &str, parent_id: &str) -> CommentNode {
+    CommentNode::More(MoreComments {
+        id: id.to_string(),
+        name: format!("t1_{id}"),
+        parent_id: parent_id.to_string(),
+        depth: 0,
+        count: 1,
+        children: vec![id.to_string()],
+        link_id: None,
+    })
+}
+
+/// A small linear congruential generator so the generated trees are reproducible
+struct Lcg(u64);
+
+impl Lcg {
+    fn below(&mut self, bound: u64) -> u64 {
+        self.0 = self
+            .0
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (self.0 >> 33) % bound
+    }
+}
+
+/// Generates the nodes under `parent_id` and counts the comments and placeholders
+fn generate(
+    rng: &mut Lcg,
+    parent_id: &str,
+    depth: usize,
+    counts: &mut (usize, usize),
+) -> Vec<CommentNode> {
+    let width = if depth > 5 { 0 } else { rng.below(4) };
+    (0..width)
+        .map(|index| {
+            let id = format!(
+                "{}{index}",
+                parent_id
+                    .trim_start_matches("t3_")
+                    .trim_start_matches("t1_")
+            );
+            if rng.below(6) == 0 {
+                counts.1 += 1;
+                more(&format!("m{id}"), parent_id)
+            } else {
+                counts.0 += 1;
+                let replies = generate(rng, &format!("t1_{id}"), depth + 1, counts);
+                comment(&id, parent_id, replies)
+            }
+        })
+        .collect()
+}
+
+/// The expected pre-order. Recursive, the generated trees are shallow
+fn reference(nodes: &[CommentNode], depth: usize, out: &mut Vec<(String, usize)>) {
+    for node in nodes {
+        if let CommentNode::Comment(comment) = node {
+            out.push((comment.id.clone(), depth));
+            reference(&comment.replies, depth + 1, out);
+        }
+    }
+}
+
+#[test]
+fn generated_trees() {
+    let mut rng = Lcg(42);
+    for _ in 0..200 {
+        let mut counts = (0, 0);
+        let tree = CommentTree {
+            nodes: generate(&mut rng, "t3_root", 0, &mut counts),
+        };
+        let mut expected = Vec::new();
+        reference(&tree.nodes, 0, &mut expected);
+        assert_eq!(expected.len(), counts.0);
+
+        let mut depth_first = tree.iter_depth_first();
+        let walked: Vec<(String, usize)> = depth_first
+            .by_ref()
+            .map(|(comment, depth)| (comment.id.clone(), depth))
+            .collect();
+        assert_eq!(walked, expected);
+        assert_eq!(depth_first.more().len(), counts.1);
+        assert_eq!(tree.more().count(), counts.1);
+
+        let mut breadth_first = tree.iter_breadth_first();
+        let mut levels: Vec<(String, usize)> = breadth_first
+            .by_ref()
+            .map(|(comment, depth)| (comment.id.clone(), depth))
+            .collect();
+        assert_eq!(breadth_first.more().len(), counts.1);
+        assert!(levels.windows(2).all(|pair| pair[0].1 <= pair[1].1));
+        levels.sort();
+        let mut sorted = expected.clone();
+        sorted.sort();
+        assert_eq!(levels, sorted);
+
+        let flattened = tree.flatten();
+        let ids: Vec<&str> = flattened.iter().map(|c| c.id.as_str()).collect();
+        let expected_ids: Vec<&str> = expected.iter().map(|(id, _)| id.as_str()).collect();
+        assert_eq!(ids, expected_ids);
+        // Every parent comes before its replies
+        for (index, comment) in flattened.iter().enumerate() {
+            if comment.parent_id != "t3_root" {
+                assert!(flattened[..index]
+                    .iter()
+                    .any(|parent| parent.fullname() == comment.parent_id));
+            }
+        }
+    }
+}
+
+#[test]
+fn deep_chain() {
+    const DEPTH: usize = 10_000;
+    let mut node = more("end", "t1_c9999");
+    for depth in (0..DEPTH).rev() {
+        let parent = if depth == 0 {
+            "t3_root".to_string()
+        } else {
+            format!("t1_c{}", depth - 1)
+        };
+        node = comment(&format!("c{depth}"), &parent, vec![node]);
+    }
+    let tree = CommentTree { nodes: vec![node] };
+    let mut depth_first = tree.iter_depth_first();
+    assert_eq!(depth_first.by_ref().last().unwrap().1, DEPTH - 1);
+    assert_eq!(depth_first.more().len(), 1);
+    assert_eq!(tree.iter_breadth_first().count(), DEPTH);
+    assert_eq!(
+        tree.find(|c| c.id == "c9999").unwrap().parent_id,
+        "t1_c9998"
+    );
+    let flattened = tree.flatten();
+    assert_eq!(flattened.len(), DEPTH);
+    assert!(flattened.iter().all(|c| c.replies.is_empty()));
+}
+
+#[test]
+fn more_link_ids() {
+    let tree = tree();
+    assert!(tree
+        .more()
+        .all(|more| more.link_id.as_deref() == Some("t3_15bfi0")));
+    let (top, nested): (Vec<&MoreComments>, Vec<&MoreComments>) =
+        tree.more().partition(|more| more.is_top_level());
+    assert_eq!(top[0].id, "m1");
+    assert!(nested[0].is_continue_thread());
+}
+
+#[tokio::test]
+async fn load_more() {
+    let server = MockServer::start(|_| {
+        Some(MockResponse::json(include_str!(
+            "fixtures/morechildren.json"
+        )))
+    })
+    .await;
+    let client = mock_client(&server).await;
+    let mut tree = tree();
+    let more = tree
+        .more()
+        .find(|more| more.is_top_level())
+        .cloned()
+        .unwrap();
+    let nodes = more.load_more(&client).await.unwrap();
+    assert_eq!(
+        server.requests()[0].path,
+        "/api/morechildren?api_type=json&link_id=t3_15bfi0&children=m1%2Cm2&limit_children=false"
+    );
+    let ids: Vec<&str> = nodes
+        .iter()
+        .map(|node| match node {
+            CommentNode::Comment(comment) => comment.id.as_str(),
+            CommentNode::More(more) => more.id.as_str(),
+        })
+        .collect();
+    assert_eq!(ids, vec!["m1", "m2"]);
+    let m1 = match &nodes[0] {
+        CommentNode::Comment(comment) => comment,
+        CommentNode::More(_) => panic!("m1 is a comment"),
+    };
+    assert_eq!(m1.replies.len(), 2);
+    let nested = match &m1.replies[1] {
+        CommentNode::More(more) => more,
+        CommentNode::Comment(_) => panic!("m4 is a placeholder"),
+    };
+    assert_eq!(nested.link_id.as_deref(), Some("t3_15bfi0"));
+
+    assert!(tree.replace_more(&more, nodes));
+    assert!(!tree.replace_more(&more, Vec::new()));
+    let bodies: Vec<&str> = tree.iter().map(|comment| comment.body.as_str()).collect();
+    assert_eq!(bodies, vec!["A", "B", "C", "D", "E", "M1", "M3", "M2"]);
+    let more: Vec<&str> = tree.more().map(|more| more.id.as_str()).collect();
+    assert_eq!(more, vec!["_", "m4"]);
+}
+
+#[tokio::test]
+async fn load_more_chunks() {
+    let server = MockServer::start(|_| {
+        Some(MockResponse::json(
+            json!({"json": {"errors": [], "data": {"things": []}}}).to_string(),
+        ))
+    })
+    .await;
+    let client = mock_client(&server).await;
+    let more = MoreComments {
+        id: "m0".to_string(),
+        name: "t1_m0".to_string(),
+        parent_id: "t3_root".to_string(),
+        depth: 0,
+        count: 250,
+        children: (0..250).map(|index| format!("m{index}")).collect(),
+        link_id: None,
+    };
+    assert!(more.load_more(&client).await.unwrap().is_empty());
+    let sizes: Vec<usize> = server
+        .requests()
+        .iter()
+        .map(|request| {
+            let children = request.path.split("children=").nth(1).unwrap();
+            let children = children.split('&').next().unwrap();
+            children.split("%2C").count()
+        })
+        .collect();
+    assert_eq!(sizes, vec![100, 100, 50]);
+    assert!(server.requests()[0].path.contains("link_id=t3_root"));
+}
+
+#[tokio::test]
+async fn load_continue_thread() {
+    let server = MockServer::start(|_| {
+        let parent = comment("c3", "t1_b", vec![comment("f", "t1_c3", Vec::new())]);
+        let listing = |children: Vec<CommentNode>| {
+            json!({"kind": "Listing", "data": {"modhash": null, "after": null, "before": null, "children": children}})
+        };
+        Some(MockResponse::json(
+            json!([listing(Vec::new()), listing(vec![parent])]).to_string(),
+        ))
+    })
+    .await;
+    let client = mock_client(&server).await;
+    let tree = tree();
+    let more = tree.more().find(|more| more.is_continue_thread()).unwrap();
+    let nodes = more.load_more(&client).await.unwrap();
+    assert_eq!(server.requests()[0].path, "/comments/15bfi0/_/c3");
+    assert!(matches!(&nodes[..], [CommentNode::Comment(comment)] if comment.id == "f"));
+}
@@ -0,0 +1,192 @@
+mod common;
+
+use common::{mock_client, MockResponse, MockServer};
+use rraw::comments::response::{CommentResponse, CommentsResponse};
+use rraw::comments::CommentType;
+use rraw::error::Error;
+use rraw::responses::api::{JsonResponse, Things};
+use rraw::responses::types::Distinguished;
+use rraw::responses::GenericResponse;
+use rraw::thing::{Savable, Votable};
+use serde_json::Value;
+
+#[test]
+fn edited() {
+    let response: JsonResponse<Things<GenericResponse<CommentResponse>>> =
+        serde_json::from_str(include_str!("fixtures/edit_comment.json")).unwrap();
+    let things = response.into_result().unwrap().unwrap();
+    let comment = &things.things[0].data;
+    assert_eq!(comment.edited, Some(1690000900.0));
+    assert_eq!(comment.body, "Edited: thanks for trying it out");
+    assert_eq!(comment.fullname(), "t1_jtq1a2b");
+}
+
+#[test]
+fn not_edited() {
+    let listing: CommentsResponse =
+        serde_json::from_str(include_str!("fixtures/comments.json")).unwrap();
+    assert!(listing
+        .data
+        .children
+        .iter()
+        .all(|c| c.data.edited.is_none()));
+    let value = serde_json::to_value(&listing.data.children[0].data).unwrap();
+    assert_eq!(value["edited"], serde_json::Value::Bool(false));
+}
+
+#[test]
+fn distinguished() {
+    let listing: CommentsResponse =
+        serde_json::from_str(include_str!("fixtures/comments.json")).unwrap();
+    let comment = &listing.data.children[0].data;
+    assert_eq!(comment.distinguished, Some(Distinguished::Moderator));
+    assert!(comment.stickied);
+    assert!(comment.is_mod_comment());
+
+    let comment = &listing.data.children[1].data;
+    assert!(!comment.stickied);
+    assert!(!comment.is_submitter);
+    assert!(!comment.is_mod_comment());
+
+    let mut value = serde_json::to_value(comment).unwrap();
+    value["distinguished"] = Value::from("admin");
+    value["is_submitter"] = Value::Bool(true);
+    let comment: CommentResponse = serde_json::from_value(value).unwrap();
+    assert!(comment.is_submitter);
+    assert_eq!(comment.distinguished, Some(Distinguished::Admin));
+    assert!(!comment.is_mod_comment());
+}
+
+#[test]
+fn permalink() {
+    let listing: CommentsResponse =
+        serde_json::from_str(include_str!("fixtures/comments.json")).unwrap();
+    let mut comment = listing.data.children[0].data.clone();
+    assert_eq!(
+        comment.permalink_url(),
+        "https://www.reddit.com/r/rust/comments/15bfi0/rraw_release/jtq1a2b/"
+    );
+    assert_eq!(
+        comment.context_url(3),
+        "https://www.reddit.com/r/rust/comments/15bfi0/rraw_release/jtq1a2b/?context=3"
+    );
+    assert!(comment.context_url(20).ends_with("?context=8"));
+    comment.permalink = String::new();
+    assert_eq!(
+        comment.permalink_url(),
+        "https://www.reddit.com/comments/15bfi0/_/jtq1a2b/"
+    );
+
+    let mut value = serde_json::to_value(&listing.data.children[0].data).unwrap();
+    value.as_object_mut().unwrap().remove("permalink");
+    let comment: CommentResponse = serde_json::from_value(value).unwrap();
+    assert!(comment.permalink.is_empty());
+}
+
+#[tokio::test]
+async fn comment_context() {
+    let server = MockServer::start(|_| {
+        let submissions: Value =
+            serde_json::from_str(include_str!("fixtures/submissions.json")).unwrap();
+        let comments: Value = serde_json::from_str(include_str!("fixtures/comments.json")).unwrap();
+        Some(MockResponse::json(Value::Array(vec![
+            submissions,
+            comments,
+        ])))
+    })
+    .await;
+    let client = mock_client(&server).await;
+    let (submission, comments) = client
+        .comment_context("t3_15bfi0", "t1_jtq1a2b", 20)
+        .await
+        .unwrap();
+    assert_eq!(submission.id, "15bfi0");
+    assert_eq!(comments.nodes.len(), 2);
+    assert!(comments.find(|comment| comment.id == "jtq1a2b").is_some());
+    client
+        .comment_context("15bfi0", "jtq1a2b", 3)
+        .await
+        .unwrap();
+    let requests = server.requests();
+    assert_eq!(
+        requests[0].path,
+        "/comments/15bfi0/_/jtq1a2b.json?context=8"
+    );
+    assert_eq!(
+        requests[1].path,
+        "/comments/15bfi0/_/jtq1a2b.json?context=3"
+    );
+}
+
+#[tokio::test]
+async fn sticky() {
+    let server =
+        MockServer::start(|_| Some(MockResponse::json(r#"{"json": {"errors": []}}"#))).await;
+    let client = mock_client(&server).await;
+    let listing: CommentsResponse =
+        serde_json::from_str(include_str!("fixtures/comments.json")).unwrap();
+    listing.data.children[0]
+        .data
+        .to_comment(&client)
+        .sticky()
+        .await
+        .unwrap();
+    let request = &server.requests()[0];
+    assert_eq!(request.path, "/api/distinguish");
+    assert_eq!(
+        request.body,
+        "api_type=json&id=t1_jtq1a2b&how=yes&sticky=true"
+    );
+
+    // A reply can not be stickied
+    let error = listing.data.children[1]
+        .data
+        .to_comment(&client)
+        .sticky()
+        .await
+        .unwrap_err();
+    assert!(matches!(error, Error::InvalidOption(_)), "{:?}", error);
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[tokio::test]
+async fn send_replies() {
+    let server = MockServer::start(|_| Some(MockResponse::json("{}"))).await;
+    let client = mock_client(&server).await;
+    let listing: CommentsResponse =
+        serde_json::from_str(include_str!("fixtures/comments.json")).unwrap();
+    let comment = listing.data.children[1].data.to_comment(&client);
+    comment.set_send_replies(true).await.unwrap();
+    assert_eq!(server.requests()[0].body, "id=t1_jtq1a2c&state=true");
+}
+
+#[tokio::test]
+async fn bound() {
+    let server = MockServer::start(|_| Some(MockResponse::json("{}"))).await;
+    let client = mock_client(&server).await;
+    let listing: CommentsResponse =
+        serde_json::from_str(include_str!("fixtures/comments.json")).unwrap();
+    let comments: Vec<_> = listing.bound(&client).collect();
+    assert_eq!(comments.len(), 2);
+    comments[1].downvote().await.unwrap();
+    comments[1].unsave().await.unwrap();
+    let requests = server.requests();
+    assert_eq!(requests[0].path, "/api/vote");
+    assert_eq!(requests[0].body, "id=t1_jtq1a2c&dir=-1");
+    assert_eq!(requests[1].path, "/api/unsave");
+    assert_eq!(requests[1].body, "id=t1_jtq1a2c");
+}
+
+#[tokio::test]
+async fn author_user() {
+    let server = MockServer::start(|_| Some(MockResponse::json("{}"))).await;
+    let client = mock_client(&server).await;
+    let listing: CommentsResponse =
+        serde_json::from_str(include_str!("fixtures/comments.json")).unwrap();
+    let comments: Vec<_> = listing.bound(&client).collect();
+    let author = comments[0].author_user().unwrap();
+    assert_eq!(author.user.name, "KingTuxWH");
+    assert_eq!(author.user.id, "3s8a2");
+    assert!(comments[1].author_user().is_none());
+    assert!(server.requests().is_empty());
+}
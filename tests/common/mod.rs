@@ -0,0 +1,210 @@
+#![allow(dead_code)]
+use async_trait::async_trait;
+use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION};
+use reqwest::Client as ReqwestClient;
+use rraw::auth::{Authenticator, Authorized};
+use rraw::error::Error;
+use rraw::Client;
+
+/// An Authorized authenticator with a fixed token. Logging in does not make a request
+#[derive(Clone, Debug, Default)]
+pub struct TestAuthenticator;
+
+#[async_trait(?Send)]
+impl Authenticator for TestAuthenticator {
+    async fn login(&mut self, _client: &ReqwestClient, _user_agent: &str) -> Result<bool, Error> {
+        Ok(true)
+    }
+    async fn logout(&mut self, _client: &ReqwestClient, _user_agent: &str) -> Result<(), Error> {
+        Ok(())
+    }
+    async fn token_refresh(
+        &mut self,
+        _client: &ReqwestClient,
+        _user_agent: &str,
+    ) -> Result<bool, Error> {
+        Ok(true)
+    }
+    fn headers(&self, headers: &mut HeaderMap) {
+        headers.insert(AUTHORIZATION, HeaderValue::from_static("Bearer test"));
+    }
+    fn oauth(&self) -> bool {
+        true
+    }
+    fn needs_token_refresh(&self) -> bool {
+        false
+    }
+    fn get_refresh_token(&self) -> Option<String> {
+        None
+    }
+}
+
+impl Authorized for TestAuthenticator {}
+
+pub async fn test_client() -> Client<TestAuthenticator> {
+    Client::login(TestAuthenticator, "RRAW Test (by u/KingTuxWH)")
+        .await
+        .expect("The TestAuthenticator does not make requests")
+}
+
+/// A request received by the [MockServer]
+#[derive(Clone, Debug)]
+pub struct MockRequest {
+    pub method: String,
+    pub path: String,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl MockRequest {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// The response the [MockServer] sends
+pub struct MockResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+impl MockResponse {
+    pub fn json(body: impl ToString) -> MockResponse {
+        MockResponse {
+            status: 200,
+            headers: vec![("Content-Type".to_string(), "application/json".to_string())],
+            body: body.to_string().into_bytes(),
+        }
+    }
+    /// A body sent exactly as given
+    pub fn bytes(body: Vec<u8>) -> MockResponse {
+        MockResponse {
+            status: 200,
+            headers: Vec::new(),
+            body,
+        }
+    }
+    pub fn status(mut self, status: u16) -> MockResponse {
+        self.status = status;
+        self
+    }
+    pub fn header(mut self, name: &str, value: &str) -> MockResponse {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+}
+
+type Handler = dyn Fn(&MockRequest) -> Option<MockResponse> + Send + Sync;
+
+/// A minimal HTTP/1.1 server on localhost. Returning None from the handler never responds
+pub struct MockServer {
+    pub url: String,
+    pub requests: std::sync::Arc<std::sync::Mutex<Vec<MockRequest>>>,
+}
+
+impl MockServer {
+    pub async fn start(
+        handler: impl Fn(&MockRequest) -> Option<MockResponse> + Send + Sync + 'static,
+    ) -> MockServer {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let handler: std::sync::Arc<Handler> = std::sync::Arc::new(handler);
+        let server_requests = requests.clone();
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                let handler = handler.clone();
+                let requests = server_requests.clone();
+                tokio::spawn(async move {
+                    let _ = Self::handle(stream, handler, requests).await;
+                });
+            }
+        });
+        MockServer { url, requests }
+    }
+
+    pub fn requests(&self) -> Vec<MockRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    async fn handle(
+        mut stream: tokio::net::TcpStream,
+        handler: std::sync::Arc<Handler>,
+        requests: std::sync::Arc<std::sync::Mutex<Vec<MockRequest>>>,
+    ) -> std::io::Result<()> {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+        let mut buffer = Vec::new();
+        let mut chunk = [0u8; 4096];
+        let header_end = loop {
+            let read = stream.read(&mut chunk).await?;
+            if read == 0 {
+                return Ok(());
+            }
+            buffer.extend_from_slice(&chunk[..read]);
+            if let Some(position) = buffer.windows(4).position(|window| window == b"\r\n\r\n") {
+                break position + 4;
+            }
+        };
+        let head = String::from_utf8_lossy(&buffer[..header_end]).into_owned();
+        let mut lines = head.lines();
+        let mut request_line = lines.next().unwrap_or_default().split_whitespace();
+        let method = request_line.next().unwrap_or_default().to_string();
+        let path = request_line.next().unwrap_or_default().to_string();
+        let headers: Vec<(String, String)> = lines
+            .filter_map(|line| line.split_once(':'))
+            .map(|(key, value)| (key.trim().to_string(), value.trim().to_string()))
+            .collect();
+        let length = headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case("content-length"))
+            .and_then(|(_, value)| value.parse::<usize>().ok())
+            .unwrap_or_default();
+        while buffer.len() < header_end + length {
+            let read = stream.read(&mut chunk).await?;
+            if read == 0 {
+                break;
+            }
+            buffer.extend_from_slice(&chunk[..read]);
+        }
+        let body = String::from_utf8_lossy(&buffer[header_end..]).into_owned();
+        let request = MockRequest {
+            method,
+            path,
+            headers,
+            body,
+        };
+        requests.lock().unwrap().push(request.clone());
+        let response = match handler(&request) {
+            Some(response) => response,
+            None => {
+                // Hold the connection open without responding
+                std::future::pending::<()>().await;
+                unreachable!()
+            }
+        };
+        let mut head = format!("HTTP/1.1 {} Mock\r\n", response.status);
+        for (key, value) in &response.headers {
+            head.push_str(&format!("{key}: {value}\r\n"));
+        }
+        head.push_str(&format!(
+            "Content-Length: {}\r\nConnection: close\r\n\r\n",
+            response.body.len()
+        ));
+        stream.write_all(head.as_bytes()).await?;
+        stream.write_all(&response.body).await?;
+        stream.shutdown().await
+    }
+}
+
+/// A Client sending every request to the mock server
+pub async fn mock_client(server: &MockServer) -> Client<TestAuthenticator> {
+    Client::builder(TestAuthenticator, "RRAW Test (by u/KingTuxWH)")
+        .base_url(server.url.clone())
+        .login()
+        .await
+        .expect("The TestAuthenticator does not make requests")
+}
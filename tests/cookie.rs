@@ -0,0 +1,103 @@
+mod common;
+
+use common::{MockResponse, MockServer};
+use reqwest::header::{HeaderMap, COOKIE};
+use rraw::access::Access;
+use rraw::auth::{Authenticator, CookieAuthenticator, CookieLoginData};
+use rraw::error::Error;
+use rraw::responses::api::JsonResponse;
+use rraw::submission::response::SubmissionsResponse;
+use rraw::utils::redact::{redact_headers, REDACTED};
+use rraw::Client;
+
+#[test]
+fn login_response() {
+    let response: JsonResponse<CookieLoginData> = serde_json::from_str(
+        r#"{"json": {"errors": [], "data": {"need_https": true, "modhash": "f0f0f0", "cookie": "1234,2023-07-22T00:00:00,abcdef"}}}"#,
+    )
+    .unwrap();
+    let data = response.into_result().unwrap().unwrap();
+    assert_eq!(data.modhash, "f0f0f0");
+    assert!(!format!("{:?}", data).contains("abcdef"));
+
+    let response: JsonResponse<CookieLoginData> = serde_json::from_str(
+        r#"{"json": {"errors": [["WRONG_PASSWORD", "wrong password", "passwd"]]}}"#,
+    )
+    .unwrap();
+    match response.into_result() {
+        Err(Error::Api(errors)) => assert_eq!(errors[0].code, "WRONG_PASSWORD"),
+        other => panic!("Expected Error::Api got {:?}", other),
+    }
+}
+
+#[test]
+fn headers() {
+    let mut authenticator = CookieAuthenticator::new("KingTuxWH", "hunter2");
+    assert!(authenticator.needs_token_refresh());
+    assert!(!authenticator.oauth());
+    authenticator.cookie = Some("session".to_string());
+    authenticator.modhash = Some("f0f0f0".to_string());
+    assert!(!authenticator.needs_token_refresh());
+    assert_eq!(authenticator.modhash(), Some("f0f0f0".to_string()));
+
+    let mut headers = HeaderMap::new();
+    authenticator.headers(&mut headers);
+    assert_eq!(headers[COOKIE], "reddit_session=session");
+    assert_eq!(headers["x-modhash"], "f0f0f0");
+    let redacted = redact_headers(&headers);
+    assert_eq!(redacted[COOKIE], REDACTED);
+    assert_eq!(redacted["x-modhash"], REDACTED);
+    assert!(!format!("{:?}", authenticator).contains("session"));
+}
+
+#[test]
+fn listing_modhash() {
+    let mut value: serde_json::Value =
+        serde_json::from_str(include_str!("fixtures/submissions.json")).unwrap();
+    value["data"]["modhash"] = serde_json::Value::from("");
+    let listing: SubmissionsResponse = serde_json::from_value(value.clone()).unwrap();
+    assert_eq!(listing.modhash(), None);
+    value["data"]["modhash"] = serde_json::Value::from("f0f0f0");
+    let listing: SubmissionsResponse = serde_json::from_value(value).unwrap();
+    assert_eq!(listing.modhash(), Some("f0f0f0"));
+}
+
+#[tokio::test]
+async fn login_through_base_url() {
+    let server = MockServer::start(|request| {
+        if request.path.starts_with("/api/login/") {
+            return Some(MockResponse::json(
+                r#"{"json": {"errors": [], "data": {"modhash": "f0f0f0", "cookie": "session"}}}"#,
+            ));
+        }
+        Some(MockResponse::json("{}"))
+    })
+    .await;
+    let client = Client::builder(
+        CookieAuthenticator::new("KingTuxWH", "hunter2"),
+        "RRAW Test (by u/KingTuxWH)",
+    )
+    .base_url(server.url.clone())
+    .login()
+    .await
+    .unwrap();
+    client
+        .get_raw("/r/rust/about.json", Access::Any)
+        .await
+        .unwrap();
+
+    let requests = server.requests();
+    assert_eq!(requests[0].path, "/api/login/KingTuxWH");
+    assert!(requests[0].body.contains("user=KingTuxWH"));
+    assert_eq!(requests[1].header("Cookie"), Some("reddit_session=session"));
+}
+
+#[tokio::test]
+async fn invalid_user_agent() {
+    let mut authenticator = CookieAuthenticator::new("KingTuxWH", "hunter2");
+    let error = authenticator
+        .login(&reqwest::Client::new(), "RRAW\nTest")
+        .await
+        .unwrap_err();
+    assert!(matches!(error, Error::InvalidOption(_)), "{:?}", error);
+}
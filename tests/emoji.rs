@@ -0,0 +1,95 @@
+mod common;
+
+use common::{mock_client, MockRequest, MockResponse, MockServer};
+use rraw::error::Error;
+
+const PNG: &[u8] = b"\x89PNG\r\n\x1a\nferris";
+
+fn emoji_server(request: &MockRequest) -> Option<MockResponse> {
+    let body = match request.path.as_str() {
+        "/r/rraw/about.json" => include_str!("fixtures/subreddit_about.json").to_string(),
+        "/api/v1/rraw/emojis/all" => include_str!("fixtures/emojis.json").to_string(),
+        "/api/v1/rraw/emoji_asset_upload_s3.json" => {
+            let host = request.header("Host").unwrap();
+            format!(
+                r#"{{"s3UploadLease": {{"action": "//{host}/emoji-upload", "fields": [
+                    {{"name": "key", "value": "t5_2s4x6/ferris.png"}},
+                    {{"name": "policy", "value": "cG9saWN5"}}
+                ]}}, "websockets": []}}"#
+            )
+        }
+        "/emoji-upload" => return Some(MockResponse::json("").status(201)),
+        _ => "{}".to_string(),
+    };
+    Some(MockResponse::json(body))
+}
+
+#[tokio::test]
+async fn emojis() {
+    let server = MockServer::start(emoji_server).await;
+    let client = mock_client(&server).await;
+    let subreddit = client.subreddit("rraw").await.unwrap();
+    let emojis = subreddit.emojis().await.unwrap();
+    let names: Vec<&str> = emojis.snoomojis.iter().map(|e| e.name.as_str()).collect();
+    assert_eq!(names, vec!["cake", "snoo"]);
+    assert_eq!(emojis.subreddit_id.as_deref(), Some("t5_2s4x6"));
+    let ferris = emojis.get("ferris").unwrap();
+    assert!(ferris.mod_flair_only);
+    assert!(!ferris.user_flair_allowed);
+    assert_eq!(ferris.created_by.as_deref(), Some("t2_1pm1nmnj"));
+}
+
+#[tokio::test]
+async fn upload_emoji() {
+    let server = MockServer::start(emoji_server).await;
+    let client = mock_client(&server).await;
+    let subreddit = client.subreddit("rraw").await.unwrap();
+    subreddit
+        .upload_emoji("ferris", PNG.to_vec())
+        .await
+        .unwrap();
+
+    let requests = server.requests();
+    let lease = &requests[1];
+    assert_eq!(lease.path, "/api/v1/rraw/emoji_asset_upload_s3.json");
+    assert!(lease.body.contains("filepath=ferris.png"));
+    assert!(lease.body.contains("mimetype=image%2Fpng"));
+
+    let upload = &requests[2];
+    assert_eq!(upload.path, "/emoji-upload");
+    assert_eq!(upload.header("Authorization"), None);
+    assert!(upload.body.contains("t5_2s4x6/ferris.png"));
+    assert!(upload.body.contains(r#"filename="ferris.png""#));
+
+    let create = &requests[3];
+    assert_eq!(create.path, "/api/v1/rraw/emoji.json");
+    assert!(create.body.contains("name=ferris"));
+    assert!(create.body.contains("s3_key=t5_2s4x6%2Fferris.png"));
+}
+
+#[tokio::test]
+async fn delete_emoji() {
+    let server = MockServer::start(emoji_server).await;
+    let client = mock_client(&server).await;
+    let subreddit = client.subreddit("rraw").await.unwrap();
+    subreddit.delete_emoji("ferris").await.unwrap();
+    let request = &server.requests()[1];
+    assert_eq!(request.method, "DELETE");
+    assert_eq!(request.path, "/api/v1/rraw/emoji/ferris");
+}
+
+#[tokio::test]
+async fn invalid_emoji() {
+    let server = MockServer::start(emoji_server).await;
+    let client = mock_client(&server).await;
+    let subreddit = client.subreddit("rraw").await.unwrap();
+    for error in [
+        subreddit.upload_emoji("ferris", b"GIF89a".to_vec()).await,
+        subreddit.upload_emoji("", PNG.to_vec()).await,
+        subreddit.upload_emoji("a/b", PNG.to_vec()).await,
+        subreddit.delete_emoji("../about").await,
+    ] {
+        assert!(matches!(error, Err(Error::InvalidOption(_))), "{:?}", error);
+    }
+    assert_eq!(server.requests().len(), 1);
+}
@@ -0,0 +1,222 @@
+mod common;
+
+#[cfg(not(feature = "simd-json"))]
+use common::{mock_client, MockResponse, MockServer};
+use rraw::error::internal_error::{InternalError, SNIPPET_LENGTH};
+use rraw::responses::listing::RedditListing;
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug)]
+#[allow(dead_code)]
+struct Edited {
+    edited: bool,
+}
+
+#[test]
+fn deserialize_error_context() {
+    let padding = "x".repeat(2000);
+    let body = format!(
+        "{{\"padding\": \"{padding}\",\n\"edited\": \"yesterday\", \"more\": \"{padding}\"}}"
+    );
+    let error = serde_json::from_str::<Edited>(&body).unwrap_err();
+    let error = InternalError::deserialize(
+        "https://api.reddit.com/r/rust/hot",
+        "data.children[3].data.edited",
+        &body,
+        error,
+    );
+    let display = error.to_string();
+    assert!(display.contains("https://api.reddit.com/r/rust/hot"));
+    assert!(display.contains("data.children[3].data.edited"));
+    match error {
+        InternalError::DeserializeError { snippet, .. } => {
+            assert_eq!(snippet.chars().count(), SNIPPET_LENGTH);
+            assert!(snippet.contains("\"edited\": \"yesterday\""));
+        }
+        _ => panic!("Expected a DeserializeError"),
+    }
+}
+
+/// The data of a thing is read through the same deserializer. The path and position reach the failing field
+#[test]
+fn deserialize_error_inside_listing() {
+    let body =
+        include_str!("fixtures/overview.json").replace("\"score\": 42", "\"score\": \"lots\"");
+    let deserializer = &mut serde_json::Deserializer::from_str(&body);
+    let error = serde_path_to_error::deserialize::<_, RedditListing>(deserializer).unwrap_err();
+    assert_eq!(error.path().to_string(), "data.children[1].data.score");
+    let line = body
+        .lines()
+        .position(|line| line.contains("\"lots\""))
+        .unwrap()
+        + 1;
+    assert_eq!(error.inner().line(), line);
+}
+
+#[test]
+fn deserialize_error_short_body() {
+    let body = "{\"edited\": 5}";
+    let error = serde_json::from_str::<Edited>(body).unwrap_err();
+    match InternalError::deserialize("https://api.reddit.com", "edited", body, error) {
+        InternalError::DeserializeError { snippet, .. } => assert_eq!(snippet, body),
+        _ => panic!("Expected a DeserializeError"),
+    }
+}
+
+#[test]
+fn error_source_chain() {
+    use std::error::Error as StdError;
+
+    let json_error = serde_json::from_str::<Edited>("{}").unwrap_err();
+    let error = rraw::error::Error::from(json_error);
+    let internal = error
+        .source()
+        .expect("Error should expose the InternalError");
+    let json = internal
+        .source()
+        .expect("InternalError should expose the serde_json Error");
+    assert!(json.is::<serde_json::Error>());
+}
+
+#[test]
+fn io_error_conversion() {
+    use rraw::error::http_error::HTTPError;
+    use rraw::error::Error;
+    use std::io::ErrorKind;
+
+    let kinds = [
+        (Error::from(HTTPError::NotFound), ErrorKind::NotFound),
+        (Error::from(HTTPError::Banned), ErrorKind::PermissionDenied),
+        (Error::NotLoggedIn, ErrorKind::PermissionDenied),
+        (Error::DeadlineExceeded, ErrorKind::TimedOut),
+        (
+            Error::InvalidOption("limit".to_string()),
+            ErrorKind::InvalidInput,
+        ),
+        (Error::Other("other".to_string()), ErrorKind::Other),
+        (
+            Error::Batch(std::sync::Arc::new(HTTPError::Forbidden.into())),
+            ErrorKind::PermissionDenied,
+        ),
+        (
+            Error::Forbidden {
+                reason: None,
+                response: None,
+            },
+            ErrorKind::PermissionDenied,
+        ),
+    ];
+    for (error, kind) in kinds {
+        let message = error.to_string();
+        let io = std::io::Error::from(error);
+        assert_eq!(io.kind(), kind);
+        assert_eq!(io.to_string(), message);
+    }
+
+    fn read() -> std::io::Result<()> {
+        let json_error = serde_json::from_str::<Edited>("{}").unwrap_err();
+        Err(Error::from(json_error))?;
+        Ok(())
+    }
+    let io = read().unwrap_err();
+    assert_eq!(io.kind(), ErrorKind::InvalidData);
+    let inner = io.get_ref().unwrap();
+    assert!(inner.is::<Error>());
+    let internal = inner.source().expect("The InternalError is kept");
+    assert!(internal.source().unwrap().is::<serde_json::Error>());
+}
+
+#[test]
+fn error_response_reason() {
+    use reqwest::StatusCode;
+    use rraw::error::http_error::{ErrorResponse, HTTPError};
+    use rraw::error::Error;
+
+    let private = ErrorResponse::new(
+        "https://api.reddit.com/r/secret/about.json",
+        StatusCode::FORBIDDEN,
+        r#"{"reason": "private", "message": "Forbidden", "error": 403}"#,
+    );
+    assert_eq!(private.reason.as_deref(), Some("private"));
+    assert_eq!(private.message.as_deref(), Some("Forbidden"));
+    let error = Error::from(private);
+    assert_eq!(error.http_error(), Some(&HTTPError::Private));
+    let display = error.to_string();
+    assert!(display.contains("https://api.reddit.com/r/secret/about.json"));
+    assert!(display.contains("private"));
+
+    for (reason, expected) in [
+        ("banned", HTTPError::Banned),
+        ("quarantined", HTTPError::Quarantined),
+        ("gold_only", HTTPError::GoldOnly),
+        ("something_new", HTTPError::Forbidden),
+    ] {
+        let body = format!(r#"{{"reason": "{reason}"}}"#);
+        let response = ErrorResponse::new("https://api.reddit.com", StatusCode::FORBIDDEN, &body);
+        assert_eq!(response.http_error(), expected);
+    }
+
+    // A 403 without a subreddit reason is Forbidden with the reason Reddit sent
+    let body = r#"{"reason": "something_new", "message": "Forbidden"}"#;
+    let response = ErrorResponse::new("https://api.reddit.com", StatusCode::FORBIDDEN, body);
+    match Error::from(response) {
+        Error::Forbidden { reason, response } => {
+            assert_eq!(reason.as_deref(), Some("something_new"));
+            assert_eq!(response.unwrap().message.as_deref(), Some("Forbidden"));
+        }
+        error => panic!("Expected Forbidden got {:?}", error),
+    }
+    assert!(matches!(
+        Error::from(StatusCode::FORBIDDEN),
+        Error::Forbidden { reason: None, .. }
+    ));
+    assert!(matches!(
+        Error::from(HTTPError::Forbidden),
+        Error::Forbidden { reason: None, .. }
+    ));
+    assert!(matches!(
+        Error::from(HTTPError::Private),
+        Error::HTTPError {
+            error: HTTPError::Private,
+            ..
+        }
+    ));
+
+    let html = "<html>".repeat(1000);
+    let response = ErrorResponse::new("https://api.reddit.com", StatusCode::BAD_GATEWAY, &html);
+    assert_eq!(response.reason, None);
+    assert_eq!(
+        response.body.len(),
+        rraw::error::http_error::ERROR_BODY_LENGTH
+    );
+    assert_eq!(
+        response.http_error(),
+        HTTPError::Other(StatusCode::BAD_GATEWAY)
+    );
+}
+
+/// Bodies are parsed from bytes. The snippet is still taken from the text around the failure.
+///
+/// simd-json errors have no snippet. They are checked in `tests/simd_json.rs`
+#[cfg(not(feature = "simd-json"))]
+#[tokio::test]
+async fn deserialize_error_from_response() {
+    let server = MockServer::start(|_| {
+        Some(MockResponse::json(
+            "{\"name\": \"Reddit \u{1F980}\", \"created\": \"yesterday\"}",
+        ))
+    })
+    .await;
+    let client = mock_client(&server).await;
+    let error = client
+        .me()
+        .await
+        .err()
+        .expect("The body is not a Me response");
+    match error {
+        rraw::error::Error::InternalError(InternalError::DeserializeError { snippet, .. }) => {
+            assert!(snippet.contains("\u{1F980}"), "{}", snippet);
+        }
+        error => panic!("Expected a DeserializeError got {:?}", error),
+    }
+}
@@ -0,0 +1,81 @@
+//! Parses the recorded payloads in `tests/fixtures` and checks the fields callers rely on
+use rraw::comments::tree::CommentTree;
+use rraw::message::response::{InboxItem, InboxListing};
+use rraw::submission::response::SubmissionsResponse;
+use rraw::user::response::UserResponse;
+use serde::de::IgnoredAny;
+
+#[test]
+fn user_about() {
+    let response: UserResponse =
+        serde_json::from_str(include_str!("fixtures/user_about.json")).unwrap();
+    let user = response.data;
+    assert_eq!(user.name, "KingTuxWH");
+    assert_eq!(user.id, "3s8a2");
+    assert_eq!(user.link_karma, 1500);
+    assert_eq!(user.comment_karma, 2700);
+    assert_eq!(user.total_karma, 4210);
+    assert!(user.is_mod);
+    assert!(user.verified);
+    assert!(!user.is_suspended);
+    assert_eq!(user.created_utc, 1540000000.0);
+}
+
+#[test]
+fn submission_listing() {
+    let listing: SubmissionsResponse =
+        serde_json::from_str(include_str!("fixtures/submissions.json")).unwrap();
+    let submissions: Vec<_> = listing
+        .data
+        .children
+        .iter()
+        .map(|child| &child.data)
+        .collect();
+    assert_eq!(submissions.len(), 2);
+    let first = submissions[0];
+    assert_eq!(first.name, "t3_15bfi0");
+    assert_eq!(first.title, "RRAW Release");
+    assert_eq!(first.subreddit, "rust");
+    assert_eq!(first.author, "KingTuxWH");
+    assert_eq!(first.score, 42);
+    assert_eq!(first.permalink, "/r/rust/comments/15bfi0/rraw_release/");
+    assert!(!first.is_poll());
+    assert_eq!(submissions[1].name, "t3_15bfi1");
+}
+
+#[test]
+fn comment_tree() {
+    let (_, tree): (IgnoredAny, CommentTree) =
+        serde_json::from_str(include_str!("fixtures/comment_tree.json")).unwrap();
+    let top: Vec<&str> = tree
+        .iter()
+        .filter(|comment| comment.is_top_level())
+        .map(|comment| comment.id.as_str())
+        .collect();
+    assert_eq!(top, vec!["a", "e"]);
+    let first = tree.iter().next().unwrap();
+    assert_eq!(first.body, "A");
+    assert_eq!(first.link_id, "t3_15bfi0");
+    assert_eq!(first.replies.len(), 2);
+    assert!(!tree.is_complete());
+}
+
+#[test]
+fn message_inbox() {
+    let inbox: InboxListing = serde_json::from_str(include_str!("fixtures/inbox.json")).unwrap();
+    let items = inbox.data.children;
+    assert_eq!(items.len(), 2);
+    assert!(matches!(items[0], InboxItem::Message(_)));
+    assert_eq!(items[0].name(), "t4_1xo4abc");
+    assert_eq!(items[0].subject(), "Hello from RRAW");
+    assert_eq!(items[0].body(), Some("Testing the inbox"));
+    assert!(items[0].is_new());
+    match &items[1] {
+        InboxItem::CommentReply(reply) => {
+            assert!(reply.message.was_comment);
+            assert_eq!(reply.message.author, "TheSmartKing");
+        }
+        InboxItem::Message(_) => panic!("Expected a comment reply"),
+    }
+    assert!(!items[1].is_new());
+}
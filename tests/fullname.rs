@@ -0,0 +1,73 @@
+use rraw::utils::fullname::{Fullname, FullnameKind};
+
+#[test]
+fn round_trip() {
+    for (value, kind, id) in [
+        ("t1_jtq1a2b", FullnameKind::Comment, "jtq1a2b"),
+        ("t2_1pm1nmnj", FullnameKind::Account, "1pm1nmnj"),
+        ("t3_15bfi0", FullnameKind::Link, "15bfi0"),
+        ("t4_1x2y3z", FullnameKind::Message, "1x2y3z"),
+        ("t5_2s4x6", FullnameKind::Subreddit, "2s4x6"),
+        ("t6_abc", FullnameKind::Award, "abc"),
+    ] {
+        let fullname = Fullname::parse(value).unwrap();
+        assert_eq!(fullname.kind(), kind);
+        assert_eq!(fullname.id(), id);
+        assert_eq!(fullname.to_string(), value);
+        assert_eq!(Fullname::new(kind, id), fullname);
+        assert_eq!(FullnameKind::from_prefix(kind.prefix()), Some(kind));
+    }
+}
+
+#[test]
+fn invalid() {
+    for value in [
+        "",
+        "15bfi0",
+        "t3_",
+        "_15bfi0",
+        "t7_15bfi0",
+        "T3_15bfi0",
+        "t3_15BFI0",
+        "t3_15b-fi0",
+        "t3_15bfi0_",
+        "t3 15bfi0",
+    ] {
+        assert_eq!(Fullname::parse(value), None, "{value}");
+    }
+}
+
+#[test]
+fn either_form() {
+    assert_eq!(
+        Fullname::new(FullnameKind::Link, "t3_15bfi0").id(),
+        "15bfi0"
+    );
+    assert_eq!(FullnameKind::Link.strip("t3_15bfi0"), "15bfi0");
+    assert_eq!(FullnameKind::Link.strip("15bfi0"), "15bfi0");
+    // Only the prefix of the kind is removed
+    assert_eq!(FullnameKind::Comment.strip("t3_15bfi0"), "t3_15bfi0");
+    assert!(FullnameKind::Link.matches("t3_15bfi0"));
+    assert!(!FullnameKind::Link.matches("t1_15bfi0"));
+    assert!(!FullnameKind::Link.matches("15bfi0"));
+}
+
+#[test]
+fn parse_as() {
+    let expected = Fullname::new(FullnameKind::Link, "15bfi0");
+    assert_eq!(
+        Fullname::parse_as(FullnameKind::Link, "15bfi0"),
+        Some(expected.clone())
+    );
+    assert_eq!(
+        Fullname::parse_as(FullnameKind::Link, "t3_15bfi0"),
+        Some(expected)
+    );
+    for value in ["", "t1_15bfi0", "15BFI0", "t3_"] {
+        assert_eq!(
+            Fullname::parse_as(FullnameKind::Link, value),
+            None,
+            "{value}"
+        );
+    }
+}
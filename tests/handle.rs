@@ -0,0 +1,109 @@
+mod common;
+
+use common::{mock_client, MockResponse, MockServer};
+use rraw::error::http_error::HTTPError;
+use rraw::error::Error;
+use rraw::thing::{Deletable, Replyable, Savable, Votable};
+use rraw::utils::fullname::FullnameKind;
+use rraw::utils::options::VoteDirection;
+use serde_json::{json, Value};
+
+fn handle_server() -> impl Fn(&common::MockRequest) -> Option<MockResponse> {
+    |request| {
+        let path = request.path.as_str();
+        let body = if path == "/api/v1/me" {
+            include_str!("fixtures/me.json").to_string()
+        } else if path == "/api/info?id=t1_jtq1a2b" {
+            include_str!("fixtures/comments.json").to_string()
+        } else if path.starts_with("/api/info") {
+            json!({"kind": "Listing", "data": {"children": []}}).to_string()
+        } else if path == "/api/comment" {
+            let comments: Value =
+                serde_json::from_str(include_str!("fixtures/comments.json")).unwrap();
+            let reply = comments["data"]["children"][1].clone();
+            json!({"json": {"errors": [], "data": {"things": [reply]}}}).to_string()
+        } else {
+            "{}".to_string()
+        };
+        Some(MockResponse::json(body))
+    }
+}
+
+#[tokio::test]
+async fn invalid_ids() {
+    let server = MockServer::start(handle_server()).await;
+    let client = mock_client(&server).await;
+    let me = client.me().await.unwrap();
+    for id in ["", "t1_jtq1a2b", "15BFI0", "15bf-i0", "t3_"] {
+        assert!(
+            matches!(me.submission(id), Err(Error::InvalidOption(_))),
+            "{id}"
+        );
+    }
+    assert!(matches!(
+        me.comment("t3_15bfi0"),
+        Err(Error::InvalidOption(_))
+    ));
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[tokio::test]
+async fn submission_actions() {
+    let server = MockServer::start(handle_server()).await;
+    let client = mock_client(&server).await;
+    let me = client.me().await.unwrap();
+    let submission = me.submission("15bfi0").unwrap();
+    assert_eq!(
+        submission.fullname(),
+        me.submission("t3_15bfi0").unwrap().fullname()
+    );
+    assert_eq!(submission.fullname().kind(), FullnameKind::Link);
+
+    submission.vote(VoteDirection::Down).await.unwrap();
+    submission.save().await.unwrap();
+    submission.remove(true).await.unwrap();
+    submission.lock().await.unwrap();
+    let reply = submission.reply("Thanks").await.unwrap();
+    assert_eq!(reply.id, "jtq1a2c");
+    let requests = server.requests();
+    let sent: Vec<(&str, &str)> = requests[1..]
+        .iter()
+        .map(|request| (request.path.as_str(), request.body.as_str()))
+        .collect();
+    assert_eq!(
+        sent,
+        vec![
+            ("/api/vote", "id=t3_15bfi0&dir=-1"),
+            ("/api/save", "id=t3_15bfi0"),
+            ("/api/remove", "id=t3_15bfi0&spam=true"),
+            ("/api/lock", "id=t3_15bfi0"),
+            (
+                "/api/comment",
+                "api_type=json&thing_id=t3_15bfi0&text=Thanks"
+            ),
+        ]
+    );
+
+    let missing = submission.fetch().await;
+    assert!(matches!(
+        missing,
+        Err(Error::HTTPError {
+            error: HTTPError::NotFound,
+            ..
+        })
+    ));
+}
+
+#[tokio::test]
+async fn comment_fetch() {
+    let server = MockServer::start(handle_server()).await;
+    let client = mock_client(&server).await;
+    let me = client.me().await.unwrap();
+    let comment = me.comment("t1_jtq1a2b").unwrap();
+    let fetched = comment.fetch().await.unwrap();
+    assert_eq!(fetched.fullname(), comment.fullname().to_string());
+    comment.delete().await.unwrap();
+    let requests = server.requests();
+    assert_eq!(requests[2].path, "/api/del");
+    assert_eq!(requests[2].body, "id=t1_jtq1a2b");
+}
@@ -0,0 +1,57 @@
+mod common;
+
+use common::{MockResponse, MockServer, TestAuthenticator};
+use rraw::access::Access;
+use rraw::comments::response::CommentsResponse;
+use rraw::submission::response::SubmissionsResponse;
+use rraw::utils::unescape_html;
+use rraw::Client;
+use serde_json::json;
+
+#[test]
+fn escaped_html() {
+    let comments: CommentsResponse =
+        serde_json::from_str(include_str!("fixtures/comments.json")).unwrap();
+    assert_eq!(
+        comments.data.children[0].data.rendered_html().as_deref(),
+        Some("<div class=\"md\"><p>Thanks for trying it out &gt; everything else</p>\n</div>")
+    );
+    let submissions: SubmissionsResponse =
+        serde_json::from_str(include_str!("fixtures/submissions.json")).unwrap();
+    assert_eq!(
+        submissions.data.children[0].data.rendered_html().as_deref(),
+        Some("<div class=\"md\"><p>Hello &amp; welcome</p>\n</div>")
+    );
+    assert_eq!(submissions.data.children[1].data.rendered_html(), None);
+    assert_eq!(unescape_html("&amp;lt;"), "&lt;");
+}
+
+#[tokio::test]
+async fn raw_json() {
+    let server = MockServer::start(|_| {
+        let mut comments: serde_json::Value =
+            serde_json::from_str(include_str!("fixtures/comments.json")).unwrap();
+        comments["data"]["children"][0]["data"]["body_html"] =
+            json!("<div class=\"md\"><p>a &lt; b</p>\n</div>");
+        Some(MockResponse::json(comments))
+    })
+    .await;
+    let client = Client::builder(TestAuthenticator, "RRAW Test (by u/KingTuxWH)")
+        .base_url(server.url.clone())
+        .raw_json(true)
+        .login()
+        .await
+        .unwrap();
+    let (comments, _) = client
+        .get_json_with_raw::<CommentsResponse>("/r/rust/comments.json?limit=1", Access::Any)
+        .await
+        .unwrap();
+    assert_eq!(
+        comments.data.children[0].data.rendered_html().as_deref(),
+        Some("<div class=\"md\"><p>a &lt; b</p>\n</div>")
+    );
+    assert_eq!(
+        server.requests()[0].path,
+        "/r/rust/comments.json?limit=1&raw_json=1"
+    );
+}
@@ -0,0 +1,114 @@
+mod common;
+
+use common::{mock_client, MockResponse, MockServer};
+use rraw::error::Error;
+use rraw::message::response::{InboxItem, InboxListing};
+use rraw::thing::{Deletable, Replyable};
+use rraw::user::response::MeResponse;
+use serde_json::{json, Value};
+
+#[test]
+fn inbox_items() {
+    let listing: InboxListing = serde_json::from_str(include_str!("fixtures/inbox.json")).unwrap();
+    assert_eq!(listing.len(), 2);
+    match &listing.data.children[0] {
+        InboxItem::Message(message) => {
+            assert_eq!(message.subject, "Hello from RRAW");
+            assert!(!message.was_comment);
+        }
+        other => panic!("Expected a Message got {:?}", other),
+    }
+    match &listing.data.children[1] {
+        InboxItem::CommentReply(reply) => {
+            assert!(reply.message.was_comment);
+            assert_eq!(reply.link_title.as_deref(), Some("RRAW 1.2 Released"));
+            assert_eq!(
+                reply.message.context.as_deref(),
+                Some("/r/rraw/comments/15bfi0/rraw_12_released/jtq9zz1/?context=3")
+            );
+        }
+        other => panic!("Expected a CommentReply got {:?}", other),
+    }
+    let names: Vec<&str> = listing.iter().map(InboxItem::name).collect();
+    assert_eq!(names, vec!["t4_1xo4abc", "t1_jtq9zz1"]);
+    assert!(listing.data.children[0].is_new());
+    assert_eq!(listing.data.children[1].body(), Some("Thanks!"));
+}
+
+#[test]
+fn round_trip() {
+    let listing: InboxListing = serde_json::from_str(include_str!("fixtures/inbox.json")).unwrap();
+    let value = serde_json::to_value(&listing.data.children[1]).unwrap();
+    assert_eq!(value["kind"], "t1");
+    let item: InboxItem = serde_json::from_value(value).unwrap();
+    assert_eq!(item, listing.data.children[1]);
+}
+
+#[test]
+fn inbox_count() {
+    let me: MeResponse = serde_json::from_str(include_str!("fixtures/me.json")).unwrap();
+    assert_eq!(me.inbox_count, 3);
+    assert_eq!(me.has_mail, Some(true));
+    assert!(me.about.personal_details.is_some());
+}
+
+#[tokio::test]
+async fn delete_rejects_comment_replies() {
+    let client = common::test_client().await;
+    let listing: InboxListing = serde_json::from_str(include_str!("fixtures/inbox.json")).unwrap();
+    let reply = &listing.data.children[1];
+    let error = reply.to_message(&client).delete().await.unwrap_err();
+    assert!(matches!(error, Error::InvalidOption(_)), "{:?}", error);
+}
+
+#[tokio::test]
+async fn comment_reply_conveniences() {
+    let server = MockServer::start(|request| {
+        let body = match request.path.as_str() {
+            "/api/info?id=t3_15bfi0" => include_str!("fixtures/submissions.json").to_string(),
+            "/api/comment" => {
+                let comments: Value =
+                    serde_json::from_str(include_str!("fixtures/comments.json")).unwrap();
+                let reply = comments["data"]["children"][1].clone();
+                json!({"json": {"errors": [], "data": {"things": [reply]}}}).to_string()
+            }
+            _ => "{}".to_string(),
+        };
+        Some(MockResponse::json(body))
+    })
+    .await;
+    let client = mock_client(&server).await;
+    let listing: InboxListing = serde_json::from_str(include_str!("fixtures/inbox.json")).unwrap();
+    let reply = listing.data.children[1].to_message(&client);
+
+    let comment = reply.reply("You are welcome").await.unwrap();
+    assert_eq!(comment.id, "jtq1a2c");
+    let submission = reply.parent_submission().await.unwrap();
+    assert_eq!(submission.name, "t3_15bfi0");
+    reply.mark_read().await.unwrap();
+
+    let requests = server.requests();
+    let paths: Vec<&str> = requests.iter().map(|r| r.path.as_str()).collect();
+    assert_eq!(
+        paths,
+        vec![
+            "/api/comment",
+            "/api/info?id=t3_15bfi0",
+            "/api/read_message"
+        ]
+    );
+    assert!(requests[0].body.contains("thing_id=t1_jtq9zz1"));
+    assert!(requests[0].body.contains("text=You+are+welcome"));
+    assert_eq!(requests[2].body, "id=t1_jtq9zz1");
+
+    let message = listing.data.children[0].to_message(&client);
+    assert!(matches!(
+        message.reply("Hi").await,
+        Err(Error::InvalidOption(_))
+    ));
+    assert!(matches!(
+        message.parent_submission().await,
+        Err(Error::InvalidOption(_))
+    ));
+    assert_eq!(server.requests().len(), 3);
+}
@@ -0,0 +1,148 @@
+use rraw::comments::response::CommentsResponse;
+use rraw::responses::listing::{MixedItem, MixedListing, RedditListing};
+use rraw::submission::response::SubmissionsResponse;
+use rraw::utils::options::{FeedOption, TimePeriod};
+
+#[test]
+fn next_options() {
+    let listing: SubmissionsResponse =
+        serde_json::from_str(include_str!("fixtures/submissions.json")).unwrap();
+    assert_eq!(listing.after(), Some("t3_15bfi0"));
+    let options = FeedOption::builder()
+        .before("t3_first")
+        .time(TimePeriod::Week)
+        .limit(25)
+        .build()
+        .unwrap();
+    let next = listing.next_options(&options).unwrap();
+    assert_eq!(next.after.as_deref(), Some("t3_15bfi0"));
+    assert_eq!(next.before, None);
+    assert_eq!(next.limit, Some(25));
+    assert_eq!(next.url(), "after=t3_15bfi0&t=week&limit=25");
+}
+
+#[test]
+fn last_page() {
+    let listing: CommentsResponse =
+        serde_json::from_str(include_str!("fixtures/comments.json")).unwrap();
+    assert_eq!(listing.after(), None);
+    assert!(listing.next_options(&FeedOption::default()).is_none());
+
+    let listing: RedditListing =
+        serde_json::from_str(include_str!("fixtures/overview.json")).unwrap();
+    assert!(listing.next_options(&FeedOption::default()).is_none());
+}
+
+#[test]
+fn collection() {
+    let listing: SubmissionsResponse =
+        serde_json::from_str(include_str!("fixtures/submissions.json")).unwrap();
+    assert!(!listing.is_empty());
+    assert_eq!(listing.len(), listing.data.children.len());
+    assert_eq!((&listing).into_iter().count(), listing.len());
+    let titles: Vec<String> = listing
+        .into_iter()
+        .map(|submission| submission.data.title)
+        .collect();
+    assert!(!titles.is_empty());
+}
+
+#[test]
+fn random() {
+    let listing: SubmissionsResponse =
+        serde_json::from_str(include_str!("fixtures/submissions.json")).unwrap();
+    for seed in 0..32 {
+        let pick = listing.random(Some(seed)).unwrap();
+        assert_eq!(pick, listing.random(Some(seed)).unwrap());
+        assert!(listing.iter().any(|submission| &submission.data == pick));
+    }
+    // Every item can be picked
+    let mut ids: Vec<&str> = (0..64)
+        .map(|seed| listing.random(Some(seed)).unwrap().id.as_str())
+        .collect();
+    ids.sort_unstable();
+    ids.dedup();
+    assert_eq!(ids.len(), listing.len());
+    assert!(listing.random(None).is_some());
+
+    let comments: CommentsResponse =
+        serde_json::from_str(include_str!("fixtures/comments.json")).unwrap();
+    assert!(comments.random(Some(7)).is_some());
+    let empty: CommentsResponse =
+        serde_json::from_str(r#"{"kind": "Listing", "data": {"children": []}}"#).unwrap();
+    assert!(empty.random(None).is_none());
+}
+
+#[test]
+fn sr_detail() {
+    let listing: SubmissionsResponse =
+        serde_json::from_str(include_str!("fixtures/submissions.json")).unwrap();
+    assert!(listing.iter().all(|child| child.data.sr_detail.is_none()));
+
+    let listing: SubmissionsResponse =
+        serde_json::from_str(include_str!("fixtures/submissions_sr_detail.json")).unwrap();
+    let detail = listing.data.children[0].data.sr_detail.as_ref().unwrap();
+    assert_eq!(detail.name, "t5_2s7lj");
+    assert_eq!(detail.display_name_prefixed, "r/rust");
+    assert_eq!(detail.subscribers, 285000);
+    assert!(!detail.over_18);
+    assert_eq!(detail.user_is_subscriber, Some(true));
+    assert!(detail.other.contains_key("link_flair_position"));
+
+    let listing: CommentsResponse =
+        serde_json::from_str(include_str!("fixtures/comments_sr_detail.json")).unwrap();
+    let detail = listing.data.children[0].data.sr_detail.as_ref().unwrap();
+    assert_eq!(detail.display_name, "rust");
+    assert_eq!(detail.primary_color.as_deref(), Some("#dea584"));
+
+    // Kept when paginating
+    let options = FeedOption::builder().sr_detail(true).build().unwrap();
+    let listing: SubmissionsResponse =
+        serde_json::from_str(include_str!("fixtures/submissions.json")).unwrap();
+    let next = listing.next_options(&options).unwrap();
+    assert_eq!(next.url(), "after=t3_15bfi0&sr_detail=true");
+}
+
+#[test]
+fn mixed() {
+    let mut value: serde_json::Value =
+        serde_json::from_str(include_str!("fixtures/overview.json")).unwrap();
+    value["data"]["children"]
+        .as_array_mut()
+        .unwrap()
+        .push(serde_json::json!({"kind": "t4", "data": {"id": "1vbma6c"}}));
+    let listing: MixedListing = serde_json::from_value(value).unwrap();
+    assert_eq!(listing.len(), 3);
+    let comments: Vec<&str> = listing
+        .comments()
+        .map(|comment| comment.id.as_str())
+        .collect();
+    assert_eq!(comments, vec!["jtq1a2b"]);
+    let submissions: Vec<&str> = listing
+        .submissions()
+        .map(|submission| submission.id.as_str())
+        .collect();
+    assert_eq!(submissions, vec!["15bfi0"]);
+    match &listing.data.children[2] {
+        MixedItem::Other(kind, data) => {
+            assert_eq!(kind, "t4");
+            assert_eq!(data["id"], "1vbma6c");
+        }
+        item => panic!("Expected an other item. Got {item:?}"),
+    }
+
+    // Serializes back into the kinds Reddit sent
+    let json = serde_json::to_string(&listing).unwrap();
+    let again: MixedListing = serde_json::from_str(&json).unwrap();
+    assert_eq!(again, listing);
+    assert_eq!(listing.into_submissions().count(), 1);
+}
+
+#[test]
+fn mixed_invalid_comment() {
+    let json = r#"{"kind": "Listing", "data": {"children": [{"kind": "t1", "data": {"id": 5}}]}}"#;
+    let deserializer = &mut serde_json::Deserializer::from_str(json);
+    let error = serde_path_to_error::deserialize::<_, MixedListing>(deserializer).unwrap_err();
+    assert_eq!(error.path().to_string(), "data.children[0].data.id");
+    assert_eq!(error.inner().column(), json.find('5').unwrap() + 1);
+}
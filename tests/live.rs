@@ -0,0 +1,141 @@
+mod common;
+
+use common::{mock_client, MockResponse, MockServer};
+use rraw::live::response::{LiveThreadResponse, LiveUpdates};
+use rraw::responses::{RedditDataType, RedditResponse, RedditTypeResponse};
+use serde_json::{json, Value};
+
+const UPDATES: &str = include_str!("fixtures/live_updates.json");
+
+fn contributor(name: &str) -> Value {
+    json!({"name": name, "id": "t2_1", "permissions": ["all"]})
+}
+
+#[test]
+fn updates() {
+    let updates: LiveUpdates = serde_json::from_str(UPDATES).unwrap();
+    assert_eq!(updates.len(), 2);
+    let first = &updates.data.children[0];
+    assert_eq!(first.kind, RedditDataType::LiveUpdate);
+    assert_eq!(first.data.embeds[0].width, Some(485));
+    assert!(!first.data.stricken);
+    assert!(updates.data.children[1].data.stricken);
+    assert_eq!(updates.data.children[1].data.author, None);
+
+    let dynamic: RedditResponse = serde_json::from_value(
+        serde_json::from_str::<Value>(UPDATES).unwrap()["data"]["children"][0].clone(),
+    )
+    .unwrap();
+    assert!(matches!(dynamic.data, RedditTypeResponse::LiveUpdate(_)));
+}
+
+#[test]
+fn about() {
+    let about: LiveThreadResponse = serde_json::from_value(json!({
+        "kind": "LiveUpdateEvent",
+        "data": {
+            "id": "18hnzysb1elcs", "title": "RRAW Release", "description": "", "description_html": null,
+            "resources": "", "resources_html": null, "state": "complete", "viewer_count": 3,
+            "nsfw": false, "created_utc": 1690000000.0, "websocket_url": null, "announcement_url": null
+        }
+    }))
+    .unwrap();
+    assert_eq!(about.kind, RedditDataType::LiveUpdateEvent);
+    assert!(!about.data.is_live());
+}
+
+#[tokio::test]
+async fn contributors() {
+    let server = MockServer::start(|request| {
+        let list =
+            |name: &str| json!({"kind": "UserList", "data": {"children": [contributor(name)]}});
+        let body = if request.path.starts_with("/live/invited/") {
+            json!([list("KingTuxWH"), list("invited")])
+        } else {
+            list("KingTuxWH")
+        };
+        Some(MockResponse::json(body))
+    })
+    .await;
+    let client = mock_client(&server).await;
+    for id in ["single", "invited"] {
+        let contributors = client.live_thread(id).contributors().await.unwrap();
+        assert_eq!(contributors.len(), 1, "{}", id);
+        assert_eq!(contributors[0].name, "KingTuxWH");
+    }
+}
+
+#[cfg(feature = "stream")]
+#[tokio::test]
+async fn stream_updates() {
+    use futures_util::StreamExt;
+    use std::time::Duration;
+
+    let server = MockServer::start(|request| {
+        let mut updates: Value = serde_json::from_str(UPDATES).unwrap();
+        let children = updates["data"]["children"].as_array().unwrap().clone();
+        let page = if request.path.contains("before=LiveUpdate_0f6b") {
+            children[..1].to_vec()
+        } else if request.path.contains("before=") {
+            Vec::new()
+        } else {
+            children[1..].to_vec()
+        };
+        updates["data"]["children"] = Value::Array(page);
+        Some(MockResponse::json(updates))
+    })
+    .await;
+    let client = mock_client(&server).await;
+    let thread = client.live_thread("18hnzysb1elcs");
+    let mut stream = Box::pin(thread.stream_updates(Duration::from_millis(10)));
+    let update = stream.next().await.unwrap().unwrap();
+    assert_eq!(update.body, "Kick off! https://twitter.com/rraw/status/1");
+    let update = tokio::time::timeout(Duration::from_millis(200), stream.next()).await;
+    assert!(update.is_err(), "Only new updates are yielded");
+    drop(stream);
+
+    let requests = server.requests();
+    assert_eq!(requests[0].path, "/live/18hnzysb1elcs.json?limit=1");
+    assert_eq!(
+        requests[1].path,
+        "/live/18hnzysb1elcs.json?before=LiveUpdate_0f6b3b2e-2a7b-11ee-9a1c-3e7a4a6d2b11&limit=100"
+    );
+    assert!(requests[2..]
+        .iter()
+        .all(|request| request.path.contains("before=LiveUpdate_1c2d")));
+}
+
+/// A thread without updates has no cursor. Later polls still ask for a full page
+#[cfg(feature = "stream")]
+#[tokio::test]
+async fn stream_updates_without_cursor() {
+    use futures_util::StreamExt;
+    use std::time::Duration;
+
+    let server = MockServer::start(|request| {
+        let mut updates: Value = serde_json::from_str(UPDATES).unwrap();
+        if request.path.ends_with("limit=1") || request.path.contains("before=") {
+            updates["data"]["children"] = Value::Array(Vec::new());
+        }
+        Some(MockResponse::json(updates))
+    })
+    .await;
+    let client = mock_client(&server).await;
+    let thread = client.live_thread("18hnzysb1elcs");
+    let stream = thread.stream_updates(Duration::from_millis(10));
+    let bodies = stream.take(2).map(|update| update.unwrap().body).collect();
+    let bodies: Vec<String> = tokio::time::timeout(Duration::from_secs(5), bodies)
+        .await
+        .expect("Polls without a cursor ask for every new update");
+    assert_eq!(
+        bodies,
+        vec![
+            "Teams are out",
+            "Kick off! https://twitter.com/rraw/status/1"
+        ]
+    );
+
+    let requests = server.requests();
+    assert_eq!(requests[0].path, "/live/18hnzysb1elcs.json?limit=1");
+    assert_eq!(requests[1].path, "/live/18hnzysb1elcs.json?limit=100");
+}
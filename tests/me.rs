@@ -36,11 +36,15 @@ mod me_tests {
 
         assert!(me.is_ok());
         let me = me.unwrap();
-        assert!(me.saved(None).await.is_ok());
+        assert!(me.saved(None, None).await.is_ok());
+        assert!(me.saved_comments(None).await.is_ok());
         assert!(me.up_voted(None).await.is_ok());
         assert!(me.down_voted(None).await.is_ok());
+        assert!(me.subscribed_subreddits(None).await.is_ok());
+        assert!(me.moderated_subreddits(None).await.is_ok());
+        assert!(me.contributor_subreddits(None).await.is_ok());
 
-        return Ok(());
+        Ok(())
     }
 
     #[ignore]
@@ -58,7 +62,7 @@ mod me_tests {
         me.get_messages(Some(WhereMessage::Unread), None)
             .await
             .unwrap();
-        return Ok(());
+        Ok(())
     }
 
     async fn create_client_by_code() -> anyhow::Result<Client<CodeAuthenticator>> {
@@ -82,18 +86,18 @@ mod me_tests {
 
         assert!(me.is_ok());
         let me = me.unwrap();
-        assert!(me.saved(None).await.is_ok());
+        assert!(me.saved(None, None).await.is_ok());
         assert!(me.up_voted(None).await.is_ok());
         assert!(me.down_voted(None).await.is_ok());
 
         let r_t = client.refresh_token();
-        if r_t.is_some() {
-            println!("Refresh Token Is: {}", r_t.unwrap())
+        if let Some(r_t) = r_t {
+            println!("Refresh Token Is: {}", r_t)
         } else {
             println!("Refresh Token Not Exist!")
         }
 
-        return Ok(());
+        Ok(())
     }
 
     #[ignore]
@@ -108,7 +112,7 @@ mod me_tests {
             vec!["identity", "read", "save", "history"],
         );
         println!("{}", string);
-        return Ok(());
+        Ok(())
     }
 
     #[ignore]
@@ -126,7 +130,7 @@ mod me_tests {
         me.get_messages(Some(WhereMessage::Unread), None)
             .await
             .unwrap();
-        return Ok(());
+        Ok(())
     }
 
     async fn create_client_by_token() -> anyhow::Result<Client<TokenAuthenticator>> {
@@ -149,18 +153,18 @@ mod me_tests {
 
         assert!(me.is_ok());
         let me = me.unwrap();
-        assert!(me.saved(None).await.is_ok());
+        assert!(me.saved(None, None).await.is_ok());
         assert!(me.up_voted(None).await.is_ok());
         assert!(me.down_voted(None).await.is_ok());
 
         let r_t = client.refresh_token();
-        if r_t.is_some() {
-            println!("Refresh Token Is: {}", r_t.unwrap())
+        if let Some(r_t) = r_t {
+            println!("Refresh Token Is: {}", r_t)
         } else {
             println!("Refresh Token Not Exist!")
         }
 
-        return Ok(());
+        Ok(())
     }
 
     #[ignore]
@@ -178,6 +182,6 @@ mod me_tests {
         me.get_messages(Some(WhereMessage::Unread), None)
             .await
             .unwrap();
-        return Ok(());
+        Ok(())
     }
 }
@@ -0,0 +1,76 @@
+mod common;
+
+use common::{mock_client, MockResponse, MockServer};
+use rraw::modmail::response::ConversationResponse;
+use rraw::modmail::ConversationState;
+use rraw::utils::options::FeedOption;
+use serde_json::{json, Value};
+
+const CONVERSATION: &str = include_str!("fixtures/modmail_conversation.json");
+
+#[test]
+fn conversation() {
+    let response: ConversationResponse = serde_json::from_str(CONVERSATION).unwrap();
+    assert_eq!(response.conversation.owner.display_name, "rraw");
+    assert_eq!(response.conversation.authors.len(), 2);
+    assert!(response.conversation.authors[1].is_hidden);
+    assert_eq!(response.mod_actions["a1"].author.name, "KingTuxWH");
+    let bodies: Vec<&str> = response
+        .ordered_messages()
+        .map(|message| message.body_markdown.as_str())
+        .collect();
+    assert_eq!(bodies, vec!["Why was my post removed?", "Read rule 3"]);
+}
+
+#[test]
+fn state_display() {
+    assert_eq!(ConversationState::InProgress.to_string(), "inprogress");
+    assert_eq!(ConversationState::JoinRequests.to_string(), "join_requests");
+}
+
+#[tokio::test]
+async fn conversations_and_reply() {
+    let server = MockServer::start(|request| {
+        let body = if request.path.starts_with("/api/v1/me") {
+            include_str!("fixtures/me.json").to_string()
+        } else if request.path.starts_with("/api/mod/conversations?") {
+            let conversation: Value = serde_json::from_str(CONVERSATION).unwrap();
+            json!({
+                "conversations": {"1abcd": conversation["conversation"]},
+                "conversationIds": ["1abcd", "missing"],
+                "messages": {},
+                "viewerId": "t2_5678"
+            })
+            .to_string()
+        } else {
+            CONVERSATION.to_string()
+        };
+        Some(MockResponse::json(body))
+    })
+    .await;
+    let client = mock_client(&server).await;
+    let me = client.me().await.unwrap();
+    let modmail = me.modmail();
+
+    let options = FeedOption::builder().limit(10).build().unwrap();
+    let conversations = modmail
+        .conversations(ConversationState::New, Some(options))
+        .await
+        .unwrap();
+    let ids: Vec<&str> = conversations.ordered().map(|c| c.id.as_str()).collect();
+    assert_eq!(ids, vec!["1abcd"]);
+
+    let response = modmail.reply("1abcd", "Thanks", true, false).await.unwrap();
+    assert_eq!(response.conversation.id, "1abcd");
+
+    let requests = server.requests();
+    let list = &requests[requests.len() - 2];
+    assert_eq!(list.path, "/api/mod/conversations?state=new&limit=10");
+    let reply = &requests[requests.len() - 1];
+    assert_eq!(reply.method, "POST");
+    assert_eq!(reply.path, "/api/mod/conversations/1abcd");
+    assert_eq!(
+        reply.body,
+        "body=Thanks&isInternal=true&isAuthorHidden=false"
+    );
+}
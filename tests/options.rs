@@ -0,0 +1,193 @@
+mod common;
+
+use common::{mock_client, MockResponse, MockServer};
+use rraw::error::Error;
+use rraw::utils::options::{
+    CommentOption, CommentSort, FeedOption, FriendType, SearchSort, SubmissionSort, ThingType,
+    TimePeriod, UserSort,
+};
+
+#[test]
+fn friend_type() {
+    assert_eq!(FriendType::Contributor.to_string(), "contributor");
+    assert_eq!(FriendType::WikiContributor.to_string(), "wikicontributor");
+    assert_eq!(FriendType::Moderator.to_string(), "moderator");
+    assert_eq!(FriendType::Custom("muted".to_string()).to_string(), "muted");
+}
+
+#[test]
+fn empty_feed_option() {
+    let options = FeedOption::default();
+    assert_eq!(options.url(), "");
+    let mut path = "/user/KingTuxWH/comments.json".to_string();
+    options.extend(&mut path);
+    assert_eq!(path, "/user/KingTuxWH/comments.json");
+}
+
+#[test]
+fn feed_option_builder() {
+    let options = FeedOption::builder()
+        .before("t3_abc")
+        .after("t3_def")
+        .time(TimePeriod::AllTime)
+        .build()
+        .unwrap();
+    assert_eq!(options.before, None);
+    assert_eq!(options.url(), "after=t3_def&t=all");
+
+    let mut path = "/subreddits/search?q=rust".to_string();
+    options.extend(&mut path);
+    assert_eq!(path, "/subreddits/search?q=rust&after=t3_def&t=all");
+}
+
+#[test]
+fn feed_option_encoding() {
+    let options = FeedOption::builder().after("t3_a&b=c d").build().unwrap();
+    assert_eq!(options.url(), "after=t3_a%26b%3Dc+d");
+}
+
+#[test]
+fn feed_option_limit() {
+    assert!(FeedOption::builder().limit(100).build().is_ok());
+    for limit in [0, 101] {
+        let error = FeedOption::builder().limit(limit).build().unwrap_err();
+        assert!(matches!(error, Error::InvalidOption(_)), "{:?}", error);
+    }
+}
+
+#[test]
+fn feed_option_clamps_limit() {
+    let options = FeedOption {
+        limit: Some(500),
+        ..FeedOption::default()
+    };
+    assert_eq!(options.url(), "limit=100");
+    let options = FeedOption {
+        limit: Some(0),
+        ..FeedOption::default()
+    };
+    assert_eq!(options.url(), "limit=1");
+}
+
+#[test]
+fn comment_option() {
+    let options = CommentOption {
+        sort: Some(CommentSort::Top),
+        depth: Some(2),
+        ..CommentOption::default()
+    };
+    let mut path = "/r/rust/comments/abc".to_string();
+    options.extend(&mut path);
+    assert_eq!(path, "/r/rust/comments/abc?sort=top&depth=2");
+}
+
+#[test]
+fn comment_option_truncate() {
+    let options = CommentOption {
+        truncate: Some(80),
+        show_more: Some(true),
+        ..CommentOption::default()
+    };
+    assert_eq!(options.url(), "truncate=50&showmore=true");
+    assert_eq!(CommentOption::default().url(), "");
+}
+
+#[test]
+fn thing_type() {
+    let mut path = "/user/KingTuxWH/saved".to_string();
+    ThingType::Comments.extend(&mut path);
+    assert_eq!(path, "/user/KingTuxWH/saved?type=comments");
+
+    let mut path = "/user/KingTuxWH/saved".to_string();
+    FeedOption::builder()
+        .limit(5)
+        .build()
+        .unwrap()
+        .extend(&mut path);
+    ThingType::Links.extend(&mut path);
+    assert_eq!(path, "/user/KingTuxWH/saved?limit=5&type=links");
+}
+
+#[test]
+fn sorts() {
+    assert_eq!(CommentSort::Best.to_string(), "confidence");
+    assert_eq!(CommentSort::QandA.to_string(), "qa");
+    assert_eq!(
+        serde_json::to_value(CommentSort::QandA).unwrap(),
+        serde_json::to_value(CommentSort::QandA.to_string()).unwrap()
+    );
+    assert_eq!(
+        serde_json::to_value(CommentSort::Best).unwrap(),
+        "confidence"
+    );
+    assert_eq!(SubmissionSort::Rising.to_string(), "rising");
+    assert_eq!(UserSort::Controversial.to_string(), "controversial");
+    assert!(UserSort::Top.uses_period() && !UserSort::Hot.uses_period());
+    assert_eq!(SearchSort::Comments.to_string(), "comments");
+    let options = CommentOption {
+        sort: Some(CommentSort::QandA),
+        ..CommentOption::default()
+    };
+    assert_eq!(options.url(), "sort=qa");
+}
+
+#[test]
+fn feed_option_merge() {
+    let defaults = FeedOption::builder()
+        .limit(100)
+        .after("t3_default")
+        .sr_detail(true)
+        .build()
+        .unwrap();
+    let explicit = FeedOption::builder()
+        .limit(10)
+        .before("t3_mine")
+        .build()
+        .unwrap();
+    let merged = explicit.merge(&defaults);
+    assert_eq!(merged.limit, Some(10));
+    assert_eq!(merged.before.as_deref(), Some("t3_mine"));
+    assert_eq!(merged.after, None);
+    assert_eq!(merged.sr_detail, Some(true));
+
+    let merged = FeedOption::default().merge(&defaults);
+    assert_eq!(merged.url(), defaults.url());
+}
+
+#[tokio::test]
+async fn me_default_feed_option() {
+    let server = MockServer::start(|request| {
+        let body = match request.path.as_str() {
+            "/api/v1/me" => include_str!("fixtures/me.json"),
+            _ => include_str!("fixtures/overview.json"),
+        };
+        Some(MockResponse::json(body))
+    })
+    .await;
+    let client = mock_client(&server).await;
+    let me = client
+        .me()
+        .await
+        .unwrap()
+        .with_default_feed_option(FeedOption::builder().limit(100).build().unwrap());
+    me.saved(None, None).await.unwrap();
+    let partial = FeedOption::builder().after("t3_15bfi0").build().unwrap();
+    me.saved(Some(partial), None).await.unwrap();
+    let explicit = FeedOption::builder().limit(5).build().unwrap();
+    me.saved(Some(explicit), None).await.unwrap();
+
+    let paths: Vec<String> = server
+        .requests()
+        .into_iter()
+        .map(|request| request.path)
+        .filter(|path| path.contains("/saved"))
+        .collect();
+    assert_eq!(
+        paths,
+        vec![
+            "/user/KingTuxWH/saved?limit=100",
+            "/user/KingTuxWH/saved?after=t3_15bfi0&limit=100",
+            "/user/KingTuxWH/saved?limit=5",
+        ]
+    );
+}
@@ -0,0 +1,307 @@
+mod common;
+
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use common::{mock_client, MockResponse, MockServer};
+use futures_util::StreamExt;
+use rraw::error::http_error::HTTPError;
+use rraw::error::Error;
+use rraw::paginator::{Paginator, PartialError};
+use rraw::responses::listing::GenericListing;
+use rraw::utils::options::FeedOption;
+use serde_json::{json, Value};
+
+fn page(ids: &[u32], after: Option<&str>) -> GenericListing<Value> {
+    let children: Vec<Value> = ids
+        .iter()
+        .map(|id| json!({"kind": "t3", "data": {"id": id}}))
+        .collect();
+    serde_json::from_value(json!({
+        "kind": "Listing",
+        "data": {"modhash": null, "after": after, "before": null, "children": children}
+    }))
+    .unwrap()
+}
+
+/// Serves the pages in order recording the `after` of each request
+fn paginator<'a>(
+    pages: Vec<Result<GenericListing<Value>, Error>>,
+    requests: &'a RefCell<Vec<Option<String>>>,
+) -> Paginator<'a, Value> {
+    let mut pages = pages.into_iter();
+    Paginator::new(FeedOption::default(), move |options: FeedOption| {
+        requests.borrow_mut().push(options.after.clone());
+        let page = pages.next().expect("Requested a page after the end");
+        async move { page }
+    })
+    .delay(Duration::ZERO)
+}
+
+async fn ids(paginator: Paginator<'_, Value>) -> Vec<u64> {
+    paginator
+        .map(|item| item.unwrap()["id"].as_u64().unwrap())
+        .collect()
+        .await
+}
+
+#[tokio::test]
+async fn follows_after() {
+    let requests = RefCell::new(Vec::new());
+    let pages = vec![
+        Ok(page(&[1, 2], Some("t3_2"))),
+        Ok(page(&[3, 4], Some("t3_4"))),
+        Ok(page(&[5], None)),
+    ];
+    assert_eq!(ids(paginator(pages, &requests)).await, vec![1, 2, 3, 4, 5]);
+    assert_eq!(
+        requests.into_inner(),
+        vec![None, Some("t3_2".to_string()), Some("t3_4".to_string())]
+    );
+}
+
+#[tokio::test]
+async fn stops_on_empty_page() {
+    let requests = RefCell::new(Vec::new());
+    let pages = vec![Ok(page(&[1, 2], Some("t3_2"))), Ok(page(&[], Some("t3_2")))];
+    assert_eq!(ids(paginator(pages, &requests)).await, vec![1, 2]);
+    assert_eq!(requests.borrow().len(), 2);
+}
+
+#[tokio::test]
+async fn item_limit() {
+    let requests = RefCell::new(Vec::new());
+    let pages = vec![
+        Ok(page(&[1, 2], Some("t3_2"))),
+        Ok(page(&[3, 4], Some("t3_4"))),
+        Ok(page(&[5], None)),
+    ];
+    let paginator = paginator(pages, &requests).limit(3);
+    assert_eq!(ids(paginator).await, vec![1, 2, 3]);
+    assert_eq!(requests.borrow().len(), 2);
+}
+
+#[tokio::test]
+async fn error_ends_stream() {
+    let requests = RefCell::new(Vec::new());
+    let pages = vec![
+        Ok(page(&[1], Some("t3_1"))),
+        Err(HTTPError::NotFound.into()),
+        Ok(page(&[2], None)),
+    ];
+    let items: Vec<_> = paginator(pages, &requests).collect().await;
+    assert_eq!(items.len(), 2);
+    assert!(items[0].is_ok());
+    assert!(matches!(
+        items[1],
+        Err(Error::HTTPError {
+            error: HTTPError::NotFound,
+            ..
+        })
+    ));
+    assert_eq!(requests.borrow().len(), 2);
+}
+
+#[tokio::test]
+async fn collect_partial() {
+    let requests = RefCell::new(Vec::new());
+    let pages = vec![
+        Ok(page(&[1, 2], Some("t3_2"))),
+        Err(HTTPError::NotFound.into()),
+    ];
+    let PartialError { items, error } = paginator(pages, &requests)
+        .collect_partial()
+        .await
+        .unwrap_err();
+    assert_eq!(items.len(), 2);
+    assert!(matches!(
+        error,
+        Error::HTTPError {
+            error: HTTPError::NotFound,
+            ..
+        }
+    ));
+
+    let requests = RefCell::new(Vec::new());
+    let pages = vec![Ok(page(&[1, 2], Some("t3_2"))), Ok(page(&[3], None))];
+    let items = paginator(pages, &requests)
+        .limit(2)
+        .collect_partial()
+        .await
+        .unwrap();
+    assert_eq!(items.len(), 2);
+    assert_eq!(requests.borrow().len(), 1);
+}
+
+/// Serves a page of `id` after `delay` forever
+fn endless<'a>(delay: Duration, requests: &'a RefCell<u32>) -> Paginator<'a, Value> {
+    Paginator::new(FeedOption::default(), move |_| {
+        *requests.borrow_mut() += 1;
+        let id = *requests.borrow();
+        async move {
+            tokio::time::sleep(delay).await;
+            Ok(page(&[id], Some("t3_next")))
+        }
+    })
+    .delay(Duration::ZERO)
+}
+
+#[tokio::test]
+async fn deadline() {
+    let requests = RefCell::new(0);
+    let PartialError { items, error } = endless(Duration::from_millis(20), &requests)
+        .timeout(Duration::from_millis(150))
+        .collect_partial()
+        .await
+        .unwrap_err();
+    assert!(matches!(error, Error::DeadlineExceeded), "{:?}", error);
+    assert!(!items.is_empty());
+    // The page loading at the deadline is dropped. None is loading if the deadline passed between pages
+    let requests = *requests.borrow();
+    assert!(
+        requests == items.len() as u32 || requests == items.len() as u32 + 1,
+        "{} requests for {} items",
+        requests,
+        items.len()
+    );
+}
+
+#[tokio::test]
+async fn timeout_too_large() {
+    let requests = RefCell::new(0);
+    let items: Vec<_> = endless(Duration::ZERO, &requests)
+        .timeout(Duration::MAX)
+        .take(3)
+        .collect()
+        .await;
+    assert_eq!(items.len(), 3);
+    assert!(items.iter().all(Result::is_ok));
+}
+
+#[tokio::test]
+async fn deadline_drops_slow_page() {
+    let requests = RefCell::new(0);
+    let started = std::time::Instant::now();
+    let items: Vec<_> = endless(Duration::from_secs(60), &requests)
+        .deadline(started + Duration::from_millis(50))
+        .collect()
+        .await;
+    assert!(started.elapsed() < Duration::from_secs(5));
+    assert_eq!(items.len(), 1);
+    assert!(matches!(items[0], Err(Error::DeadlineExceeded)));
+    assert_eq!(*requests.borrow(), 1);
+}
+
+#[tokio::test]
+async fn deadline_without_waiting() {
+    let requests = RefCell::new(0);
+    let items: Vec<_> = endless(Duration::ZERO, &requests)
+        .deadline(std::time::Instant::now())
+        .collect()
+        .await;
+    assert!(matches!(items[..], [Err(Error::DeadlineExceeded)]));
+    assert_eq!(*requests.borrow(), 0);
+}
+
+/// 250 submissions. Several are created in the same second so pages end part way through a second
+fn submissions() -> Vec<Value> {
+    let fixture: Value = serde_json::from_str(include_str!("fixtures/submissions.json")).unwrap();
+    let template = &fixture["data"]["children"][0];
+    (0..250u64)
+        .rev()
+        .map(|index| {
+            let mut child = template.clone();
+            child["data"]["id"] = json!(format!("s{index}"));
+            child["data"]["name"] = json!(format!("t3_s{index}"));
+            child["data"]["created_utc"] = json!((1_690_000_000 + index * 7 / 10) as f64);
+            child
+        })
+        .collect()
+}
+
+/// Answers `timestamp:{start}..{end}` searches with the newest submissions of the window
+fn search(path: &str, submissions: &[Value]) -> Value {
+    let query = path.split("q=timestamp%3A").nth(1).unwrap();
+    let range = query.split('&').next().unwrap();
+    let (start, end) = range.split_once("..").unwrap();
+    let (start, end): (f64, f64) = (start.parse().unwrap(), end.parse().unwrap());
+    let children: Vec<&Value> = submissions
+        .iter()
+        .filter(|child| {
+            let created = child["data"]["created_utc"].as_f64().unwrap();
+            created >= start && created <= end
+        })
+        .take(100)
+        .collect();
+    json!({
+        "kind": "Listing",
+        "data": {"modhash": null, "after": null, "before": null, "children": children}
+    })
+}
+
+#[tokio::test]
+async fn submissions_between() {
+    let server = MockServer::start(|request| {
+        if request.path.starts_with("/r/rraw/about") {
+            return Some(MockResponse::json(include_str!(
+                "fixtures/subreddit_about.json"
+            )));
+        }
+        let body = search(&request.path, &submissions());
+        Some(MockResponse::json(body.to_string()))
+    })
+    .await;
+    let client = mock_client(&server).await;
+    let subreddit = client.subreddit("rraw").await.unwrap();
+    let start = UNIX_EPOCH + Duration::from_secs(1_690_000_010);
+    let end = UNIX_EPOCH + Duration::from_secs(1_690_000_160);
+    let loaded: Vec<String> = subreddit
+        .submissions_between(start, end)
+        .collect_partial()
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|submission| submission.id)
+        .collect();
+    let expected: Vec<String> = submissions()
+        .iter()
+        .filter(|child| {
+            (1_690_000_010.0..=1_690_000_160.0)
+                .contains(&child["data"]["created_utc"].as_f64().unwrap())
+        })
+        .map(|child| child["data"]["id"].as_str().unwrap().to_string())
+        .collect();
+    assert_eq!(loaded, expected);
+    assert_eq!(loaded.iter().collect::<HashSet<_>>().len(), loaded.len());
+    let searches: Vec<String> = server
+        .requests()
+        .into_iter()
+        .map(|request| request.path)
+        .filter(|path| path.contains("search.json"))
+        .collect();
+    assert_eq!(searches.len(), 3);
+    assert!(searches[0].contains("q=timestamp%3A1690000010..1690000160"));
+    assert!(searches[0].contains("sort=new"));
+    assert!(searches[0].contains("syntax=cloudsearch"));
+    assert!(!searches[1].contains("..1690000160"));
+}
+
+#[tokio::test]
+async fn submissions_between_empty_range() {
+    let server = MockServer::start(|_| {
+        Some(MockResponse::json(include_str!(
+            "fixtures/subreddit_about.json"
+        )))
+    })
+    .await;
+    let client = mock_client(&server).await;
+    let subreddit = client.subreddit("rraw").await.unwrap();
+    let now = SystemTime::now();
+    let loaded = subreddit
+        .submissions_between(now, now - Duration::from_secs(60))
+        .collect_partial()
+        .await
+        .unwrap();
+    assert!(loaded.is_empty());
+}
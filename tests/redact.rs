@@ -0,0 +1,43 @@
+use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION, USER_AGENT};
+use rraw::auth::TokenResponseData;
+use rraw::utils::redact::{redact_headers, redact_url, REDACTED};
+
+#[test]
+fn url() {
+    assert_eq!(
+        redact_url("https://oauth.reddit.com/r/rust/hot?limit=5"),
+        "https://oauth.reddit.com/r/rust/hot?limit=5"
+    );
+    assert_eq!(
+        redact_url("https://www.reddit.com/api/v1/access_token?grant_type=authorization_code&code=abc&Password=hunter2"),
+        format!("https://www.reddit.com/api/v1/access_token?grant_type=authorization_code&code={REDACTED}&Password={REDACTED}")
+    );
+}
+
+#[test]
+fn headers() {
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        AUTHORIZATION,
+        HeaderValue::from_static("Bearer secret-token"),
+    );
+    headers.insert(USER_AGENT, HeaderValue::from_static("RRAW Test"));
+    let redacted = redact_headers(&headers);
+    assert_eq!(
+        redacted[AUTHORIZATION],
+        format!("Bearer {REDACTED}").as_str()
+    );
+    assert_eq!(redacted[USER_AGENT], "RRAW Test");
+    assert!(!format!("{:?}", redacted).contains("secret-token"));
+}
+
+#[test]
+fn token_response_debug() {
+    let response: TokenResponseData = serde_json::from_str(
+        r#"{"access_token": "secret-token", "expires_in": 86400, "scope": "*", "token_type": "bearer", "refresh_token": "secret-refresh"}"#,
+    )
+    .unwrap();
+    let debug = format!("{:?}", response);
+    assert!(!debug.contains("secret-token"));
+    assert!(!debug.contains("secret-refresh"));
+}
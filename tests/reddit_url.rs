@@ -0,0 +1,156 @@
+mod common;
+
+use common::{MockResponse, MockServer, TestAuthenticator};
+use reqwest::Url;
+use rraw::error::url_error::RedditUrlError;
+use rraw::error::Error;
+use rraw::utils::reddit_url::{RedditUrl, UrlTarget};
+use rraw::Client;
+
+fn submission(subreddit: Option<&str>, id: &str) -> RedditUrl {
+    RedditUrl::Submission {
+        subreddit: subreddit.map(str::to_string),
+        id: id.to_string(),
+    }
+}
+
+#[test]
+fn parse() {
+    let cases = [
+        (
+            "https://www.reddit.com/r/rust/comments/15bfi0/rraw_release/",
+            submission(Some("rust"), "15bfi0"),
+        ),
+        (
+            "https://old.reddit.com/r/rust/comments/15bfi0",
+            submission(Some("rust"), "15bfi0"),
+        ),
+        ("https://redd.it/15bfi0", submission(None, "15bfi0")),
+        (
+            "https://reddit.com/comments/15bfi0/",
+            submission(None, "15bfi0"),
+        ),
+        (
+            "https://www.reddit.com/gallery/15bfi0",
+            submission(None, "15bfi0"),
+        ),
+        (
+            "https://new.reddit.com/r/rust/comments/15bfi0/rraw_release/jtq1a2b/?context=3",
+            RedditUrl::Comment {
+                subreddit: Some("rust".to_string()),
+                submission_id: "15bfi0".to_string(),
+                id: "jtq1a2b".to_string(),
+            },
+        ),
+        (
+            "https://www.reddit.com/user/KingTuxWH/comments/15bfi0/profile_post/",
+            submission(Some("u_KingTuxWH"), "15bfi0"),
+        ),
+        (
+            "https://www.reddit.com/r/rust/top/?t=week",
+            RedditUrl::Subreddit("rust".to_string()),
+        ),
+        (
+            "https://www.reddit.com/u/KingTuxWH",
+            RedditUrl::User("KingTuxWH".to_string()),
+        ),
+        (
+            "https://www.reddit.com/user/KingTuxWH/submitted/",
+            RedditUrl::User("KingTuxWH".to_string()),
+        ),
+        (
+            "https://www.reddit.com/r/rust/s/XyZ12aB",
+            RedditUrl::Share(Url::parse("https://www.reddit.com/r/rust/s/XyZ12aB").unwrap()),
+        ),
+    ];
+    for (url, expected) in cases {
+        assert_eq!(RedditUrl::parse(url), Ok(expected), "{url}");
+    }
+}
+
+#[test]
+fn parse_errors() {
+    assert!(matches!(
+        RedditUrl::parse("reddit.com/r/rust"),
+        Err(RedditUrlError::Malformed(_))
+    ));
+    for url in [
+        "https://example.com/r/rust",
+        "https://notreddit.com/r/rust",
+        "mailto:someone@reddit.com",
+    ] {
+        assert!(
+            matches!(RedditUrl::parse(url), Err(RedditUrlError::NotReddit(_))),
+            "{url}"
+        );
+    }
+    for url in [
+        "https://www.reddit.com/",
+        "https://www.reddit.com/settings",
+        "https://www.reddit.com/r/rust/comments/NOT-AN-ID/",
+        "https://redd.it/",
+    ] {
+        assert!(
+            matches!(RedditUrl::parse(url), Err(RedditUrlError::Unrecognized(_))),
+            "{url}"
+        );
+    }
+}
+
+#[tokio::test]
+async fn from_url() {
+    let server = MockServer::start(|request| match request.path.as_str() {
+        "/api/v1/me" => Some(MockResponse::json(include_str!("fixtures/me.json"))),
+        "/r/rraw/about.json" => Some(MockResponse::json(include_str!(
+            "fixtures/subreddit_about.json"
+        ))),
+        "/r/rust/s/XyZ12aB" => Some(MockResponse::json("").status(301).header(
+            "Location",
+            "https://www.reddit.com/r/rust/comments/15bfi0/rraw_release/jtq1a2b/",
+        )),
+        _ => None,
+    })
+    .await;
+    let client = Client::builder(TestAuthenticator, "RRAW Test (by u/KingTuxWH)")
+        .base_url(server.url.clone())
+        .login()
+        .await
+        .unwrap();
+    let me = client.me().await.unwrap();
+
+    match me.from_url("https://www.reddit.com/r/rraw/").await.unwrap() {
+        UrlTarget::Subreddit(subreddit) => assert_eq!(subreddit.subreddit.display_name, "rraw"),
+        _ => panic!("Expected a subreddit"),
+    }
+    match me.from_url("https://redd.it/15bfi0").await.unwrap() {
+        UrlTarget::Submission(submission) => {
+            assert_eq!(submission.fullname().to_string(), "t3_15bfi0")
+        }
+        _ => panic!("Expected a submission"),
+    }
+
+    let share = Url::parse(&format!("{}/r/rust/s/XyZ12aB", server.url)).unwrap();
+    match me.resolve_url(RedditUrl::Share(share)).await.unwrap() {
+        UrlTarget::Comment(comment) => assert_eq!(comment.fullname().to_string(), "t1_jtq1a2b"),
+        _ => panic!("Expected a comment"),
+    }
+    // The redirect is read without following it even though the default policy follows redirects
+    let share = server
+        .requests()
+        .into_iter()
+        .find(|request| request.path == "/r/rust/s/XyZ12aB")
+        .unwrap();
+    assert_eq!(
+        share.header("user-agent"),
+        Some("RRAW Test (by u/KingTuxWH)")
+    );
+
+    let requests = server.requests().len();
+    let error = me
+        .from_url("https://example.com/r/rust")
+        .await
+        .err()
+        .unwrap();
+    assert!(matches!(error, Error::Url(RedditUrlError::NotReddit(_))));
+    assert_eq!(server.requests().len(), requests);
+}
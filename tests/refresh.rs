@@ -0,0 +1,339 @@
+mod common;
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use common::{MockResponse, MockServer};
+use futures_util::future::join_all;
+use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION};
+use reqwest::Client as ReqwestClient;
+use reqwest::StatusCode;
+use rraw::access::Access;
+use rraw::auth::Authenticator;
+use rraw::error::http_error::HTTPError;
+use rraw::error::Error;
+use rraw::{Client, REFRESH_ATTEMPTS};
+use serde_json::json;
+
+/// Starts expired. Every refresh takes a while and hands out the next token
+#[derive(Clone, Debug, Default)]
+struct SlowRefresh {
+    refreshes: Arc<AtomicUsize>,
+    token: usize,
+}
+
+#[async_trait(?Send)]
+impl Authenticator for SlowRefresh {
+    async fn login(&mut self, _client: &ReqwestClient, _user_agent: &str) -> Result<bool, Error> {
+        Ok(true)
+    }
+    async fn logout(&mut self, _client: &ReqwestClient, _user_agent: &str) -> Result<(), Error> {
+        Ok(())
+    }
+    async fn token_refresh(
+        &mut self,
+        _client: &ReqwestClient,
+        _user_agent: &str,
+    ) -> Result<bool, Error> {
+        tokio::time::sleep(Duration::from_millis(300)).await;
+        self.token = self.refreshes.fetch_add(1, Ordering::SeqCst) + 1;
+        Ok(true)
+    }
+    fn headers(&self, headers: &mut HeaderMap) {
+        let value = HeaderValue::from_str(&format!("Bearer token-{}", self.token)).unwrap();
+        headers.insert(AUTHORIZATION, value);
+    }
+    fn oauth(&self) -> bool {
+        true
+    }
+    fn needs_token_refresh(&self) -> bool {
+        self.token == 0
+    }
+    fn get_refresh_token(&self) -> Option<String> {
+        None
+    }
+    fn invalidate_token(&mut self) {
+        self.token = 0;
+    }
+}
+
+#[tokio::test]
+async fn concurrent_refreshes_coalesce() {
+    let server = MockServer::start(|_| Some(MockResponse::json(json!({"ok": true})))).await;
+    let auth = SlowRefresh::default();
+    let refreshes = auth.refreshes.clone();
+    let client = Client::builder(auth, "RRAW Test (by u/KingTuxWH)")
+        .base_url(server.url.clone())
+        .login()
+        .await
+        .unwrap();
+
+    let requests = (0..8).map(|_| client.get_raw("/api/v1/me", Access::OAuthOnly));
+    for result in join_all(requests).await {
+        result.unwrap();
+    }
+    assert_eq!(refreshes.load(Ordering::SeqCst), 1);
+    for request in server.requests() {
+        assert_eq!(request.header("authorization"), Some("Bearer token-1"));
+    }
+
+    // Requests made during a refresh use the current token instead of waiting
+    let (refreshed, request) = tokio::join!(
+        client.re_login(),
+        tokio::time::timeout(
+            Duration::from_millis(150),
+            client.get_raw("/api/v1/me", Access::OAuthOnly)
+        )
+    );
+    assert!(refreshed.unwrap());
+    request.expect("The request waited on the refresh").unwrap();
+    assert_eq!(refreshes.load(Ordering::SeqCst), 2);
+    let last = server.requests().pop().unwrap();
+    assert_eq!(last.header("authorization"), Some("Bearer token-1"));
+}
+
+/// Starts expired. Refreshing fails with `status` until `failures` runs out
+#[derive(Clone, Debug)]
+struct Flaky {
+    attempts: Arc<AtomicUsize>,
+    failures: usize,
+    status: StatusCode,
+    refreshed: bool,
+}
+
+impl Flaky {
+    fn new(failures: usize, status: StatusCode) -> Flaky {
+        Flaky {
+            attempts: Arc::default(),
+            failures,
+            status,
+            refreshed: false,
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl Authenticator for Flaky {
+    async fn login(&mut self, _client: &ReqwestClient, _user_agent: &str) -> Result<bool, Error> {
+        Ok(true)
+    }
+    async fn logout(&mut self, _client: &ReqwestClient, _user_agent: &str) -> Result<(), Error> {
+        Ok(())
+    }
+    async fn token_refresh(
+        &mut self,
+        _client: &ReqwestClient,
+        _user_agent: &str,
+    ) -> Result<bool, Error> {
+        if self.attempts.fetch_add(1, Ordering::SeqCst) < self.failures {
+            return Err(Error::HTTPError {
+                error: HTTPError::from(self.status),
+                response: None,
+            });
+        }
+        self.refreshed = true;
+        Ok(true)
+    }
+    fn headers(&self, headers: &mut HeaderMap) {
+        headers.insert(AUTHORIZATION, HeaderValue::from_static("Bearer flaky"));
+    }
+    fn oauth(&self) -> bool {
+        true
+    }
+    fn needs_token_refresh(&self) -> bool {
+        !self.refreshed
+    }
+    fn get_refresh_token(&self) -> Option<String> {
+        None
+    }
+}
+
+async fn flaky_client(server: &MockServer, auth: Flaky) -> Client<Flaky> {
+    Client::builder(auth, "RRAW Test (by u/KingTuxWH)")
+        .base_url(server.url.clone())
+        .login()
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn refresh_retries_server_errors() {
+    let server = MockServer::start(|_| Some(MockResponse::json(json!({"ok": true})))).await;
+    let auth = Flaky::new(2, StatusCode::SERVICE_UNAVAILABLE);
+    let attempts = auth.attempts.clone();
+    let client = flaky_client(&server, auth).await;
+
+    client
+        .get_raw("/api/v1/me", Access::OAuthOnly)
+        .await
+        .unwrap();
+    assert_eq!(attempts.load(Ordering::SeqCst), 3);
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[tokio::test]
+async fn refresh_gives_up() {
+    let server = MockServer::start(|_| Some(MockResponse::json(json!({"ok": true})))).await;
+    let auth = Flaky::new(usize::MAX, StatusCode::BAD_GATEWAY);
+    let attempts = auth.attempts.clone();
+    let client = flaky_client(&server, auth).await;
+
+    let error = client
+        .get_raw("/api/v1/me", Access::OAuthOnly)
+        .await
+        .unwrap_err();
+    match error {
+        Error::AuthFailed(source) => assert_eq!(
+            source.http_error(),
+            Some(&HTTPError::Other(StatusCode::BAD_GATEWAY))
+        ),
+        error => panic!("Expected AuthFailed got {:?}", error),
+    }
+    assert_eq!(attempts.load(Ordering::SeqCst), REFRESH_ATTEMPTS as usize);
+    assert!(server.requests().is_empty());
+}
+
+#[tokio::test]
+async fn refresh_does_not_retry_client_errors() {
+    let server = MockServer::start(|_| Some(MockResponse::json(json!({"ok": true})))).await;
+    let auth = Flaky::new(usize::MAX, StatusCode::UNAUTHORIZED);
+    let attempts = auth.attempts.clone();
+    let client = flaky_client(&server, auth).await;
+
+    let error = client
+        .get_raw("/api/v1/me", Access::OAuthOnly)
+        .await
+        .unwrap_err();
+    assert!(matches!(error, Error::AuthFailed(_)), "{:?}", error);
+    assert_eq!(attempts.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn invalidated_token_logs_in_again() {
+    let server = MockServer::start(|_| Some(MockResponse::json(json!({"ok": true})))).await;
+    let auth = SlowRefresh::default();
+    let refreshes = auth.refreshes.clone();
+    let client = Client::builder(auth, "RRAW Test (by u/KingTuxWH)")
+        .base_url(server.url.clone())
+        .login()
+        .await
+        .unwrap();
+    client
+        .get_raw("/api/v1/me", Access::OAuthOnly)
+        .await
+        .unwrap();
+    assert_eq!(refreshes.load(Ordering::SeqCst), 1);
+
+    client.invalidate_token().await;
+    // Nothing is sent until the next request
+    assert_eq!(refreshes.load(Ordering::SeqCst), 1);
+    client
+        .get_raw("/api/v1/me", Access::OAuthOnly)
+        .await
+        .unwrap();
+    assert_eq!(refreshes.load(Ordering::SeqCst), 2);
+    let requests = server.requests();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[1].header("authorization"), Some("Bearer token-2"));
+}
+
+#[tokio::test]
+async fn invalidate_during_refresh() {
+    let server = MockServer::start(|_| Some(MockResponse::json(json!({"ok": true})))).await;
+    let (client, refreshes) = slow_client(&server, false).await;
+    // The token stored by the refresh does not replace the invalidated one
+    let (refreshed, ()) = tokio::join!(client.re_login(), async {
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        client.invalidate_token().await;
+    });
+    assert!(refreshed.unwrap());
+    assert_eq!(refreshes.load(Ordering::SeqCst), 1);
+    client
+        .get_raw("/api/v1/me", Access::OAuthOnly)
+        .await
+        .unwrap();
+    assert_eq!(refreshes.load(Ordering::SeqCst), 2);
+}
+
+async fn slow_client(
+    server: &MockServer,
+    relogin: bool,
+) -> (Client<SlowRefresh>, Arc<AtomicUsize>) {
+    let auth = SlowRefresh::default();
+    let refreshes = auth.refreshes.clone();
+    let client = Client::builder(auth, "RRAW Test (by u/KingTuxWH)")
+        .base_url(server.url.clone())
+        .relogin_on_unauthorized(relogin)
+        .login()
+        .await
+        .unwrap();
+    (client, refreshes)
+}
+
+#[tokio::test]
+async fn unauthorized_logs_in_again() {
+    // The first token is revoked before it expires
+    let server = MockServer::start(|request| {
+        let response = MockResponse::json(json!({"ok": true}));
+        if request.header("authorization") == Some("Bearer token-1") {
+            Some(response.status(401))
+        } else {
+            Some(response)
+        }
+    })
+    .await;
+    let (client, refreshes) = slow_client(&server, true).await;
+
+    let (first, second) = tokio::join!(
+        client.post_raw("/api/save", &[("id", "t3_15bfi0")], Access::OAuthOnly),
+        client.get_raw("/api/v1/me", Access::OAuthOnly)
+    );
+    assert_eq!(first.unwrap()["ok"], true);
+    assert_eq!(second.unwrap()["ok"], true);
+    // One refresh for the expired token and one for the revoked token
+    assert_eq!(refreshes.load(Ordering::SeqCst), 2);
+    let requests = server.requests();
+    assert_eq!(requests.len(), 4);
+    let resent = requests
+        .iter()
+        .find(|request| {
+            request.path == "/api/save" && request.header("authorization") == Some("Bearer token-2")
+        })
+        .expect("The post was not sent again");
+    assert_eq!(resent.body, "id=t3_15bfi0");
+}
+
+#[tokio::test]
+async fn unauthorized_twice() {
+    let server = MockServer::start(|_| Some(MockResponse::json("{}").status(401))).await;
+    let (client, refreshes) = slow_client(&server, true).await;
+
+    let error = client
+        .get_raw("/api/v1/me", Access::OAuthOnly)
+        .await
+        .unwrap_err();
+    assert!(matches!(error, Error::AuthenticationExpired), "{:?}", error);
+    assert_eq!(refreshes.load(Ordering::SeqCst), 2);
+    let requests = server.requests();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[1].header("authorization"), Some("Bearer token-2"));
+}
+
+#[tokio::test]
+async fn unauthorized_without_relogin() {
+    let server = MockServer::start(|_| Some(MockResponse::json("{}").status(401))).await;
+    let (client, refreshes) = slow_client(&server, false).await;
+
+    let error = client
+        .get_raw("/api/v1/me", Access::OAuthOnly)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        error.http_error(),
+        Some(&HTTPError::Other(StatusCode::UNAUTHORIZED))
+    );
+    assert_eq!(refreshes.load(Ordering::SeqCst), 1);
+    assert_eq!(server.requests().len(), 1);
+}
@@ -0,0 +1,66 @@
+mod common;
+
+use common::{MockResponse, MockServer};
+use rraw::auth::AnonymousAuthenticator;
+use rraw::error::internal_error::InternalError;
+use rraw::error::Error;
+use rraw::rss::{parse_feed, RssItem};
+use rraw::Client;
+
+const FEED: &str = include_str!("fixtures/new.rss");
+
+#[test]
+fn entries() {
+    let items = parse_feed("https://www.reddit.com/r/rraw/new.rss", FEED).unwrap();
+    assert_eq!(
+        items[0],
+        RssItem {
+            id: "t3_15bfi0".to_string(),
+            title: "RRAW 1.2 Released & more".to_string(),
+            link: "https://www.reddit.com/r/rraw/comments/15bfi0/rraw_12_released/".to_string(),
+            author: Some("KingTuxWH".to_string()),
+            published: Some("2023-07-27T20:31:50+00:00".to_string()),
+        }
+    );
+    assert_eq!(items[1].author, None);
+    assert_eq!(items.len(), 2);
+}
+
+#[test]
+fn invalid_feed() {
+    let error = parse_feed("https://www.reddit.com/r/rraw/new.rss", "<feed>").unwrap_err();
+    assert!(matches!(
+        error,
+        Error::InternalError(InternalError::FeedError { .. })
+    ));
+}
+
+#[tokio::test]
+async fn feeds() {
+    let server = MockServer::start(|request| {
+        let response = match request.path.as_str() {
+            "/r/rraw/about.json" => {
+                MockResponse::json(include_str!("fixtures/subreddit_about.json"))
+            }
+            "/u/KingTuxWH/about.json" => {
+                MockResponse::json(include_str!("fixtures/user_about.json"))
+            }
+            _ => MockResponse::json(FEED).header("Content-Type", "application/atom+xml"),
+        };
+        Some(response)
+    })
+    .await;
+    let client = Client::builder(AnonymousAuthenticator::new(), "RRAW Test (by u/KingTuxWH)")
+        .base_url(server.url.clone())
+        .login()
+        .await
+        .unwrap();
+    let subreddit = client.subreddit("rraw").await.unwrap();
+    assert_eq!(subreddit.new_rss().await.unwrap().len(), 2);
+    let user = client.user("KingTuxWH").await.unwrap();
+    assert_eq!(user.submissions_rss().await.unwrap()[0].id, "t3_15bfi0");
+
+    let paths: Vec<String> = server.requests().into_iter().map(|r| r.path).collect();
+    assert_eq!(paths[1], "/r/rraw/new.rss");
+    assert_eq!(paths[3], "/user/KingTuxWH/submitted.rss");
+}
@@ -0,0 +1,71 @@
+mod common;
+
+use common::{mock_client, MockResponse, MockServer};
+use rraw::access::Access;
+use rraw::auth::{AnonymousAuthenticator, Scope, ScopeDescriptions};
+use rraw::Client;
+
+fn scopes() -> ScopeDescriptions {
+    serde_json::from_str(include_str!("fixtures/scopes.json")).unwrap()
+}
+
+fn assert_known(scopes: &ScopeDescriptions) {
+    for (id, description) in scopes {
+        assert_eq!(&description.id, id);
+        let scope = description.scope();
+        assert!(scope.is_some(), "Scope is missing `{id}`");
+        assert_eq!(scope.unwrap().id(), id);
+    }
+}
+
+#[test]
+fn every_scope_is_known() {
+    let scopes = scopes();
+    assert_known(&scopes);
+    // Scopes Reddit no longer lists should be removed from the enum
+    for scope in Scope::ALL {
+        assert!(scopes.contains_key(scope.id()), "Reddit has no `{scope}`");
+    }
+    assert_eq!(scopes.len(), Scope::ALL.len());
+}
+
+#[test]
+fn ids() {
+    for scope in Scope::ALL {
+        assert_eq!(Scope::from_id(scope.id()), Some(scope));
+        let json = serde_json::to_string(&scope).unwrap();
+        assert_eq!(json, format!("\"{scope}\""));
+        assert_eq!(serde_json::from_str::<Scope>(&json).unwrap(), scope);
+    }
+    assert_eq!(Scope::from_id("mysubreddits"), Some(Scope::MySubreddits));
+    assert_eq!(Scope::from_id("unknown"), None);
+}
+
+#[tokio::test]
+async fn oauth_scopes() {
+    let server = MockServer::start(|request| {
+        let body = match request.path.as_str() {
+            "/api/v1/me" => include_str!("fixtures/me.json"),
+            _ => include_str!("fixtures/scopes.json"),
+        };
+        Some(MockResponse::json(body))
+    })
+    .await;
+    let client = mock_client(&server).await;
+    let me = client.me().await.unwrap();
+    let scopes = me.oauth_scopes().await.unwrap();
+    assert_eq!(scopes["mysubreddits"].name, "My Subreddits");
+    assert_eq!(scopes["read"].scope(), Some(Scope::Read));
+    assert_eq!(server.requests()[1].path, "/api/v1/scopes");
+}
+
+/// Checks the enum against the scopes Reddit currently lists
+#[ignore]
+#[tokio::test]
+async fn live_scopes_are_known() -> anyhow::Result<()> {
+    let client = Client::login(AnonymousAuthenticator::new(), "RRAW Test (by u/KingTuxWH)").await?;
+    let scopes = client.get_raw("/api/v1/scopes", Access::Any).await?;
+    let scopes: ScopeDescriptions = serde_json::from_value(scopes)?;
+    assert_known(&scopes);
+    Ok(())
+}
@@ -0,0 +1,129 @@
+mod common;
+
+use common::{mock_client, MockResponse, MockServer};
+use rraw::utils::options::SearchSort;
+use rraw::utils::search::{raw, SearchQuery};
+
+#[test]
+fn fields() {
+    let query = SearchQuery::new()
+        .author("spez")
+        .subreddit("rust")
+        .url("github.com/wherkamp")
+        .site("github.com")
+        .self_posts(false)
+        .nsfw(false);
+    assert_eq!(
+        query.to_string(),
+        "author:spez subreddit:rust url:github.com/wherkamp site:github.com self:no nsfw:no"
+    );
+}
+
+#[test]
+fn quoting() {
+    let cases = [
+        (SearchQuery::new().flair("Discussion"), "flair:Discussion"),
+        (
+            SearchQuery::new().flair("Help Wanted"),
+            r#"flair:"Help Wanted""#,
+        ),
+        (
+            SearchQuery::new().title(r#"The "best" crate"#),
+            r#"title:"The \"best\" crate""#,
+        ),
+        (
+            SearchQuery::new().title(r#"a\b "c""#),
+            r#"title:"a\\b \"c\"""#,
+        ),
+        (SearchQuery::new().flair(""), r#"flair:"""#),
+        (SearchQuery::new().url("a:b"), r#"url:"a:b""#),
+        (SearchQuery::new().phrase("async rust"), r#""async rust""#),
+        (SearchQuery::new().text("async  rust"), "async rust"),
+        (SearchQuery::new().text(r#"say "hi""#), r#"say "\"hi\"""#),
+        (
+            SearchQuery::new().text("cats AND dogs OR NOT and"),
+            r#"cats "AND" dogs "OR" "NOT" and"#,
+        ),
+        (
+            SearchQuery::new().selftext("(draft)"),
+            r#"selftext:"(draft)""#,
+        ),
+    ];
+    for (query, expected) in cases {
+        assert_eq!(query.to_string(), expected);
+    }
+}
+
+#[test]
+fn operators() {
+    let query = SearchQuery::new()
+        .text("rraw")
+        .or(SearchQuery::new().author("KingTuxWH").subreddit("rust"))
+        .not(SearchQuery::new().flair("Meme"));
+    assert_eq!(
+        query.to_string(),
+        "(rraw OR (author:KingTuxWH subreddit:rust)) NOT flair:Meme"
+    );
+    // A left side with more than one term is grouped too
+    let query = SearchQuery::new()
+        .text("async")
+        .and(SearchQuery::new().text("rust"))
+        .or(SearchQuery::new().text("tokio"));
+    assert_eq!(query.to_string(), "(async AND rust) OR tokio");
+    let query = SearchQuery::new()
+        .author("KingTuxWH")
+        .subreddit("rust")
+        .not(SearchQuery::new().flair("Meme"));
+    assert_eq!(
+        query.to_string(),
+        "(author:KingTuxWH subreddit:rust) NOT flair:Meme"
+    );
+    // Joining onto an empty query does not start with an operator
+    assert_eq!(
+        SearchQuery::new().and(raw("\"exact phrase\"")).to_string(),
+        "\"exact phrase\""
+    );
+    assert_eq!(
+        SearchQuery::new()
+            .not(SearchQuery::new().nsfw(true))
+            .to_string(),
+        "NOT nsfw:yes"
+    );
+    // Empty queries are ignored
+    let query = SearchQuery::new()
+        .text("rraw")
+        .and(SearchQuery::new())
+        .or(raw("  "));
+    assert_eq!(query.to_string(), "rraw");
+    assert!(SearchQuery::new().is_empty());
+    assert_eq!(String::from(SearchQuery::new().text("rraw")), "rraw");
+}
+
+#[tokio::test]
+async fn subreddit_search() {
+    let server = MockServer::start(|request| {
+        let body = match request.path.as_str() {
+            "/r/rraw/about.json" => include_str!("fixtures/subreddit_about.json"),
+            _ => include_str!("fixtures/submissions.json"),
+        };
+        Some(MockResponse::json(body))
+    })
+    .await;
+    let client = mock_client(&server).await;
+    let subreddit = client.subreddit("rraw").await.unwrap();
+    let query = SearchQuery::new().flair("Help Wanted").self_posts(true);
+    subreddit
+        .search(&query, Some(SearchSort::New), None)
+        .await
+        .unwrap();
+    subreddit.search("rraw", None, None).await.unwrap();
+    let requests = server.requests();
+    assert_eq!(
+        requests[1].path,
+        "/r/rraw/search.json?q=flair%3A%22Help+Wanted%22+self%3Ayes&restrict_sr=on&sort=new"
+    );
+    assert_eq!(
+        requests[2].path,
+        "/r/rraw/search.json?q=rraw&restrict_sr=on"
+    );
+}
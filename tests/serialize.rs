@@ -0,0 +1,46 @@
+use rraw::comments::response::CommentsResponse;
+use rraw::message::response::InboxListing;
+use rraw::responses::listing::RedditListing;
+use rraw::submission::response::SubmissionsResponse;
+use rraw::subreddit::emoji::Emojis;
+use rraw::user::response::UserResponse;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::fmt::Debug;
+
+fn round_trip<T: DeserializeOwned + Serialize + PartialEq + Debug>(fixture: &str) {
+    let parsed: T = serde_json::from_str(fixture).expect("Unable to parse the fixture");
+    let serialized = serde_json::to_string(&parsed).expect("Unable to serialize the fixture");
+    let reparsed: T = serde_json::from_str(&serialized).expect("Unable to parse the output");
+    assert_eq!(parsed, reparsed);
+}
+
+#[test]
+fn submissions() {
+    round_trip::<SubmissionsResponse>(include_str!("fixtures/submissions.json"));
+}
+
+#[test]
+fn comments() {
+    round_trip::<CommentsResponse>(include_str!("fixtures/comments.json"));
+}
+
+#[test]
+fn user_about() {
+    round_trip::<UserResponse>(include_str!("fixtures/user_about.json"));
+}
+
+#[test]
+fn overview() {
+    round_trip::<RedditListing>(include_str!("fixtures/overview.json"));
+}
+
+#[test]
+fn emojis() {
+    round_trip::<Emojis>(include_str!("fixtures/emojis.json"));
+}
+
+#[test]
+fn inbox() {
+    round_trip::<InboxListing>(include_str!("fixtures/inbox.json"));
+}
@@ -0,0 +1,186 @@
+//! The simd-json backend parses every recorded payload like serde_json
+mod common;
+
+use common::{mock_client, MockResponse, MockServer};
+use rraw::access::Access;
+use rraw::comments::response::CommentsResponse;
+use rraw::error::internal_error::InternalError;
+use rraw::error::Error;
+use rraw::responses::listing::MixedListing;
+use rraw::submission::response::SubmissionsResponse;
+use rraw::user::response::UserResponse;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::fmt::Debug;
+
+const FIXTURES: [(&str, &str); 34] = [
+    ("collection.json", include_str!("fixtures/collection.json")),
+    (
+        "comment_tree.json",
+        include_str!("fixtures/comment_tree.json"),
+    ),
+    ("comments.json", include_str!("fixtures/comments.json")),
+    (
+        "comments_sr_detail.json",
+        include_str!("fixtures/comments_sr_detail.json"),
+    ),
+    (
+        "edit_comment.json",
+        include_str!("fixtures/edit_comment.json"),
+    ),
+    ("emojis.json", include_str!("fixtures/emojis.json")),
+    ("inbox.json", include_str!("fixtures/inbox.json")),
+    ("link_flair.json", include_str!("fixtures/link_flair.json")),
+    (
+        "live_updates.json",
+        include_str!("fixtures/live_updates.json"),
+    ),
+    ("me.json", include_str!("fixtures/me.json")),
+    (
+        "moderated_subreddits.json",
+        include_str!("fixtures/moderated_subreddits.json"),
+    ),
+    (
+        "modmail_conversation.json",
+        include_str!("fixtures/modmail_conversation.json"),
+    ),
+    (
+        "morechildren.json",
+        include_str!("fixtures/morechildren.json"),
+    ),
+    (
+        "multireddits.json",
+        include_str!("fixtures/multireddits.json"),
+    ),
+    ("overview.json", include_str!("fixtures/overview.json")),
+    (
+        "post_requirements.json",
+        include_str!("fixtures/post_requirements.json"),
+    ),
+    ("rules.json", include_str!("fixtures/rules.json")),
+    ("scopes.json", include_str!("fixtures/scopes.json")),
+    (
+        "submission_hidden_score.json",
+        include_str!("fixtures/submission_hidden_score.json"),
+    ),
+    (
+        "submission_poll.json",
+        include_str!("fixtures/submission_poll.json"),
+    ),
+    (
+        "submissions.json",
+        include_str!("fixtures/submissions.json"),
+    ),
+    (
+        "submissions_sr_detail.json",
+        include_str!("fixtures/submissions_sr_detail.json"),
+    ),
+    (
+        "submit_text.json",
+        include_str!("fixtures/submit_text.json"),
+    ),
+    (
+        "subreddit_about.json",
+        include_str!("fixtures/subreddit_about.json"),
+    ),
+    ("trophies.json", include_str!("fixtures/trophies.json")),
+    ("user_about.json", include_str!("fixtures/user_about.json")),
+    (
+        "user_about_employee.json",
+        include_str!("fixtures/user_about_employee.json"),
+    ),
+    (
+        "user_about_new.json",
+        include_str!("fixtures/user_about_new.json"),
+    ),
+    ("widgets.json", include_str!("fixtures/widgets.json")),
+    (
+        "api_errors/bad_captcha.json",
+        include_str!("fixtures/api_errors/bad_captcha.json"),
+    ),
+    (
+        "api_errors/ratelimit.json",
+        include_str!("fixtures/api_errors/ratelimit.json"),
+    ),
+    (
+        "api_errors/subreddit_noexist.json",
+        include_str!("fixtures/api_errors/subreddit_noexist.json"),
+    ),
+    (
+        "api_errors/success.json",
+        include_str!("fixtures/api_errors/success.json"),
+    ),
+    (
+        "api_errors/user_required.json",
+        include_str!("fixtures/api_errors/user_required.json"),
+    ),
+];
+
+async fn fixture_server() -> MockServer {
+    MockServer::start(|request| {
+        FIXTURES
+            .iter()
+            .find(|(name, _)| request.path == format!("/fixtures/{name}"))
+            .map(|(_, body)| MockResponse::json(body))
+    })
+    .await
+}
+
+#[tokio::test]
+async fn fixtures_match_serde_json() {
+    let server = fixture_server().await;
+    let client = mock_client(&server).await;
+    for (name, body) in FIXTURES {
+        let parsed = client
+            .get_raw(&format!("/fixtures/{name}"), Access::Any)
+            .await
+            .unwrap_or_else(|error| panic!("{name}: {error}"));
+        let expected: Value = serde_json::from_str(body).unwrap();
+        assert_eq!(parsed, expected, "{name}");
+    }
+}
+
+async fn typed<T: DeserializeOwned + PartialEq + Debug>(server: &MockServer, name: &str) {
+    let client = mock_client(server).await;
+    let (parsed, raw) = client
+        .get_json_with_raw::<T>(&format!("/fixtures/{name}"), Access::Any)
+        .await
+        .unwrap_or_else(|error| panic!("{name}: {error}"));
+    let expected: T = serde_json::from_str(&raw).unwrap();
+    assert_eq!(parsed, expected, "{name}");
+}
+
+#[tokio::test]
+async fn typed_fixtures_match_serde_json() {
+    let server = fixture_server().await;
+    typed::<SubmissionsResponse>(&server, "submissions.json").await;
+    typed::<SubmissionsResponse>(&server, "submissions_sr_detail.json").await;
+    typed::<CommentsResponse>(&server, "comments.json").await;
+    typed::<CommentsResponse>(&server, "comments_sr_detail.json").await;
+    typed::<MixedListing>(&server, "overview.json").await;
+    typed::<UserResponse>(&server, "user_about.json").await;
+}
+
+/// Failures come from simd-json. The snippet is taken from the body before simd-json rewrote it
+#[tokio::test]
+async fn simd_error_path() {
+    let server = MockServer::start(|_| {
+        Some(MockResponse::json(
+            include_str!("fixtures/overview.json").replace("\"score\": 42", "\"score\": \"lots\""),
+        ))
+    })
+    .await;
+    let client = mock_client(&server).await;
+    let error = client
+        .get_json_with_raw::<MixedListing>("/user/KingTuxWH/overview", Access::Any)
+        .await
+        .err()
+        .unwrap();
+    match error {
+        Error::InternalError(InternalError::SimdDeserializeError { path, snippet, .. }) => {
+            assert_eq!(path, "data.children[1].data.score");
+            assert!(snippet.contains("\"score\": \"lots\""), "{snippet}");
+        }
+        error => panic!("Expected a SimdDeserializeError got {error:?}"),
+    }
+}
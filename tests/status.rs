@@ -0,0 +1,127 @@
+use rraw::comments::response::CommentResponse;
+use rraw::responses::status::{BannedBy, ContentStatus};
+use rraw::submission::response::SubmissionResponse;
+use serde_json::{json, Value};
+
+fn submission(changes: Value) -> SubmissionResponse {
+    let listing: Value = serde_json::from_str(include_str!("fixtures/submissions.json")).unwrap();
+    let mut data = listing["data"]["children"][0]["data"].clone();
+    for (key, value) in changes.as_object().unwrap() {
+        data[key] = value.clone();
+    }
+    serde_json::from_value(data).unwrap()
+}
+
+fn comment(changes: Value) -> CommentResponse {
+    let listing: Value = serde_json::from_str(include_str!("fixtures/comments.json")).unwrap();
+    let mut data = listing["data"]["children"][0]["data"].clone();
+    for (key, value) in changes.as_object().unwrap() {
+        data[key] = value.clone();
+    }
+    serde_json::from_value(data).unwrap()
+}
+
+#[test]
+fn submission_status() {
+    let cases = [
+        (json!({}), ContentStatus::Live),
+        (
+            json!({"author": "[deleted]", "selftext": "Still here"}),
+            ContentStatus::Live,
+        ),
+        (
+            json!({"author": "[deleted]", "selftext": "[deleted]"}),
+            ContentStatus::DeletedByAuthor,
+        ),
+        (
+            json!({"removed_by_category": "deleted", "selftext": "[deleted]"}),
+            ContentStatus::DeletedByAuthor,
+        ),
+        (
+            json!({"removed_by_category": "moderator", "selftext": "[removed]"}),
+            ContentStatus::RemovedByModerator,
+        ),
+        (
+            json!({"removed_by_category": "automod_filtered"}),
+            ContentStatus::RemovedByModerator,
+        ),
+        (
+            json!({"removed_by_category": "anti_evil_ops", "selftext": "[removed]"}),
+            ContentStatus::RemovedByReddit,
+        ),
+        (
+            json!({"removed_by_category": "copyright_takedown"}),
+            ContentStatus::RemovedByReddit,
+        ),
+        (
+            json!({"banned_by": "KingTuxWH"}),
+            ContentStatus::RemovedByModerator,
+        ),
+        (json!({"banned_by": true}), ContentStatus::Spam),
+        (
+            json!({"spam": true, "removed_by_category": "moderator"}),
+            ContentStatus::Spam,
+        ),
+        // Removed then deleted by the author
+        (
+            json!({"removed_by_category": "deleted", "banned_by": "KingTuxWH", "author": "[deleted]", "selftext": "[deleted]"}),
+            ContentStatus::DeletedByAuthor,
+        ),
+    ];
+    for (changes, status) in cases {
+        assert_eq!(submission(changes.clone()).status(), status, "{}", changes);
+    }
+}
+
+#[test]
+fn comment_status() {
+    assert_eq!(comment(json!({})).status(), ContentStatus::Live);
+    // Reddit hides the author of removed comments
+    assert_eq!(
+        comment(json!({"author": "[deleted]", "body": "[removed]"})).status(),
+        ContentStatus::RemovedByModerator
+    );
+    assert_eq!(
+        comment(json!({"author": "[deleted]", "body": "[deleted]"})).status(),
+        ContentStatus::DeletedByAuthor
+    );
+    assert_eq!(
+        comment(json!({"author": null, "body": "Still here"})).status(),
+        ContentStatus::Live
+    );
+}
+
+#[test]
+fn banned_by() {
+    let submission = submission(json!({"banned_by": "KingTuxWH"}));
+    assert_eq!(
+        submission.banned_by,
+        Some(BannedBy::Moderator("KingTuxWH".to_string()))
+    );
+    let value = serde_json::to_value(&submission).unwrap();
+    assert_eq!(value["banned_by"], "KingTuxWH");
+}
+
+#[test]
+fn deleted_author() {
+    let listing: Value = serde_json::from_str(include_str!("fixtures/submissions.json")).unwrap();
+    let mut data = listing["data"]["children"][0]["data"].clone();
+    let data_map = data.as_object_mut().unwrap();
+    data_map.remove("author");
+    data_map.remove("author_fullname");
+    let submission: SubmissionResponse = serde_json::from_value(data).unwrap();
+    assert!(submission.is_deleted());
+    assert_eq!(submission.author, "[deleted]");
+    assert_eq!(submission.author_fullname, None);
+    assert!(!self::submission(json!({})).is_deleted());
+
+    let listing: Value = serde_json::from_str(include_str!("fixtures/comments.json")).unwrap();
+    let mut data = listing["data"]["children"][0]["data"].clone();
+    data["author"] = json!("[deleted]");
+    data.as_object_mut().unwrap().remove("author_fullname");
+    let deleted: CommentResponse = serde_json::from_value(data).unwrap();
+    assert!(deleted.is_deleted());
+    assert_eq!(deleted.author_fullname, None);
+    assert!(comment(json!({"author": null})).is_deleted());
+    assert!(!comment(json!({})).is_deleted());
+}
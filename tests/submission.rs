@@ -0,0 +1,319 @@
+mod common;
+
+use common::{mock_client, MockResponse, MockServer};
+use rraw::error::http_error::HTTPError;
+use rraw::error::Error;
+use rraw::responses::GenericResponse;
+use rraw::submission::response::{SubmissionResponse, SubmissionsResponse};
+use rraw::submission::{submission_id, SubmissionType};
+use rraw::thing::{Replyable, Savable, Votable};
+use serde_json::json;
+
+fn hidden_score() -> SubmissionResponse {
+    let response: GenericResponse<SubmissionResponse> =
+        serde_json::from_str(include_str!("fixtures/submission_hidden_score.json")).unwrap();
+    response.data
+}
+
+#[test]
+fn counts() {
+    let listing: SubmissionsResponse =
+        serde_json::from_str(include_str!("fixtures/submissions.json")).unwrap();
+    let submission = &listing.data.children[0].data;
+    assert_eq!(submission.score, 42);
+    assert_eq!(submission.ups, 42);
+    assert_eq!(submission.downs, 0);
+    assert_eq!(submission.num_comments, 3);
+    assert!(!submission.score_hidden);
+}
+
+#[test]
+fn score_hidden() {
+    let submission = hidden_score();
+    assert!(submission.score_hidden);
+    assert_eq!(submission.score, 1);
+    assert_eq!(submission.downs, 0, "Missing downs should default to 0");
+    assert_eq!(submission.num_comments, 12);
+    assert_eq!(submission.upvote_ratio, 0.5);
+}
+
+#[test]
+fn closed_poll() {
+    let response: GenericResponse<SubmissionResponse> =
+        serde_json::from_str(include_str!("fixtures/submission_poll.json")).unwrap();
+    let submission = response.data;
+    assert!(submission.is_poll());
+    let poll = submission.poll_data.unwrap();
+    assert_eq!(poll.total_vote_count, 100);
+    assert_eq!(poll.voting_end_timestamp, 1690604800000);
+    assert_eq!(poll.user_selection.as_deref(), Some("20734422"));
+    let votes: Vec<(&str, Option<u64>)> = poll
+        .options
+        .iter()
+        .map(|option| (option.text.as_str(), option.vote_count))
+        .collect();
+    assert_eq!(votes, vec![("2018", Some(12)), ("2021", Some(88))]);
+    assert_eq!(poll.options[0].id, "20734421");
+    assert!(!hidden_score().is_poll());
+}
+
+#[test]
+fn hot_score() {
+    let mut submission = hidden_score();
+    submission.created_utc = 1134028003.0;
+    submission.score = 0;
+    assert_eq!(submission.hot_score(), 0.0);
+
+    submission.created_utc += 45000.0;
+    submission.score = 10;
+    assert_eq!(submission.hot_score(), 2.0);
+    submission.score = -10;
+    assert_eq!(submission.hot_score(), 0.0);
+
+    let mut newer = submission.clone();
+    newer.created_utc += 45000.0;
+    assert!(newer.hot_score() > submission.hot_score());
+}
+
+#[tokio::test]
+async fn flair() {
+    let server = MockServer::start(|request| {
+        if request.body.contains("flair_template_id=missing") {
+            Some(MockResponse::json(json!({"json": {"errors": [
+                ["BAD_FLAIR_TEMPLATE_ID", "that flair template doesn't exist", "flair_template_id"]
+            ]}})))
+        } else {
+            Some(MockResponse::json(json!({"json": {"errors": []}})))
+        }
+    })
+    .await;
+    let client = mock_client(&server).await;
+    let listing: SubmissionsResponse =
+        serde_json::from_str(include_str!("fixtures/submissions.json")).unwrap();
+    let submission = listing.data.children[0].data.to_submission(&client);
+
+    submission
+        .set_flair(Some("e5f3a1c2-2a7b-11ee"), Some("Release & News"))
+        .await
+        .unwrap();
+    submission.clear_flair().await.unwrap();
+    let error = submission
+        .set_flair(Some("missing"), None)
+        .await
+        .unwrap_err();
+    assert!(matches!(error, Error::Api(_)), "{:?}", error);
+
+    let requests = server.requests();
+    assert_eq!(requests[0].method, "POST");
+    assert_eq!(requests[0].path, "/r/rust/api/selectflair");
+    assert_eq!(
+        requests[0].body,
+        "api_type=json&link=t3_15bfi0&flair_template_id=e5f3a1c2-2a7b-11ee&text=Release+%26+News"
+    );
+    assert_eq!(requests[1].body, "api_type=json&link=t3_15bfi0");
+}
+
+#[test]
+fn permalink_url() {
+    let listing: SubmissionsResponse =
+        serde_json::from_str(include_str!("fixtures/submissions.json")).unwrap();
+    let submission = &listing.data.children[0].data;
+    assert_eq!(
+        submission.permalink,
+        "/r/rust/comments/15bfi0/rraw_release/"
+    );
+    assert_eq!(
+        submission.permalink_url(),
+        "https://www.reddit.com/r/rust/comments/15bfi0/rraw_release/"
+    );
+}
+
+#[test]
+fn submission_ids() {
+    let id = |url: &str| submission_id(&reqwest::Url::parse(url).unwrap());
+    assert_eq!(id("https://redd.it/15bfi0").as_deref(), Some("15bfi0"));
+    assert_eq!(
+        id("https://www.reddit.com/r/rust/comments/15bfi0/rraw_13_released/").as_deref(),
+        Some("15bfi0")
+    );
+    assert_eq!(
+        id("https://old.reddit.com/comments/15bfi0?context=3").as_deref(),
+        Some("15bfi0")
+    );
+    assert_eq!(
+        id("https://www.reddit.com/gallery/15bfi0").as_deref(),
+        Some("15bfi0")
+    );
+    // The same links RedditUrl accepts
+    assert_eq!(
+        id("https://www.reddit.com/r/rust/comments/15bfi0/rraw_release/jtq1a2b/").as_deref(),
+        Some("15bfi0")
+    );
+    assert_eq!(id("https://www.reddit.com/r/rust/comments/15BFI0/"), None);
+    assert_eq!(id("https://v.redd.it/abc123xyz"), None);
+    assert_eq!(id("https://www.reddit.com/r/rust/"), None);
+    assert_eq!(id("https://example.com/comments/15bfi0"), None);
+}
+
+#[tokio::test]
+async fn resolve_shortlink() {
+    let server = MockServer::start(|request| {
+        if request.path.contains("t3_15bfi0") {
+            Some(MockResponse::json(include_str!(
+                "fixtures/submissions.json"
+            )))
+        } else {
+            Some(MockResponse::json(
+                json!({"kind": "Listing", "data": {"children": [], "after": null, "before": null}}),
+            ))
+        }
+    })
+    .await;
+    let client = mock_client(&server).await;
+    let submission = client
+        .resolve_shortlink("https://redd.it/15bfi0")
+        .await
+        .unwrap();
+    assert_eq!(submission.name, "t3_15bfi0");
+    let submission = client
+        .resolve_shortlink("https://www.reddit.com/r/rust/comments/15bfi0/rraw_13_released/")
+        .await
+        .unwrap();
+    assert_eq!(submission.name, "t3_15bfi0");
+    assert_eq!(server.requests()[0].path, "/api/info?id=t3_15bfi0");
+
+    let error = client
+        .resolve_shortlink("https://redd.it/zzzzzz")
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(
+            error,
+            Error::HTTPError {
+                error: HTTPError::NotFound,
+                ..
+            }
+        ),
+        "{:?}",
+        error
+    );
+    let error = client
+        .resolve_shortlink("https://www.reddit.com/r/rust/")
+        .await
+        .unwrap_err();
+    assert!(matches!(error, Error::InvalidOption(_)), "{:?}", error);
+}
+
+#[tokio::test]
+async fn send_replies() {
+    let server = MockServer::start(|_| Some(MockResponse::json(json!({})))).await;
+    let client = mock_client(&server).await;
+    let listing: SubmissionsResponse =
+        serde_json::from_str(include_str!("fixtures/submissions.json")).unwrap();
+    let submission = listing.data.children[0].data.to_submission(&client);
+    submission.set_send_replies(false).await.unwrap();
+    let request = &server.requests()[0];
+    assert_eq!(request.path, "/api/sendreplies");
+    assert_eq!(request.body, "id=t3_15bfi0&state=false");
+}
+
+#[tokio::test]
+async fn follow() {
+    let server = MockServer::start(|_| Some(MockResponse::json(json!({})))).await;
+    let client = mock_client(&server).await;
+    let listing: SubmissionsResponse =
+        serde_json::from_str(include_str!("fixtures/submissions.json")).unwrap();
+    let mut submission = listing.data.children[0].data.clone();
+    submission.to_submission(&client).follow().await.unwrap();
+    // Only the id
+    submission.name = "15bfi0".to_string();
+    submission.to_submission(&client).unfollow().await.unwrap();
+    let requests = server.requests();
+    assert_eq!(requests[0].path, "/api/follow_post");
+    assert_eq!(requests[0].body, "fullname=t3_15bfi0&follow=true");
+    assert_eq!(requests[1].body, "fullname=t3_15bfi0&follow=false");
+}
+
+#[tokio::test]
+async fn bound() {
+    let server = MockServer::start(|request| {
+        let body = match request.path.as_str() {
+            "/u/KingTuxWH/about" => include_str!("fixtures/user_about.json").to_string(),
+            "/r/rust/about.json" => include_str!("fixtures/subreddit_about.json").to_string(),
+            "/api/comment" => {
+                let comments: serde_json::Value =
+                    serde_json::from_str(include_str!("fixtures/comments.json")).unwrap();
+                let comment = comments["data"]["children"][0].clone();
+                json!({"json": {"errors": [], "data": {"things": [comment]}}}).to_string()
+            }
+            _ => "{}".to_string(),
+        };
+        Some(MockResponse::json(body))
+    })
+    .await;
+    let client = mock_client(&server).await;
+    let listing: SubmissionsResponse =
+        serde_json::from_str(include_str!("fixtures/submissions.json")).unwrap();
+    let names: Vec<&str> = listing
+        .bound(&client)
+        .map(|submission| submission.submission.name.as_str())
+        .collect();
+    assert_eq!(names, vec!["t3_15bfi0", "t3_15bfi1"]);
+
+    let submission = listing.bound(&client).next().unwrap();
+    submission.upvote().await.unwrap();
+    submission.save().await.unwrap();
+    let reply = submission.reply("Nice release").await.unwrap();
+    assert_eq!(reply.id, "jtq1a2b");
+    assert_eq!(submission.author().await.unwrap().user.name, "KingTuxWH");
+    assert_eq!(
+        submission.subreddit().await.unwrap().subreddit.display_name,
+        "rraw"
+    );
+    let requests = server.requests();
+    let sent: Vec<(&str, &str)> = requests
+        .iter()
+        .map(|request| (request.path.as_str(), request.body.as_str()))
+        .collect();
+    assert_eq!(
+        sent,
+        vec![
+            ("/api/vote", "id=t3_15bfi0&dir=1"),
+            ("/api/save", "id=t3_15bfi0"),
+            (
+                "/api/comment",
+                "api_type=json&thing_id=t3_15bfi0&text=Nice+release"
+            ),
+            ("/u/KingTuxWH/about", ""),
+            ("/r/rust/about.json", ""),
+        ]
+    );
+
+    let mut deleted = listing.data.children[1].data.clone();
+    deleted.author = "[deleted]".to_string();
+    let error = deleted.to_submission(&client).author().await.err().unwrap();
+    assert_eq!(error.http_error(), Some(&HTTPError::NotFound));
+    assert_eq!(server.requests().len(), 5);
+}
+
+#[tokio::test]
+async fn author_user() {
+    let server =
+        MockServer::start(|_| Some(MockResponse::json(include_str!("fixtures/user_about.json"))))
+            .await;
+    let client = mock_client(&server).await;
+    let listing: SubmissionsResponse =
+        serde_json::from_str(include_str!("fixtures/submissions.json")).unwrap();
+    let mut bound = listing.bound(&client);
+    let author = bound.next().unwrap().author_user().unwrap();
+    assert_eq!(author.user.name, "KingTuxWH");
+    assert_eq!(author.user.id, "3s8a2");
+    assert!(!author.is_loaded());
+    assert!(server.requests().is_empty());
+
+    let about = author.about().await.unwrap();
+    assert_eq!(about.name, "KingTuxWH");
+    assert_eq!(server.requests()[0].path, "/u/KingTuxWH/about");
+    // Deleted authors have no user
+    assert!(bound.next().unwrap().author_user().is_none());
+}
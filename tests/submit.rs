@@ -0,0 +1,113 @@
+use rraw::error::Error;
+use rraw::submission::submit::{
+    SubmitBuilder, SubmitKind, SubmitPoll, SubmitPollResponse, MAX_TITLE_LENGTH,
+};
+use serde_json::json;
+
+fn invalid(builder: SubmitBuilder) {
+    let error = builder.build().unwrap_err();
+    assert!(matches!(error, Error::InvalidOption(_)), "{:?}", error);
+}
+
+#[test]
+fn validation() {
+    invalid(SubmitBuilder::new("rraw", "No kind"));
+    invalid(SubmitBuilder::new("", "Title").kind(SubmitKind::SelfPost {
+        text: String::new(),
+    }));
+    invalid(SubmitBuilder::new("rraw", "  ").kind(SubmitKind::SelfPost {
+        text: String::new(),
+    }));
+    invalid(
+        SubmitBuilder::new("rraw", "x".repeat(MAX_TITLE_LENGTH + 1)).kind(SubmitKind::SelfPost {
+            text: String::new(),
+        }),
+    );
+    invalid(SubmitBuilder::new("rraw", "Title").kind(SubmitKind::Link { url: String::new() }));
+    invalid(
+        SubmitBuilder::new("rraw", "Title").kind(SubmitKind::Crosspost {
+            parent: "15bfi0".to_string(),
+        }),
+    );
+}
+
+#[test]
+fn form() {
+    let submit = SubmitBuilder::new("r/rraw", "RRAW 1.3 Released")
+        .kind(SubmitKind::Crosspost {
+            parent: "t3_15bfi0".to_string(),
+        })
+        .flair_id("a1b2")
+        .nsfw(true)
+        .build()
+        .unwrap();
+    assert_eq!(submit.subreddit, "rraw");
+    assert!(submit.send_replies);
+    let form = submit.form();
+    let get = |key: &str| {
+        form.iter()
+            .find(|(name, _)| *name == key)
+            .map(|(_, value)| value.as_str())
+    };
+    assert_eq!(get("api_type"), Some("json"));
+    assert_eq!(get("sr"), Some("rraw"));
+    assert_eq!(get("kind"), Some("crosspost"));
+    assert_eq!(get("crosspost_fullname"), Some("t3_15bfi0"));
+    assert_eq!(get("flair_id"), Some("a1b2"));
+    assert_eq!(get("nsfw"), Some("true"));
+    assert_eq!(get("spoiler"), Some("false"));
+    assert_eq!(get("sendreplies"), Some("true"));
+    assert_eq!(get("text"), None);
+}
+
+fn options(count: usize) -> Vec<String> {
+    (1..=count)
+        .map(|option| format!("Option {option}"))
+        .collect()
+}
+
+#[test]
+fn poll_validation() {
+    let poll =
+        |options: Vec<String>, days: u8| SubmitPoll::new("r/rraw", "Poll", options, days, None);
+    for (options, days) in [
+        (options(1), 3),
+        (options(7), 3),
+        (options(2), 0),
+        (options(2), 8),
+    ] {
+        let error = poll(options, days).unwrap_err();
+        assert!(matches!(error, Error::InvalidOption(_)), "{:?}", error);
+    }
+    assert!(poll(vec!["Yes".to_string(), " ".to_string()], 3).is_err());
+    assert!(poll(options(2), 1).is_ok());
+    assert!(poll(options(6), 7).is_ok());
+}
+
+#[test]
+fn poll_body() {
+    let poll = SubmitPoll::new(
+        "r/rraw",
+        "Weekly poll",
+        options(2),
+        7,
+        Some("Vote".to_string()),
+    )
+    .unwrap();
+    assert_eq!(
+        serde_json::to_value(&poll).unwrap(),
+        json!({
+            "api_type": "json",
+            "sr": "rraw",
+            "submit_type": "subreddit",
+            "title": "Weekly poll",
+            "text": "Vote",
+            "options": ["Option 1", "Option 2"],
+            "duration": 7,
+            "sendreplies": true
+        })
+    );
+    let response: SubmitPollResponse =
+        serde_json::from_value(json!({"id": "15bfi0", "url": null})).unwrap();
+    assert_eq!(response.fullname(), "t3_15bfi0");
+}
@@ -1,6 +1,15 @@
+mod common;
+
+use common::{mock_client, MockResponse, MockServer};
 use log::LevelFilter;
+use rraw::access::Access;
 use rraw::auth::AnonymousAuthenticator;
+use rraw::error::Error;
+use rraw::submission::submit::{SubmitBuilder, SubmitKind};
+use rraw::subreddit::response::{PostingSection, RequirementViolation, SubredditResponse};
+use rraw::utils::options::{FeedOption, ModQueue, ThingType};
 use rraw::Client;
+use serde_json::{json, Value};
 
 fn init() {
     if let Err(error) = env_logger::builder()
@@ -25,5 +34,358 @@ async fn generic() -> anyhow::Result<()> {
     for (id, value) in data.subreddit.other.iter() {
         println!("{id}: {value:?}");
     }
-    return Ok(());
+    Ok(())
+}
+
+#[ignore]
+#[tokio::test]
+async fn popular() -> anyhow::Result<()> {
+    init();
+    let client = Client::login(AnonymousAuthenticator::new(), "RRAW Test (by u/KingTuxWH)").await?;
+
+    let popular = client.popular_subreddits(None).await?;
+    assert!(!popular.data.children.is_empty());
+    let next = FeedOption::builder()
+        .after(popular.data.after.clone().unwrap())
+        .count(popular.data.children.len() as u32)
+        .build()?;
+    let next_page = client.popular_subreddits(Some(next)).await?;
+    assert!(!next_page.data.children.is_empty());
+
+    let new = client.new_subreddits(None).await?;
+    assert!(!new.data.children.is_empty());
+    Ok(())
+}
+
+#[ignore]
+#[tokio::test]
+async fn raw_body() -> anyhow::Result<()> {
+    init();
+    let client = Client::login(AnonymousAuthenticator::new(), "RRAW Test (by u/KingTuxWH)").await?;
+
+    let (subreddit, raw) = client
+        .get_json_with_raw::<SubredditResponse>("/r/rust/about.json", Access::Any)
+        .await?;
+    assert!(raw.contains(&subreddit.data.display_name));
+    Ok(())
+}
+
+#[tokio::test]
+async fn submit_requirements() {
+    let server = MockServer::start(|request| {
+        let body = match request.path.as_str() {
+            "/r/rraw/about.json" => include_str!("fixtures/subreddit_about.json"),
+            "/r/rraw/api/submit_text.json" => include_str!("fixtures/submit_text.json"),
+            "/r/rraw/about/rules.json" => include_str!("fixtures/rules.json"),
+            "/api/v1/rraw/post_requirements" => include_str!("fixtures/post_requirements.json"),
+            _ => return Some(MockResponse::json("{}").status(404)),
+        };
+        Some(MockResponse::json(body))
+    })
+    .await;
+    let client = mock_client(&server).await;
+    let subreddit = client.subreddit("rraw").await.unwrap();
+    let requirements = subreddit.submit_requirements().await.unwrap();
+    assert_eq!(
+        requirements.submit_text.submit_text,
+        "Read the rules before posting"
+    );
+    assert_eq!(requirements.rules.len(), 2);
+    assert_eq!(requirements.rules[0].short_name, "Stay on topic");
+    assert_eq!(requirements.requirements.title_text_max_length, Some(120));
+    assert!(requirements.requirements.is_flair_required);
+    assert_eq!(
+        requirements.requirements.domain_whitelist,
+        vec!["github.com".to_string(), "crates.io".to_string()]
+    );
+
+    let submit = |title: &str, url: &str, flair: bool| {
+        let mut builder = SubmitBuilder::new("rraw", title).kind(SubmitKind::Link {
+            url: url.to_string(),
+        });
+        if flair {
+            builder = builder.flair_id("release");
+        }
+        builder.build().unwrap()
+    };
+    let release = "https://github.com/wherkamp/async_rawr/releases";
+    assert!(requirements
+        .check(&submit("RRAW 1.3 Released", release, true))
+        .is_ok());
+    for invalid in [
+        submit("RRAW 1.3", release, true),
+        submit("[Meta] RRAW 1.3 Released", release, true),
+        submit("RRAW 1.3 Released", release, false),
+        submit("RRAW 1.3 Released", "https://example.com/rraw", true),
+    ] {
+        let error = requirements.check(&invalid).unwrap_err();
+        assert!(matches!(error, Error::InvalidOption(_)), "{:?}", error);
+    }
+}
+
+#[tokio::test]
+async fn post_requirements() {
+    let server = MockServer::start(|request| {
+        let body = match request.path.as_str() {
+            "/r/rraw/about.json" => include_str!("fixtures/subreddit_about.json"),
+            "/api/v1/rraw/post_requirements" => include_str!("fixtures/post_requirements.json"),
+            _ => return Some(MockResponse::json("{}").status(404)),
+        };
+        Some(MockResponse::json(body))
+    })
+    .await;
+    let client = mock_client(&server).await;
+    let subreddit = client.subreddit("rraw").await.unwrap();
+    let requirements = subreddit.post_requirements().await.unwrap();
+    assert_eq!(requirements.title_text_min_length, Some(10));
+
+    let draft = SubmitBuilder::new("rraw", "[Meta] 1.3")
+        .kind(SubmitKind::Link {
+            url: "https://example.com/rraw".to_string(),
+        })
+        .build()
+        .unwrap();
+    assert_eq!(
+        requirements.validate(&draft),
+        vec![
+            RequirementViolation::TitleBlacklisted {
+                blacklisted: "[meta]".to_string()
+            },
+            RequirementViolation::FlairRequired,
+            RequirementViolation::DomainNotWhitelisted {
+                host: "example.com".to_string(),
+                whitelist: vec!["github.com".to_string(), "crates.io".to_string()]
+            },
+        ]
+    );
+    let draft = SubmitBuilder::new("rraw", "RRAW 1.3 Released")
+        .kind(SubmitKind::Link {
+            url: "https://docs.crates.io/rraw".to_string(),
+        })
+        .flair_id("release")
+        .build()
+        .unwrap();
+    assert!(requirements.validate(&draft).is_empty());
+}
+
+#[tokio::test]
+async fn recent_comments() {
+    let server = MockServer::start(|request| {
+        let body = if request.path == "/r/rraw/about.json" {
+            include_str!("fixtures/subreddit_about.json")
+        } else {
+            include_str!("fixtures/comments.json")
+        };
+        Some(MockResponse::json(body))
+    })
+    .await;
+    let client = mock_client(&server).await;
+    let subreddit = client.subreddit("rraw").await.unwrap();
+    let feed = FeedOption::builder()
+        .limit(100)
+        .after("t1_jtq1a2b")
+        .build()
+        .unwrap();
+    let comments = subreddit.recent_comments(Some(feed)).await.unwrap();
+    assert_eq!(comments.data.children.len(), 2);
+    assert_eq!(
+        server.requests()[1].path,
+        "/r/rraw/comments.json?after=t1_jtq1a2b&limit=100"
+    );
+}
+
+#[tokio::test]
+async fn contributors() {
+    let server = MockServer::start(|request| {
+        let body = if request.path == "/r/rraw/about.json" {
+            include_str!("fixtures/subreddit_about.json")
+        } else {
+            include_str!("fixtures/api_errors/success.json")
+        };
+        Some(MockResponse::json(body))
+    })
+    .await;
+    let client = mock_client(&server).await;
+    let subreddit = client.subreddit("rraw").await.unwrap();
+    subreddit.add_contributor("KingTuxWH").await.unwrap();
+    subreddit.remove_contributor("KingTuxWH").await.unwrap();
+    subreddit.add_wiki_contributor("KingTuxWH").await.unwrap();
+    subreddit
+        .remove_wiki_contributor("KingTuxWH")
+        .await
+        .unwrap();
+    let requests: Vec<(String, String, String)> = server.requests()[1..]
+        .iter()
+        .map(|request| {
+            (
+                request.method.clone(),
+                request.path.clone(),
+                request.body.clone(),
+            )
+        })
+        .collect();
+    let expected = [
+        ("friend", "contributor"),
+        ("unfriend", "contributor"),
+        ("friend", "wikicontributor"),
+        ("unfriend", "wikicontributor"),
+    ]
+    .iter()
+    .map(|(endpoint, typ)| {
+        (
+            "POST".to_string(),
+            format!("/r/rraw/api/{endpoint}"),
+            format!("api_type=json&name=KingTuxWH&type={typ}"),
+        )
+    })
+    .collect::<Vec<_>>();
+    assert_eq!(requests, expected);
+}
+
+#[tokio::test]
+async fn mod_queue() {
+    let server = MockServer::start(|request| {
+        let body = if request.path == "/r/rraw/about.json" {
+            include_str!("fixtures/subreddit_about.json")
+        } else {
+            include_str!("fixtures/overview.json")
+        };
+        Some(MockResponse::json(body))
+    })
+    .await;
+    let client = mock_client(&server).await;
+    let subreddit = client.subreddit("rraw").await.unwrap();
+    let feed = FeedOption::builder().limit(25).build().unwrap();
+    let reports = subreddit
+        .mod_queue(ModQueue::Reports, Some(feed), Some(ThingType::Comments))
+        .await
+        .unwrap();
+    assert_eq!(reports.comments().count(), 1);
+    subreddit
+        .mod_queue(ModQueue::ModQueue, None, None)
+        .await
+        .unwrap();
+    let paths: Vec<String> = server.requests()[1..]
+        .iter()
+        .map(|request| request.path.clone())
+        .collect();
+    assert_eq!(
+        paths,
+        vec![
+            "/r/rraw/about/reports?limit=25&type=comments",
+            "/r/rraw/about/modqueue",
+        ]
+    );
+}
+
+#[tokio::test]
+async fn discovery() {
+    let server = MockServer::start(|request| {
+        let body = if request.path.starts_with("/api/trending_subreddits.json") {
+            r#"{"subreddit_names": ["rust", "rraw"], "comment_count": 42,
+                "comment_url": "/r/trendingsubreddits/comments/15bfi0/trending_subreddits/"}"#
+        } else {
+            r#"[{"sr_name": "learnrust"}, {"sr_name": "rust_gamedev"}]"#
+        };
+        Some(MockResponse::json(body))
+    })
+    .await;
+    let client = mock_client(&server).await;
+    let trending = client.trending_subreddits().await.unwrap();
+    assert_eq!(trending.subreddit_names, vec!["rust", "rraw"]);
+    assert_eq!(trending.comment_count, 42);
+
+    let recommended = client
+        .recommend_subreddits(&["rust", "rraw"], &["programming", "linux"])
+        .await
+        .unwrap();
+    let names: Vec<&str> = recommended.iter().map(|sr| sr.sr_name.as_str()).collect();
+    assert_eq!(names, vec!["learnrust", "rust_gamedev"]);
+    assert_eq!(
+        server.requests()[1].path,
+        "/api/recommend/sr/rust,rraw?omit=programming%2Clinux"
+    );
+
+    let error = client.recommend_subreddits(&[], &[]).await.unwrap_err();
+    assert!(matches!(error, Error::InvalidOption(_)), "{:?}", error);
+}
+
+/// Serves r/rraw with the relationship fields replaced and the rules forbidden
+async fn posting_server(relationship: Value) -> MockServer {
+    MockServer::start(move |request| {
+        let body = match request.path.as_str() {
+            "/r/rraw/about.json" => {
+                let mut about: Value =
+                    serde_json::from_str(include_str!("fixtures/subreddit_about.json")).unwrap();
+                for (key, value) in relationship.as_object().unwrap() {
+                    about["data"][key] = value.clone();
+                }
+                about.to_string()
+            }
+            "/api/v1/rraw/post_requirements" => {
+                include_str!("fixtures/post_requirements.json").to_string()
+            }
+            "/r/rraw/api/link_flair_v2" => include_str!("fixtures/link_flair.json").to_string(),
+            _ => return Some(MockResponse::json("{}").status(403)),
+        };
+        Some(MockResponse::json(body))
+    })
+    .await
+}
+
+#[tokio::test]
+async fn posting_context() {
+    let server = posting_server(json!({
+        "subreddit_type": "public",
+        "submission_type": "self",
+        "user_is_banned": false,
+    }))
+    .await;
+    let client = mock_client(&server).await;
+    let subreddit = client.subreddit("rraw").await.unwrap();
+    let context = subreddit.posting_context().await;
+    assert_eq!(context.about.as_ref().unwrap().user_is_banned, Some(false));
+    assert!(context.rules.is_none());
+    assert_eq!(context.errors.len(), 1);
+    let error = context.error(PostingSection::Rules).unwrap();
+    assert!(matches!(error, Error::Forbidden { .. }), "{:?}", error);
+    assert!(context.requirements.is_some());
+    let flair = context.flair.as_ref().unwrap();
+    assert_eq!(flair[0].text, "Release");
+    assert!(flair[1].text_editable);
+
+    assert!(context.can_submit());
+    assert!(context.can_submit_self());
+    assert!(!context.can_submit_link());
+    assert!(context.flair_required());
+}
+
+#[tokio::test]
+async fn posting_context_restricted() {
+    for (relationship, can_submit) in [
+        (
+            json!({"subreddit_type": "restricted", "user_is_contributor": false}),
+            false,
+        ),
+        (
+            json!({"subreddit_type": "restricted", "user_is_contributor": true}),
+            true,
+        ),
+        (
+            json!({"subreddit_type": "private", "user_is_moderator": true}),
+            true,
+        ),
+        (
+            json!({"subreddit_type": "public", "user_is_banned": true}),
+            false,
+        ),
+        (json!({"subreddit_type": "archived"}), false),
+    ] {
+        let server = posting_server(relationship.clone()).await;
+        let client = mock_client(&server).await;
+        let subreddit = client.subreddit("rraw").await.unwrap();
+        let context = subreddit.posting_context().await;
+        assert_eq!(context.can_submit(), can_submit, "{}", relationship);
+        assert_eq!(context.can_submit_link(), can_submit, "{}", relationship);
+    }
 }
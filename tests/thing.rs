@@ -0,0 +1,81 @@
+mod common;
+
+use common::{mock_client, MockResponse, MockServer};
+use rraw::comments::response::CommentsResponse;
+use rraw::error::Error;
+use rraw::message::response::InboxListing;
+use rraw::submission::response::SubmissionsResponse;
+use rraw::thing::{Deletable, Reportable, Savable, Votable};
+
+/// Written once for every kind of thing
+async fn archive<T: Savable + Votable>(thing: &T) -> Result<(), Error> {
+    thing.upvote().await?;
+    thing.save().await
+}
+
+#[tokio::test]
+async fn generic_actions() {
+    let server = MockServer::start(|request| {
+        let body = match request.path.as_str() {
+            "/api/v1/me" => include_str!("fixtures/me.json"),
+            _ => "{}",
+        };
+        Some(MockResponse::json(body))
+    })
+    .await;
+    let client = mock_client(&server).await;
+    let submissions: SubmissionsResponse =
+        serde_json::from_str(include_str!("fixtures/submissions.json")).unwrap();
+    let comments: CommentsResponse =
+        serde_json::from_str(include_str!("fixtures/comments.json")).unwrap();
+    let me = client.me().await.unwrap();
+
+    archive(&submissions.bound(&client).next().unwrap())
+        .await
+        .unwrap();
+    archive(&comments.bound(&client).next().unwrap())
+        .await
+        .unwrap();
+    archive(&me.comment("jtq1a2c").unwrap()).await.unwrap();
+
+    let sent: Vec<(String, String)> = server.requests()[1..]
+        .iter()
+        .map(|request| (request.path.clone(), request.body.clone()))
+        .collect();
+    let expected = ["t3_15bfi0", "t1_jtq1a2b", "t1_jtq1a2c"]
+        .iter()
+        .flat_map(|fullname| {
+            [
+                ("/api/vote".to_string(), format!("id={fullname}&dir=1")),
+                ("/api/save".to_string(), format!("id={fullname}")),
+            ]
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(sent, expected);
+}
+
+#[tokio::test]
+async fn report_and_delete() {
+    let server = MockServer::start(|_| Some(MockResponse::json("{}"))).await;
+    let client = mock_client(&server).await;
+    let comments: CommentsResponse =
+        serde_json::from_str(include_str!("fixtures/comments.json")).unwrap();
+    let inbox: InboxListing = serde_json::from_str(include_str!("fixtures/inbox.json")).unwrap();
+    let comment = comments.bound(&client).next().unwrap();
+    comment.report("Spam").await.unwrap();
+    comment.delete().await.unwrap();
+    let message = inbox.data.children[0].to_message(&client);
+    message.report("Harassment").await.unwrap();
+    message.delete().await.unwrap();
+
+    let requests = server.requests();
+    assert_eq!(requests[0].path, "/api/report");
+    assert_eq!(
+        requests[0].body,
+        "api_type=json&thing_id=t1_jtq1a2b&reason=Spam"
+    );
+    assert_eq!(requests[1].path, "/api/del");
+    assert_eq!(requests[1].body, "id=t1_jtq1a2b");
+    assert_eq!(requests[2].path, "/api/report");
+    assert_eq!(requests[3].path, "/api/del_msg");
+}
@@ -0,0 +1,55 @@
+use rraw::responses::types::{Distinguished, SubredditType};
+use rraw::subreddit::response::SubredditResponse;
+use rraw::user::response::ModeratedList;
+use serde_json::json;
+
+#[test]
+fn distinguished() {
+    let parsed: Vec<Distinguished> =
+        serde_json::from_value(json!(["moderator", "admin", "special"])).unwrap();
+    assert_eq!(
+        parsed,
+        vec![
+            Distinguished::Moderator,
+            Distinguished::Admin,
+            Distinguished::Special
+        ]
+    );
+    let unknown: Distinguished = serde_json::from_value(json!("gold")).unwrap();
+    assert_eq!(unknown, Distinguished::Unknown("gold".to_string()));
+    assert_eq!(serde_json::to_value(&unknown).unwrap(), json!("gold"));
+    assert_eq!(
+        serde_json::to_value(Distinguished::Moderator).unwrap(),
+        json!("moderator")
+    );
+}
+
+#[test]
+fn subreddit_type() {
+    for (value, expected) in [
+        ("public", SubredditType::Public),
+        ("private", SubredditType::Private),
+        ("restricted", SubredditType::Restricted),
+        ("archived", SubredditType::Archived),
+        ("employees_only", SubredditType::EmployeesOnly),
+        ("user", SubredditType::User),
+    ] {
+        let parsed: SubredditType = serde_json::from_value(json!(value)).unwrap();
+        assert_eq!(parsed, expected);
+        assert_eq!(parsed.to_string(), value);
+    }
+    let unknown: SubredditType = serde_json::from_value(json!("community_only")).unwrap();
+    assert_eq!(
+        unknown,
+        SubredditType::Unknown("community_only".to_string())
+    );
+    assert_eq!(unknown.to_string(), "community_only");
+
+    let moderated: ModeratedList =
+        serde_json::from_str(include_str!("fixtures/moderated_subreddits.json")).unwrap();
+    assert_eq!(moderated.data[0].subreddit_type, SubredditType::Public);
+    let about: SubredditResponse =
+        serde_json::from_str(include_str!("fixtures/subreddit_about.json")).unwrap();
+    assert_eq!(about.data.subreddit_type, Some(SubredditType::Public));
+    assert!(!about.data.other.contains_key("subreddit_type"));
+}
@@ -1,3 +1,259 @@
+mod common;
+
+use common::{mock_client, MockResponse, MockServer};
+use rraw::error::http_error::HTTPError;
+use rraw::error::Error;
+use rraw::responses::types::SubredditType;
+use rraw::responses::RedditDataType;
+use rraw::user::response::{ModeratedList, TrophyList, UserResponse};
+use rraw::utils::options::{FeedOption, ThingType, TimePeriod, UserSort};
+
+#[test]
+fn trophies() {
+    let trophies: TrophyList =
+        serde_json::from_str(include_str!("fixtures/trophies.json")).unwrap();
+    let trophies = trophies.data.trophies;
+    assert_eq!(trophies.len(), 2);
+    assert_eq!(trophies[0].kind, RedditDataType::Award);
+    assert_eq!(trophies[0].data.name, "Three-Year Club");
+    assert_eq!(trophies[0].data.granted_at, Some(1650000000));
+    assert_eq!(trophies[1].data.award_id.as_deref(), Some("o"));
+}
+
+#[test]
+fn moderated_subreddits() {
+    let moderated: ModeratedList =
+        serde_json::from_str(include_str!("fixtures/moderated_subreddits.json")).unwrap();
+    assert_eq!(moderated.data.len(), 1);
+    assert_eq!(moderated.data[0].sr, "rraw");
+    assert_eq!(moderated.data[0].subscribers, 42);
+    assert_eq!(moderated.data[0].mod_permissions, vec!["all".to_string()]);
+}
+
+#[tokio::test]
+async fn sorted_comments() {
+    let server = MockServer::start(|request| {
+        let body = if request.path.starts_with("/u/") {
+            include_str!("fixtures/user_about.json")
+        } else {
+            include_str!("fixtures/comments.json")
+        };
+        Some(MockResponse::json(body))
+    })
+    .await;
+    let client = mock_client(&server).await;
+    let user = client.user("KingTuxWH").await.unwrap();
+    let options = FeedOption::builder().limit(5).build().unwrap();
+    user.comments(Some(options), Some(UserSort::Top))
+        .await
+        .unwrap();
+    assert_eq!(
+        server.requests()[1].path,
+        "/user/KingTuxWH/comments.json?sort=top&limit=5"
+    );
+}
+
+#[tokio::test]
+async fn sorted_by_period() {
+    let server = MockServer::start(|request| {
+        let body = if request.path.starts_with("/u/") {
+            include_str!("fixtures/user_about.json")
+        } else if request.path.contains("/overview") {
+            include_str!("fixtures/overview.json")
+        } else {
+            include_str!("fixtures/submissions.json")
+        };
+        Some(MockResponse::json(body))
+    })
+    .await;
+    let client = mock_client(&server).await;
+    let user = client.user("KingTuxWH").await.unwrap();
+    let options = FeedOption::builder()
+        .time(TimePeriod::Year)
+        .limit(10)
+        .build()
+        .unwrap();
+    user.submissions(Some(options.clone()), Some(UserSort::Top))
+        .await
+        .unwrap();
+    user.overview(
+        Some(options.clone()),
+        Some(UserSort::Controversial),
+        Some(ThingType::Comments),
+    )
+    .await
+    .unwrap();
+    // Hot, new and no sort ignore the period
+    user.submissions(Some(options.clone()), Some(UserSort::New))
+        .await
+        .unwrap();
+    user.overview(Some(options), None, None).await.unwrap();
+    let paths: Vec<String> = server.requests()[1..]
+        .iter()
+        .map(|request| request.path.clone())
+        .collect();
+    assert_eq!(
+        paths,
+        vec![
+            "/user/KingTuxWH/submitted.json?sort=top&t=year&limit=10",
+            "/user/KingTuxWH/overview.json?sort=controversial&t=year&limit=10&type=comments",
+            "/user/KingTuxWH/submitted.json?sort=new&limit=10",
+            "/user/KingTuxWH/overview.json?limit=10",
+        ]
+    );
+}
+
+fn about(fixture: &str) -> UserResponse {
+    serde_json::from_str(fixture).unwrap()
+}
+
+#[test]
+fn about_user() {
+    let user = about(include_str!("fixtures/user_about.json")).data;
+    assert_eq!(
+        (user.total_karma, user.link_karma, user.comment_karma),
+        (4210, 1500, 2700)
+    );
+    assert_eq!((user.awardee_karma, user.awarder_karma), (10, 0));
+    assert_eq!(user.created_utc, 1540000000.0);
+    assert!(user.is_mod && !user.is_gold && !user.is_employee);
+    assert!(user.verified && user.accept_followers);
+    assert_eq!(
+        user.icon_url(),
+        "https://styles.redditmedia.com/t5_1e4ipq/styles/profileIcon_snoo.png?width=256&height=256&crop=256:256,smart&s=abc"
+    );
+    assert_eq!(user.snoovatar_url(), None);
+    let profile = user.subreddit.as_ref().unwrap();
+    assert_eq!(profile.display_name, "u_KingTuxWH");
+    assert_eq!(profile.public_description, "Developer of RRAW");
+    assert_eq!(profile.subreddit_type, Some(SubredditType::User));
+    assert_eq!(profile.banner_url(), None);
+    assert_eq!(profile.icon_url(), Some(user.icon_url()));
+}
+
+#[test]
+fn about_new_user() {
+    let user = about(include_str!("fixtures/user_about_new.json")).data;
+    assert_eq!(user.total_karma, 0);
+    assert_eq!(user.link_karma, 1);
+    assert!(!user.has_verified_email && !user.is_mod);
+    let profile = user.subreddit.unwrap();
+    assert!(profile.public_description.is_empty());
+    assert_eq!(profile.banner_url(), None);
+}
+
+#[test]
+fn about_employee() {
+    let user = about(include_str!("fixtures/user_about_employee.json")).data;
+    assert!(user.is_employee && user.is_gold && user.is_mod);
+    assert_eq!(
+        user.snoovatar_url().as_deref(),
+        Some("https://i.redd.it/snoovatar/avatars/abc.png?width=380&height=600")
+    );
+    let profile = user.subreddit.unwrap();
+    assert_eq!(
+        profile.banner_url().as_deref(),
+        Some("https://styles.redditmedia.com/t5_3b9u5/styles/profileBanner_x.png?width=1280&height=384&s=def")
+    );
+    assert_eq!(profile.other["default_set"], true);
+}
+
+#[tokio::test]
+async fn suspended_and_deleted() {
+    let server = MockServer::start(|request| match request.path.as_str() {
+        "/u/KingTuxWH/about" => Some(MockResponse::json(include_str!("fixtures/user_about.json"))),
+        "/u/suspended_account/about" => Some(MockResponse::json(
+            r#"{"kind": "t2", "data": {"is_suspended": true}}"#,
+        )),
+        _ => Some(MockResponse::json(r#"{"message": "Not Found", "error": 404}"#).status(404)),
+    })
+    .await;
+    let client = mock_client(&server).await;
+
+    let user = client.user("KingTuxWH").await.unwrap();
+    assert!(!user.user.is_suspended);
+    assert_eq!(user.user.link_karma, 1500);
+
+    let user = client.user("suspended_account").await.unwrap();
+    assert!(user.user.is_suspended);
+    assert_eq!(user.user.name, "suspended_account");
+    assert_eq!(user.user.total_karma, 0);
+    assert!(user.user.subreddit.is_none());
+
+    let error = client.user("deleted_account").await.err().unwrap();
+    assert!(
+        matches!(
+            error,
+            Error::HTTPError {
+                error: HTTPError::NotFound,
+                ..
+            }
+        ),
+        "{:?}",
+        error
+    );
+}
+
+#[tokio::test]
+async fn multireddits() {
+    let server = MockServer::start(|request| match request.path.as_str() {
+        "/api/multi/user/KingTuxWH" => Some(MockResponse::json(include_str!(
+            "fixtures/multireddits.json"
+        ))),
+        "/api/multi/user/TheSmartKing" => Some(MockResponse::json("[]")),
+        "/api/multi/user/hidden_account" => Some(MockResponse::json("{}").status(403)),
+        _ => Some(MockResponse::json(include_str!("fixtures/user_about.json"))),
+    })
+    .await;
+    let client = mock_client(&server).await;
+    let user = client.user("KingTuxWH").await.unwrap();
+    let multis = user.multireddits().await.unwrap();
+    assert_eq!(multis.len(), 1);
+    assert_eq!(multis[0].name, "rust");
+    assert_eq!(multis[0].description_md, "Rust news");
+    let subreddits: Vec<&str> = multis[0]
+        .subreddits
+        .iter()
+        .map(|subreddit| subreddit.name.as_str())
+        .collect();
+    assert_eq!(subreddits, vec!["rust", "learnrust", "rraw"]);
+
+    for name in ["TheSmartKing", "hidden_account"] {
+        let mut user = client.user("KingTuxWH").await.unwrap();
+        user.user.name = name.to_string();
+        assert!(user.multireddits().await.unwrap().is_empty(), "{}", name);
+    }
+}
+
+#[cfg(feature = "stream")]
+#[tokio::test]
+async fn users_about() {
+    use std::time::{Duration, Instant};
+
+    let server = MockServer::start(|request| match request.path.as_str() {
+        "/u/KingTuxWH/about" => Some(
+            MockResponse::json(include_str!("fixtures/user_about.json"))
+                .header("x-ratelimit-remaining", "0")
+                .header("x-ratelimit-reset", "1"),
+        ),
+        _ => Some(MockResponse::json(r#"{"message": "Not Found", "error": 404}"#).status(404)),
+    })
+    .await;
+    let client = mock_client(&server).await;
+    let start = Instant::now();
+    let mut results = client
+        .users_about(&["KingTuxWH", "deleted_account"], 1)
+        .await;
+    // The first response used the last request of the period
+    assert!(start.elapsed() >= Duration::from_millis(900));
+    results.sort_by(|(a, _), (b, _)| a.cmp(b));
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0].0, "KingTuxWH");
+    assert_eq!(results[0].1.as_ref().unwrap().data.name, "KingTuxWH");
+    assert_eq!(results[1].0, "deleted_account");
+    assert!(results[1].1.is_err());
+}
+
 #[cfg(test)]
 mod user_tests {
     use log::LevelFilter;
@@ -37,12 +293,12 @@ mod user_tests {
             );
             let user = user.unwrap();
             assert!(
-                user.submissions(None).await.is_ok(),
+                user.submissions(None, None).await.is_ok(),
                 "{}/submissions could not be loaded correctly",
                 username
             );
             assert!(
-                user.comments(None).await.is_ok(),
+                user.comments(None, None).await.is_ok(),
                 "{}/comments could not be loaded correctly",
                 username
             );
@@ -50,4 +306,16 @@ mod user_tests {
 
         Ok(())
     }
+    #[ignore]
+    #[tokio::test]
+    pub async fn test_profile() -> anyhow::Result<()> {
+        init();
+        let client =
+            Client::login(AnonymousAuthenticator::new(), "RRAW Test (by u/KingTuxWH)").await?;
+        let user = client.user("KingTuxWH").await?;
+        let profile = user.profile().await?;
+        assert_eq!(profile.about.name, "KingTuxWH");
+        assert!(profile.trophies.is_some());
+        Ok(())
+    }
 }
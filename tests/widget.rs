@@ -0,0 +1,117 @@
+mod common;
+
+use common::{mock_client, MockResponse, MockServer};
+use rraw::subreddit::widget::{Widget, Widgets};
+
+const WIDGETS: &str = include_str!("fixtures/widgets.json");
+
+#[tokio::test]
+async fn widgets() {
+    let server = MockServer::start(|request| {
+        let body = match request.path.as_str() {
+            "/r/rraw/about.json" => include_str!("fixtures/subreddit_about.json"),
+            _ => WIDGETS,
+        };
+        Some(MockResponse::json(body))
+    })
+    .await;
+    let client = mock_client(&server).await;
+    let subreddit = client.subreddit("rraw").await.unwrap();
+    let widgets = subreddit.widgets().await.unwrap();
+    assert_eq!(server.requests()[1].path, "/r/rraw/api/widgets");
+
+    let kinds: Vec<&str> = widgets.sidebar().map(|widget| widget.kind()).collect();
+    assert_eq!(
+        kinds,
+        vec![
+            "textarea",
+            "subreddit-rules",
+            "button",
+            "community-list",
+            "image",
+            "calendar"
+        ]
+    );
+    assert_eq!(widgets.topbar().count(), 0);
+
+    let about = match widgets.sidebar().next().unwrap() {
+        Widget::TextArea(about) => about,
+        other => panic!("expected a text area. Got {other:?}"),
+    };
+    assert_eq!(about.short_name, "About");
+    assert_eq!(about.text, "An **async** Reddit API wrapper");
+    assert!(about.text_html.as_ref().unwrap().contains("&lt;strong&gt;"));
+    assert_eq!(
+        about.styles.as_ref().unwrap().header_color.as_deref(),
+        Some("#DEA584")
+    );
+
+    let rules = match widgets.get("widget_rules-2s4x6").unwrap() {
+        Widget::Rules(rules) => rules,
+        other => panic!("expected rules. Got {other:?}"),
+    };
+    assert_eq!(rules.display.as_deref(), Some("full"));
+    let names: Vec<&str> = rules
+        .rules
+        .iter()
+        .map(|rule| rule.short_name.as_str())
+        .collect();
+    assert_eq!(names, vec!["Be nice", "No spam"]);
+    assert_eq!(rules.rules[0].violation_reason.as_deref(), Some("Not nice"));
+    assert_eq!(rules.rules[1].priority, 1);
+
+    let moderators = widgets.moderators().unwrap();
+    assert_eq!(moderators.mods[0].name, "KingTuxWH");
+    assert_eq!(moderators.total_mods, 2);
+
+    let id_card = widgets
+        .get(widgets.layout.id_card_widget.as_deref().unwrap())
+        .unwrap();
+    match id_card {
+        Widget::Other(value) => assert_eq!(value["subscribersCount"], 1024),
+        other => panic!("expected an unknown widget. Got {other:?}"),
+    }
+    assert_eq!(id_card.kind(), "id-card");
+    assert_eq!(id_card.short_name(), Some("Community Details"));
+}
+
+#[test]
+fn typed_variants() {
+    let widgets: Widgets = serde_json::from_str(WIDGETS).unwrap();
+    let widget = |id: &str| widgets.get(&format!("widget_13f2k9a8b1c{id}")).unwrap();
+    match widget("0e") {
+        Widget::Button(button) => {
+            assert_eq!(button.buttons[0].text.as_deref(), Some("Docs"));
+            assert_eq!(button.buttons[0].other["textColor"], "#FFFFFF");
+        }
+        other => panic!("expected a button. Got {other:?}"),
+    }
+    match widget("0f") {
+        Widget::CommunityList(list) => assert_eq!(list.communities[0].name, "rust"),
+        other => panic!("expected a community list. Got {other:?}"),
+    }
+    match widget("10") {
+        Widget::Image(image) => {
+            assert_eq!(image.images[0].width, 300);
+            assert_eq!(
+                image.images[0].link_url.as_deref(),
+                Some("https://rustacean.net")
+            );
+        }
+        other => panic!("expected an image. Got {other:?}"),
+    }
+    assert!(matches!(widget("11"), Widget::Calendar(_)));
+}
+
+#[test]
+fn round_trip() {
+    let widgets: Widgets = serde_json::from_str(WIDGETS).unwrap();
+    let value = serde_json::to_value(&widgets).unwrap();
+    assert_eq!(
+        value["items"]["widget_rules-2s4x6"]["kind"],
+        "subreddit-rules"
+    );
+    assert_eq!(value["items"]["widget_id-card-2s4x6"]["kind"], "id-card");
+    let parsed: Widgets = serde_json::from_value(value).unwrap();
+    assert_eq!(parsed, widgets);
+}